@@ -88,14 +88,23 @@ pub use validation::{
     ValidationEvent,
     ValidationError,
     ValidationMetrics,
+    ValidationMetricsDelta,
+    HEALTH_CRITICAL_THRESHOLD,
+    HEALTH_WARNING_THRESHOLD,
+    health_status,
     ValidationTools,
     ValidationReport,
+    ValidationPlan,
+    PlannedScenario,
     
     // Test types
     ConformanceTestSuite,
     ConformanceTestResults,
     TestScenario,
     TestResult,
+    ConformanceProfile,
+    ProfileThresholds,
+    ProfileResult,
     
     // Property checkers
     SafetyChecker,
@@ -103,8 +112,16 @@ pub use validation::{
     ByzantineChecker,
     NetworkChecker,
     
+    // Slashing evidence
+    SlashingEvidence,
+    SlashingRule,
+    verify_evidence,
+    
     // Actor model integration
     ActorModelBridge,
+    ActorSnapshot,
+    StateDelta,
+    SystemSnapshot,
     
     // Alert types (shared with monitoring)
     Alert as ValidationAlert,
@@ -125,17 +142,26 @@ pub use validation::{
 pub use monitor::{
     // Core monitoring types
     MonitorConfig,
+    AlertOverflowPolicy,
     AlpenglowRuntimeMonitor,
     RuntimeAlert,
+    RuntimeAlertReceiver,
     RuntimeAlertType,
     RuntimeMonitorEvent,
     
     // Metrics and health
     RuntimeMetrics,
+    HistogramMetric,
     NetworkHealth,
     ResourceUsage,
     RuntimeMonitorStats,
     PerformanceTrends,
+    MetricSample,
+    
+    // Clocks
+    MonitorClock,
+    SystemClock,
+    MockClock,
     
     // Actor integration
     RuntimeActorBridge,
@@ -186,7 +212,7 @@ pub mod integration {
     
     /// Quick validation check for basic functionality
     pub async fn quick_validation_check(config: AlpenglowConfig) -> AlpenglowResult<bool> {
-        let tools = create_validation_tools(config)?;
+        let mut tools = create_validation_tools(config)?;
         let results = tools.run_conformance_tests().await;
         Ok(results.success_rate() > 0.8) // 80% pass rate threshold
     }
@@ -269,12 +295,18 @@ pub mod tla_validation {
 pub mod metrics {
     //! Metrics export utilities for external monitoring systems
     
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, BTreeSet, HashMap};
+    use std::fs::{self, OpenOptions};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::time::{SystemTime, UNIX_EPOCH};
+    
+    use crate::error::ValidationMonitorResult;
     
     #[cfg(feature = "monitoring")]
     use crate::monitor::AlpenglowRuntimeMonitor;
     
-    /// Export metrics in Prometheus format
+    /// Export metrics in Prometheus format; finalization latency is exported as a histogram
     #[cfg(feature = "monitoring")]
     pub fn export_prometheus_metrics(monitor: &AlpenglowRuntimeMonitor) -> String {
         let metrics = monitor.export_metrics();
@@ -285,9 +317,69 @@ pub mod metrics {
             output.push_str(&format!("{} {}\n", name, value));
         }
         
+        output.push_str(&monitor.finalization_latency_histogram().to_prometheus("alpenglow_finalization_latency_ms"));
+        
         output
     }
     
+    /// Export the same metrics as `export_prometheus_metrics` as a protobuf-encoded OTLP
+    /// `ExportMetricsServiceRequest`, one gauge per metric, so they can be pushed to an
+    /// OpenTelemetry collector. The resource carries the service version and validator count.
+    #[cfg(all(feature = "monitoring", feature = "otlp"))]
+    pub fn export_otlp_metrics(monitor: &AlpenglowRuntimeMonitor) -> Vec<u8> {
+        use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+        use opentelemetry_proto::tonic::common::v1::{any_value, AnyValue, InstrumentationScope, KeyValue};
+        use opentelemetry_proto::tonic::metrics::v1::{metric, number_data_point, Gauge, Metric, NumberDataPoint, ResourceMetrics, ScopeMetrics};
+        use opentelemetry_proto::tonic::resource::v1::Resource;
+        use prost::Message;
+        
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos() as u64;
+        let attribute = |key: &str, value: any_value::Value| KeyValue {
+            key: key.to_string(),
+            value: Some(AnyValue { value: Some(value) }),
+        };
+        
+        let metrics: BTreeMap<String, f64> = monitor.export_metrics().into_iter().collect();
+        let metrics = metrics.into_iter()
+            .map(|(name, value)| Metric {
+                name,
+                data: Some(metric::Data::Gauge(Gauge {
+                    data_points: vec![NumberDataPoint {
+                        time_unix_nano: now,
+                        value: Some(number_data_point::Value::AsDouble(value)),
+                        ..Default::default()
+                    }],
+                })),
+                ..Default::default()
+            })
+            .collect();
+        
+        let request = ExportMetricsServiceRequest {
+            resource_metrics: vec![ResourceMetrics {
+                resource: Some(Resource {
+                    attributes: vec![
+                        attribute("service.name", any_value::Value::StringValue("alpenglow".to_string())),
+                        attribute("service.version", any_value::Value::StringValue(crate::VERSION.to_string())),
+                        attribute("alpenglow.validator_count", any_value::Value::IntValue(monitor.config().validator_count as i64)),
+                    ],
+                    ..Default::default()
+                }),
+                scope_metrics: vec![ScopeMetrics {
+                    scope: Some(InstrumentationScope {
+                        name: "alpenglow-validation".to_string(),
+                        version: crate::VERSION.to_string(),
+                        ..Default::default()
+                    }),
+                    metrics,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        };
+        
+        request.encode_to_vec()
+    }
+    
     /// Export metrics as JSON
     #[cfg(feature = "monitoring")]
     pub fn export_json_metrics(monitor: &AlpenglowRuntimeMonitor) -> serde_json::Value {
@@ -295,6 +387,188 @@ pub mod metrics {
         serde_json::to_value(metrics).unwrap_or_default()
     }
     
+    /// Export metrics as CSV with a header row and a single timestamped sample
+    #[cfg(feature = "monitoring")]
+    pub fn export_csv(monitor: &AlpenglowRuntimeMonitor) -> String {
+        let metrics = monitor.export_metrics();
+        let columns: Vec<String> = metrics.keys().cloned().collect::<BTreeSet<_>>().into_iter().collect();
+        
+        let mut output = csv_header(&columns);
+        output.push_str(&csv_row(crate::utils::format_timestamp(SystemTime::now()), &columns, &metrics));
+        output
+    }
+    
+    /// Appends one timestamped CSV row per sample interval to a file.
+    ///
+    /// Columns are `timestamp` followed by one column per metric. Metrics seen for
+    /// the first time are appended as new columns and the file is rewritten with the
+    /// extended header, leaving earlier rows blank in those columns.
+    #[derive(Debug, Clone)]
+    pub struct CsvMetricLogger {
+        path: PathBuf,
+        columns: Vec<String>,
+    }
+    
+    impl CsvMetricLogger {
+        /// Create a logger, picking up the column schema of an existing file if present
+        pub fn new<P: AsRef<Path>>(path: P) -> ValidationMonitorResult<Self> {
+            let path = path.as_ref().to_path_buf();
+            let columns = match fs::read_to_string(&path) {
+                Ok(content) => split_csv_header(&content).0.into_iter().skip(1).collect(),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+                Err(e) => return Err(e.into()),
+            };
+            
+            Ok(Self { path, columns })
+        }
+        
+        /// Path of the CSV file being written
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+        
+        /// Metric columns in file order (excluding the timestamp column)
+        pub fn columns(&self) -> &[String] {
+            &self.columns
+        }
+        
+        /// Append a sample, extending the schema if it contains new metrics
+        pub fn log_sample(&mut self, timestamp: SystemTime, metrics: &HashMap<String, f64>) -> ValidationMonitorResult<()> {
+            let new_columns: BTreeSet<&String> = metrics.keys()
+                .filter(|name| !self.columns.contains(name))
+                .collect();
+            
+            if !new_columns.is_empty() || !self.path.exists() {
+                let previous_width = self.columns.len();
+                self.columns.extend(new_columns.into_iter().cloned());
+                self.rewrite_header(previous_width)?;
+            }
+            
+            let mut file = OpenOptions::new().append(true).open(&self.path)?;
+            file.write_all(csv_row(crate::utils::format_timestamp(timestamp), &self.columns, metrics).as_bytes())?;
+            Ok(())
+        }
+        
+        /// Append a sample of the monitor's current metrics
+        #[cfg(feature = "monitoring")]
+        pub fn log_monitor(&mut self, monitor: &AlpenglowRuntimeMonitor) -> ValidationMonitorResult<()> {
+            self.log_sample(SystemTime::now(), &monitor.export_metrics())
+        }
+        
+        /// Rewrite the file with the current header, padding rows written under a narrower schema
+        fn rewrite_header(&self, previous_width: usize) -> ValidationMonitorResult<()> {
+            let existing = match fs::read_to_string(&self.path) {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+                Err(e) => return Err(e.into()),
+            };
+            
+            let padding = ",".repeat(self.columns.len() - previous_width);
+            let mut output = csv_header(&self.columns);
+            for row in split_csv_header(&existing).1.lines() {
+                output.push_str(row);
+                output.push_str(&padding);
+                output.push('\n');
+            }
+            
+            fs::write(&self.path, output)?;
+            Ok(())
+        }
+    }
+    
+    /// Quote a field per RFC 4180 when it contains a separator, quote or line break
+    fn csv_field(field: &str) -> String {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+    
+    /// Fields of the header record at the start of `content` and the rows that follow it.
+    /// Quoted fields may contain separators, doubled quotes and line breaks.
+    fn split_csv_header(content: &str) -> (Vec<String>, &str) {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = content.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek().is_some_and(|(_, next)| *next == '"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => quoted = !quoted,
+                ',' if !quoted => fields.push(std::mem::take(&mut field)),
+                '\n' if !quoted => {
+                    fields.push(field.trim_end_matches('\r').to_string());
+                    return (fields, &content[i + 1..]);
+                }
+                _ => field.push(c),
+            }
+        }
+        if !field.is_empty() || !fields.is_empty() {
+            fields.push(field);
+        }
+        (fields, "")
+    }
+    
+    fn csv_header(columns: &[String]) -> String {
+        let mut header = String::from("timestamp");
+        for column in columns {
+            header.push(',');
+            header.push_str(&csv_field(column));
+        }
+        header.push('\n');
+        header
+    }
+    
+    fn csv_row(timestamp: String, columns: &[String], metrics: &HashMap<String, f64>) -> String {
+        let mut row = csv_field(&timestamp);
+        for column in columns {
+            row.push(',');
+            if let Some(value) = metrics.get(column) {
+                row.push_str(&value.to_string());
+            }
+        }
+        row.push('\n');
+        row
+    }
+    
+    /// Per-validator series derived from the model state, as (metric name, labels, value)
+    /// triples labeled by `validator`, in validator order within each metric
+    pub fn export_labeled_metrics(state: &alpenglow_stateright::AlpenglowState) -> Vec<(String, BTreeMap<String, String>, f64)> {
+        let per_validator: [(&str, Vec<(alpenglow_stateright::ValidatorId, f64)>); 3] = [
+            ("alpenglow_validator_bandwidth_usage", state.rotor_bandwidth_usage.iter().map(|(v, usage)| (*v, *usage as f64)).collect()),
+            ("alpenglow_validator_view", state.votor_view.iter().map(|(v, view)| (*v, *view as f64)).collect()),
+            ("alpenglow_validator_timeout_expiry", state.votor_timeout_expiry.iter().map(|(v, expiry)| (*v, *expiry as f64)).collect()),
+        ];
+        
+        per_validator.into_iter()
+            .flat_map(|(name, values)| values.into_iter().map(move |(validator, value)| {
+                let labels = BTreeMap::from([("validator".to_string(), validator.to_string())]);
+                (name.to_string(), labels, value)
+            }))
+            .collect()
+    }
+    
+    /// Render labeled samples in Prometheus text format, one `# TYPE` line per metric name
+    pub fn render_labeled_metrics(samples: &[(String, BTreeMap<String, String>, f64)]) -> String {
+        let mut output = String::new();
+        let mut previous: Option<&str> = None;
+        
+        for (name, labels, value) in samples {
+            if previous != Some(name.as_str()) {
+                output.push_str(&format!("# TYPE {} gauge\n", name));
+                previous = Some(name.as_str());
+            }
+            let labels: Vec<String> = labels.iter().map(|(key, value)| format!("{}=\"{}\"", key, value)).collect();
+            output.push_str(&format!("{}{{{}}} {}\n", name, labels.join(","), value));
+        }
+        
+        output
+    }
+    
     /// Export validation metrics
     pub fn export_validation_metrics(tools: &crate::validation::ValidationTools) -> HashMap<String, f64> {
         let metrics = tools.get_metrics();
@@ -310,6 +584,7 @@ pub mod metrics {
         exported.insert("alpenglow_validation_skip_certificates".to_string(), metrics.skip_certificates as f64);
         exported.insert("alpenglow_validation_avg_finalization_time_ms".to_string(), metrics.average_finalization_time.as_millis() as f64);
         exported.insert("alpenglow_validation_max_finalization_time_ms".to_string(), metrics.max_finalization_time.as_millis() as f64);
+        exported.insert("alpenglow_validation_health_score".to_string(), metrics.health_score() as f64);
         
         exported
     }
@@ -345,7 +620,8 @@ pub mod dashboards {
     /// System health summary
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
     pub struct SystemHealthSummary {
-        pub overall_status: String, // "healthy", "warning", "critical"
+        pub overall_status: String, // "healthy", "warning", "critical", derived from health_score
+        pub health_score: u8,
         pub validator_count: usize,
         pub online_validators: usize,
         pub finalization_rate: f64,
@@ -359,6 +635,7 @@ pub mod dashboards {
         runtime_monitor: Option<&crate::monitor::AlpenglowRuntimeMonitor>,
     ) -> DashboardData {
         let validation_metrics = crate::metrics::export_validation_metrics(validation_tools);
+        let health_score = validation_tools.health_score();
         
         #[cfg(feature = "monitoring")]
         let runtime_metrics = runtime_monitor
@@ -374,7 +651,8 @@ pub mod dashboards {
             runtime_metrics,
             alerts: vec![], // Would be populated from actual alert history
             system_health: SystemHealthSummary {
-                overall_status: "healthy".to_string(),
+                overall_status: crate::validation::health_status(health_score).to_string(),
+                health_score,
                 validator_count: 4, // Would be from actual config
                 online_validators: 4,
                 finalization_rate: 1.0,
@@ -550,8 +828,14 @@ pub mod prelude {
 
 // Version and build information
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
-pub const BUILD_TIME: &str = env!("VERGEN_BUILD_TIMESTAMP");
-pub const GIT_HASH: &str = env!("VERGEN_GIT_SHA");
+pub const BUILD_TIME: &str = match option_env!("VERGEN_BUILD_TIMESTAMP") {
+    Some(timestamp) => timestamp,
+    None => "unknown",
+};
+pub const GIT_HASH: &str = match option_env!("VERGEN_GIT_SHA") {
+    Some(sha) => sha,
+    None => "unknown",
+};
 
 /// Get version information
 pub fn version_info() -> String {
@@ -631,7 +915,7 @@ mod tests {
         use alpenglow_stateright::utils::test_configs;
         
         let config = test_configs()[0].clone();
-        let result = integration::create_integrated_system(config).await;
+        let result = integration::create_integrated_system(config);
         
         assert!(result.is_ok());
         let (validation_tools, runtime_monitor) = result.unwrap();
@@ -661,6 +945,133 @@ mod tests {
         assert!(metrics.contains_key("alpenglow_validation_fast_path_certificates"));
     }
     
+    #[cfg(all(feature = "metrics-export", feature = "monitoring"))]
+    #[test]
+    fn test_prometheus_finalization_latency_histogram() {
+        let monitor = crate::monitor::AlpenglowRuntimeMonitor::new(crate::monitor::MonitorConfig::default());
+        for latency_ms in [20.0, 75.0, 80.0, 300.0, 1200.0] {
+            monitor.record_finalization_latency(latency_ms);
+        }
+        
+        let output = crate::metrics::export_prometheus_metrics(&monitor);
+        assert!(output.contains("# TYPE alpenglow_finalization_latency_ms histogram\n"));
+        
+        let buckets: Vec<(&str, u64)> = output.lines()
+            .filter_map(|line| line.strip_prefix("alpenglow_finalization_latency_ms_bucket{le=\""))
+            .map(|rest| {
+                let (bound, count) = rest.split_once("\"} ").unwrap();
+                (bound, count.parse().unwrap())
+            })
+            .collect();
+        let bounds: Vec<&str> = buckets.iter().map(|(bound, _)| *bound).collect();
+        assert_eq!(bounds, vec!["50", "100", "250", "500", "1000", "+Inf"]);
+        
+        let counts: Vec<u64> = buckets.iter().map(|(_, count)| *count).collect();
+        assert!(counts.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(counts, vec![1, 3, 3, 4, 4, 5]);
+        assert!(output.contains("alpenglow_finalization_latency_ms_sum 1675\n"));
+        assert!(output.contains("alpenglow_finalization_latency_ms_count 5\n"));
+    }
+    
+    #[cfg(all(feature = "metrics-export", feature = "monitoring", feature = "otlp"))]
+    #[test]
+    fn test_otlp_metrics_round_trip() {
+        use alpenglow_stateright::utils::test_configs;
+        use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+        use opentelemetry_proto::tonic::common::v1::any_value::Value;
+        use opentelemetry_proto::tonic::metrics::v1::{metric, number_data_point};
+        use prost::Message;
+        
+        let config = test_configs()[0].clone();
+        let monitor = crate::monitor::AlpenglowRuntimeMonitor::from_alpenglow_config(config.clone()).unwrap();
+        let bytes = crate::metrics::export_otlp_metrics(&monitor);
+        let request = ExportMetricsServiceRequest::decode(bytes.as_slice()).unwrap();
+        
+        assert_eq!(request.resource_metrics.len(), 1);
+        let resource_metrics = &request.resource_metrics[0];
+        let attributes: std::collections::HashMap<&str, Value> = resource_metrics.resource.as_ref().unwrap().attributes.iter()
+            .map(|kv| (kv.key.as_str(), kv.value.clone().unwrap().value.unwrap()))
+            .collect();
+        assert_eq!(attributes["service.version"], Value::StringValue(VERSION.to_string()));
+        assert_eq!(attributes["alpenglow.validator_count"], Value::IntValue(config.validator_count as i64));
+        
+        // Every exported metric decodes back to a gauge with its value
+        let expected = monitor.export_metrics();
+        let decoded = &resource_metrics.scope_metrics[0].metrics;
+        assert_eq!(decoded.len(), expected.len());
+        for metric in decoded {
+            let Some(metric::Data::Gauge(gauge)) = &metric.data else { panic!("{} is not a gauge", metric.name) };
+            assert_eq!(gauge.data_points[0].value, Some(number_data_point::Value::AsDouble(expected[&metric.name])));
+        }
+    }
+    
+    #[cfg(feature = "metrics-export")]
+    #[test]
+    fn test_labeled_metrics_export() {
+        let config = alpenglow_stateright::Config::new().with_validators(4);
+        let state = alpenglow_stateright::AlpenglowState::init(&config);
+        
+        let samples = crate::metrics::export_labeled_metrics(&state);
+        let names: std::collections::BTreeSet<&str> = samples.iter().map(|(name, _, _)| name.as_str()).collect();
+        assert!(names.contains("alpenglow_validator_bandwidth_usage"));
+        assert!(names.contains("alpenglow_validator_view"));
+        for name in names {
+            let validators: std::collections::BTreeSet<&str> = samples.iter()
+                .filter(|(sample, _, _)| sample == name)
+                .map(|(_, labels, _)| labels["validator"].as_str())
+                .collect();
+            assert_eq!(validators.len(), 4, "{} has {} validator series", name, validators.len());
+        }
+        
+        let text = crate::metrics::render_labeled_metrics(&samples);
+        assert!(text.contains("alpenglow_validator_view{validator=\"3\"} 1\n"));
+        assert_eq!(text.matches("# TYPE alpenglow_validator_view gauge").count(), 1);
+    }
+    
+    #[cfg(feature = "metrics-export")]
+    #[test]
+    fn test_csv_metric_logger() {
+        use std::collections::HashMap;
+        use std::time::UNIX_EPOCH;
+        
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("metrics.csv");
+        let mut logger = crate::metrics::CsvMetricLogger::new(&path).unwrap();
+        
+        let sample = |pairs: &[(&str, f64)]| -> HashMap<String, f64> {
+            pairs.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+        };
+        let at = |secs: u64| UNIX_EPOCH + Duration::from_secs(secs);
+        
+        logger.log_sample(at(100), &sample(&[("latency_ms", 12.5), ("throughput", 3.0)])).unwrap();
+        logger.log_sample(at(101), &sample(&[("latency_ms", 14.0), ("throughput", 2.5)])).unwrap();
+        // A new metric appears mid-run and extends the schema
+        logger.log_sample(at(102), &sample(&[("latency_ms", 11.0), ("throughput", 4.0), ("alerts", 1.0)])).unwrap();
+        
+        let content = std::fs::read_to_string(&path).unwrap();
+        let rows: Vec<Vec<&str>> = content.lines().map(|line| line.split(',').collect()).collect();
+        
+        assert_eq!(rows[0], vec!["timestamp", "latency_ms", "throughput", "alerts"]);
+        assert_eq!(rows.len(), 4);
+        assert!(rows.iter().all(|row| row.len() == 4));
+        assert_eq!(rows[1], vec!["100", "12.5", "3", ""]);
+        assert_eq!(rows[2], vec!["101", "14", "2.5", ""]);
+        assert_eq!(rows[3], vec!["102", "11", "4", "1"]);
+        
+        // Reopening picks up the existing schema
+        let reopened = crate::metrics::CsvMetricLogger::new(&path).unwrap();
+        assert_eq!(reopened.columns(), logger.columns());
+        
+        // Names with separators, quotes or line breaks are quoted per RFC 4180
+        let quoted_path = dir.path().join("quoted.csv");
+        let mut quoted = crate::metrics::CsvMetricLogger::new(&quoted_path).unwrap();
+        quoted.log_sample(at(100), &sample(&[("a,b", 1.0), ("say \"hi\"", 2.0), ("two\nlines", 3.0)])).unwrap();
+        let content = std::fs::read_to_string(&quoted_path).unwrap();
+        assert_eq!(content, "timestamp,\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\"\n100,1,2,3\n");
+        let reopened = crate::metrics::CsvMetricLogger::new(&quoted_path).unwrap();
+        assert_eq!(reopened.columns(), quoted.columns());
+    }
+    
     #[cfg(feature = "dashboards")]
     #[test]
     fn test_dashboard_generation() {
@@ -677,6 +1088,7 @@ mod tests {
         );
         
         assert_eq!(dashboard_data.system_health.overall_status, "healthy");
+        assert_eq!(dashboard_data.system_health.health_score, 100);
         assert!(dashboard_data.validation_metrics.len() > 0);
         
         let json = crate::dashboards::export_dashboard_json(&dashboard_data);
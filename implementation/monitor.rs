@@ -14,10 +14,14 @@
 //! - **Resource Efficient**: Optimized for continuous operation
 //! - **Integration Ready**: Bridges with Actor model for live event streams
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
-use tokio::sync::{broadcast, mpsc};
+use tokio::sync::{broadcast, mpsc, Notify};
 use tokio::time::{interval, timeout};
 use serde::{Deserialize, Serialize};
 use tracing::{error, warn, info, debug, trace};
@@ -39,7 +43,7 @@ use alpenglow_stateright::{
 // Re-use validation types to avoid duplication
 use crate::validation::{
     ValidationEvent, ValidationError, Alert as ValidationAlert, AlertSeverity as ValidationAlertSeverity,
-    ActorModelBridge, ValidationConfig,
+    ActorModelBridge, ValidationConfig, ByzantineChecker, Vote as ValidationVote, SystemSnapshot,
 };
 
 /// Configuration for the Alpenglow runtime monitor (extends ValidationConfig)
@@ -67,6 +71,60 @@ pub struct MonitorConfig {
     pub enable_dashboards: bool,
     /// Enable metric exports (Prometheus, etc.)
     pub enable_metric_exports: bool,
+    /// Time without throughput after which a stall alert fires (milliseconds)
+    #[serde(default = "default_stall_timeout_ms")]
+    pub stall_timeout_ms: u64,
+    /// Upper bounds of the finalization latency histogram buckets (milliseconds)
+    #[serde(default = "default_finalization_latency_buckets_ms")]
+    pub finalization_latency_buckets_ms: Vec<f64>,
+    /// Number of validators in the monitored deployment, 0 if unknown
+    #[serde(default)]
+    pub validator_count: usize,
+    /// Time without the finalized chain growing before a stall alert fires; it escalates to
+    /// critical at twice this (milliseconds)
+    #[serde(default = "default_finalization_stall_threshold_ms")]
+    pub finalization_stall_threshold_ms: u64,
+    /// Number of most recent message delivery delays the latency percentiles are computed over
+    #[serde(default = "default_latency_window_size")]
+    pub latency_window_size: usize,
+    /// Alerts buffered for the slowest runtime alert subscriber (rounded up to a power of two)
+    #[serde(default = "default_alert_channel_capacity")]
+    pub alert_channel_capacity: usize,
+    /// What to do with an alert when the slowest subscriber's buffer is full
+    #[serde(default)]
+    pub alert_overflow_policy: AlertOverflowPolicy,
+}
+
+/// How the runtime alert channel sheds load when a subscriber falls behind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AlertOverflowPolicy {
+    /// Overwrite the oldest buffered alert; lagging subscribers see `RecvError::Lagged`
+    #[default]
+    DropOldest,
+    /// Discard the new alert and keep the buffered ones
+    DropNewest,
+    /// Wait until the slowest subscriber makes room
+    Block,
+}
+
+fn default_stall_timeout_ms() -> u64 {
+    30000
+}
+
+fn default_finalization_latency_buckets_ms() -> Vec<f64> {
+    vec![50.0, 100.0, 250.0, 500.0, 1000.0]
+}
+
+fn default_finalization_stall_threshold_ms() -> u64 {
+    10000
+}
+
+fn default_latency_window_size() -> usize {
+    1000
+}
+
+fn default_alert_channel_capacity() -> usize {
+    1000
 }
 
 impl Default for MonitorConfig {
@@ -83,13 +141,119 @@ impl Default for MonitorConfig {
             max_alerts_per_window: 50,
             enable_dashboards: false,
             enable_metric_exports: false,
+            stall_timeout_ms: default_stall_timeout_ms(),
+            finalization_latency_buckets_ms: default_finalization_latency_buckets_ms(),
+            validator_count: 0,
+            finalization_stall_threshold_ms: default_finalization_stall_threshold_ms(),
+            latency_window_size: default_latency_window_size(),
+            alert_channel_capacity: default_alert_channel_capacity(),
+            alert_overflow_policy: AlertOverflowPolicy::default(),
+        }
+    }
+}
+
+/// Source of the current time for alert decisions
+pub trait MonitorClock: Send + Sync + std::fmt::Debug {
+    fn now(&self) -> SystemTime;
+}
+
+/// Wall clock used by live monitors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl MonitorClock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Manually driven clock for tests and replays; clones share the same time
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<SystemTime>>,
+}
+
+impl MockClock {
+    /// Create a clock stopped at `start`
+    pub fn new(start: SystemTime) -> Self {
+        Self { now: Arc::new(Mutex::new(start)) }
+    }
+    
+    /// Move the clock to `time`
+    pub fn set(&self, time: SystemTime) {
+        *self.now.lock().unwrap() = time;
+    }
+    
+    /// Move the clock forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl MonitorClock for MockClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap()
+    }
+}
+
+/// Metric sample as recorded for replay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricSample {
+    pub recorded_at: SystemTime,
+    pub metrics: RuntimeMetrics,
+}
+
+/// Distribution of observed values over fixed bucket bounds, exported as a Prometheus histogram
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HistogramMetric {
+    /// Upper bounds of the buckets in increasing order; the `+Inf` bucket is implicit
+    pub buckets: Vec<f64>,
+    /// Observations at or below each bound, cumulative like Prometheus `le` buckets
+    pub counts: Vec<u64>,
+    /// Sum of all observed values
+    pub sum: f64,
+    /// Number of observations, the count of the `+Inf` bucket
+    pub count: u64,
+}
+
+impl HistogramMetric {
+    /// Create an empty histogram with the given bucket bounds
+    pub fn new(mut buckets: Vec<f64>) -> Self {
+        buckets.retain(|bound| bound.is_finite());
+        buckets.sort_by(|a, b| a.total_cmp(b));
+        buckets.dedup();
+        let counts = vec![0; buckets.len()];
+        Self { buckets, counts, sum: 0.0, count: 0 }
+    }
+    
+    /// Record one observation
+    pub fn observe(&mut self, value: f64) {
+        for (bound, count) in self.buckets.iter().zip(self.counts.iter_mut()) {
+            if value <= *bound {
+                *count += 1;
+            }
         }
+        self.sum += value;
+        self.count += 1;
+    }
+    
+    /// Render in the Prometheus text exposition format as `<name>_bucket`, `<name>_sum` and `<name>_count`
+    pub fn to_prometheus(&self, name: &str) -> String {
+        let mut output = format!("# TYPE {} histogram\n", name);
+        for (bound, count) in self.buckets.iter().zip(&self.counts) {
+            output.push_str(&format!("{}_bucket{{le=\"{}\"}} {}\n", name, bound, count));
+        }
+        output.push_str(&format!("{}_bucket{{le=\"+Inf\"}} {}\n", name, self.count));
+        output.push_str(&format!("{}_sum {}\n", name, self.sum));
+        output.push_str(&format!("{}_count {}\n", name, self.count));
+        output
     }
 }
 
 impl From<AlpenglowConfig> for MonitorConfig {
     fn from(config: AlpenglowConfig) -> Self {
         Self {
+            validator_count: config.validator_count,
             validation_config: ValidationConfig::from(config),
             ..Default::default()
         }
@@ -156,6 +320,9 @@ pub struct NetworkHealth {
     pub connectivity: HashMap<MainValidatorId, HashMap<MainValidatorId, bool>>,
     /// Message drop rate
     pub message_drop_rate: f64,
+    /// p50/p90/p99 message delivery delay over the configured sliding window
+    #[serde(default)]
+    pub latency_percentiles: BTreeMap<u8, Duration>,
     /// Last health check
     pub last_check: SystemTime,
 }
@@ -169,6 +336,7 @@ impl Default for NetworkHealth {
             active_partitions: 0,
             connectivity: HashMap::new(),
             message_drop_rate: 0.0,
+            latency_percentiles: BTreeMap::new(),
             last_check: SystemTime::now(),
         }
     }
@@ -251,10 +419,12 @@ pub enum RuntimeAlertType {
     // Operational alerts
     ValidatorOffline,
     SyncLag,
+    FinalizationStall,
     ConfigurationDrift,
     HealthCheckFailure,
     
     // Security alerts
+    Equivocation { validator: MainValidatorId, slot: SlotNumber, view: u64 },
     SuspiciousActivity,
     RateLimitExceeded,
     UnauthorizedAccess,
@@ -276,6 +446,16 @@ pub struct RuntimeAlert {
     pub suggested_action: Option<String>,
     pub alert_id: String,
     pub correlation_id: Option<String>,
+    /// Occurrences folded into this alert, counting identical alerts suppressed during its cooldown
+    #[serde(default = "default_repeat_count")]
+    pub repeat_count: u64,
+}
+
+/// Sequence number of the next alert created in this process
+static NEXT_ALERT_ID: AtomicU64 = AtomicU64::new(1);
+
+fn default_repeat_count() -> u64 {
+    1
 }
 
 impl RuntimeAlert {
@@ -296,8 +476,9 @@ impl RuntimeAlert {
             metrics: HashMap::new(),
             metadata: HashMap::new(),
             suggested_action: None,
-            alert_id: uuid::Uuid::new_v4().to_string(),
+            alert_id: format!("alert-{}", NEXT_ALERT_ID.fetch_add(1, Ordering::Relaxed)),
             correlation_id: None,
+            repeat_count: 1,
         }
     }
     
@@ -319,6 +500,12 @@ impl RuntimeAlert {
         self
     }
     
+    /// Set the time the alert fired
+    pub fn with_timestamp(mut self, timestamp: SystemTime) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+    
     /// Set correlation ID for related alerts
     pub fn with_correlation(mut self, correlation_id: String) -> Self {
         self.correlation_id = Some(correlation_id);
@@ -414,7 +601,7 @@ impl RuntimeActorBridge {
         self.validation_bridge.observe_state_change(state)?;
         
         // Cache state for performance monitoring
-        let state_json = serde_json::to_value(state).unwrap_or_default();
+        let state_json = serde_json::to_value(SystemSnapshot::of(state)).unwrap_or_default();
         {
             let mut cache = self.actor_state_cache.write().unwrap();
             cache.insert(actor_id, state_json.clone());
@@ -427,7 +614,7 @@ impl RuntimeActorBridge {
         };
         
         self.monitor_event_sender.send(event)
-            .map_err(|_| AlpenglowError::Other("Failed to send actor state change event".to_string()))?;
+            .map_err(|_| AlpenglowError::NetworkError("Failed to send actor state change event".to_string()))?;
         
         // Track performance
         let processing_time = start_time.elapsed().as_millis() as f64;
@@ -463,7 +650,7 @@ impl RuntimeActorBridge {
         };
         
         self.monitor_event_sender.send(event)
-            .map_err(|_| AlpenglowError::Other("Failed to send message processed event".to_string()))?;
+            .map_err(|_| AlpenglowError::NetworkError("Failed to send message processed event".to_string()))?;
         
         Ok(())
     }
@@ -479,7 +666,7 @@ impl RuntimeActorBridge {
         let event = RuntimeMonitorEvent::ActorError { actor_id, error };
         
         self.monitor_event_sender.send(event)
-            .map_err(|_| AlpenglowError::Other("Failed to send actor error event".to_string()))?;
+            .map_err(|_| AlpenglowError::NetworkError("Failed to send actor error event".to_string()))?;
         
         Ok(())
     }
@@ -504,8 +691,6 @@ struct RuntimeMonitorState {
     network_health: NetworkHealth,
     /// Resource usage tracking
     resource_usage: ResourceUsage,
-    /// Recent alerts (for deduplication and aggregation)
-    recent_alerts: VecDeque<(RuntimeAlertType, SystemTime)>,
     /// Alert aggregation windows
     alert_windows: HashMap<String, Vec<RuntimeAlert>>,
     /// Performance history for trend analysis
@@ -516,6 +701,18 @@ struct RuntimeMonitorState {
     throughput_history: VecDeque<(SystemTime, f64)>,
     /// Error tracking
     error_counts: HashMap<String, u64>,
+    /// Time of the last metric sample showing throughput
+    last_progress: Option<SystemTime>,
+    /// Finalization latencies (milliseconds) accumulated into histogram buckets
+    finalization_latency: HistogramMetric,
+    /// Length of the finalized chain when the protocol state was last observed
+    finalized_chain_len: usize,
+    /// Time the finalized chain last grew, and the slot awaiting finalization since then
+    last_finalization: Option<(SystemTime, SlotNumber)>,
+    /// Most recent message delivery delays (milliseconds), oldest first
+    delivery_delays: VecDeque<u64>,
+    /// Ids of the messages whose delivery delay has already been recorded
+    recorded_deliveries: HashSet<u64>,
     /// Start time for monitoring
     start_time: SystemTime,
     /// Last cleanup time
@@ -539,21 +736,26 @@ impl RuntimeMonitorState {
                 limits: ResourceLimits::default(),
                 measured_at: SystemTime::now(),
             },
-            recent_alerts: VecDeque::new(),
             alert_windows: HashMap::new(),
             performance_history: VecDeque::new(),
             latency_measurements: HashMap::new(),
             throughput_history: VecDeque::new(),
             error_counts: HashMap::new(),
+            last_progress: None,
+            finalization_latency: HistogramMetric::new(default_finalization_latency_buckets_ms()),
+            finalized_chain_len: 0,
+            last_finalization: None,
+            delivery_delays: VecDeque::new(),
+            recorded_deliveries: HashSet::new(),
             start_time: SystemTime::now(),
             last_cleanup: SystemTime::now(),
         }
     }
     
-    /// Update runtime metrics
-    fn update_runtime_metrics(&mut self, metrics: RuntimeMetrics) {
+    /// Update runtime metrics sampled at `now`
+    fn update_runtime_metrics(&mut self, metrics: RuntimeMetrics, now: SystemTime) {
         self.runtime_metrics = metrics.clone();
-        self.performance_history.push_back((SystemTime::now(), metrics));
+        self.performance_history.push_back((now, metrics));
         
         // Keep only recent history
         while self.performance_history.len() > 1000 {
@@ -591,30 +793,85 @@ impl RuntimeMonitorState {
         }
     }
     
-    /// Check if alert should be suppressed (deduplication)
-    fn should_suppress_alert(&mut self, alert_type: &RuntimeAlertType, config: &MonitorConfig) -> bool {
-        let now = SystemTime::now();
-        let cooldown = Duration::from_millis(config.alert_cooldown_ms);
-        
-        // Check recent alerts for duplicates
-        let recent_count = self.recent_alerts.iter()
-            .filter(|(atype, timestamp)| {
-                atype == alert_type && now.duration_since(*timestamp).unwrap_or_default() < cooldown
-            })
-            .count();
-        
-        if recent_count > 0 {
-            return true; // Suppress duplicate
+    /// Record a metric sample taken at `now` and return the alerts it fires; depends only on
+    /// the samples seen so far and `now`, so replaying a recording regenerates the same alerts.
+    /// Repeats are left to `AlertPublisher` to deduplicate.
+    fn record_sample(&mut self, metrics: RuntimeMetrics, config: &MonitorConfig, now: SystemTime) -> Vec<RuntimeAlert> {
+        let mut alerts = performance_alerts(&metrics, now);
+        
+        if metrics.current_throughput > 0.0 {
+            self.last_progress = Some(now);
+        }
+        let since = *self.last_progress.get_or_insert(now);
+        let stalled_for = now.duration_since(since).unwrap_or_default();
+        if stalled_for > Duration::from_millis(config.stall_timeout_ms) {
+            alerts.push(RuntimeAlert::new(
+                RuntimeAlertType::FinalizationStall,
+                ValidationAlertSeverity::Critical,
+                format!("No finalization progress for {}ms", stalled_for.as_millis())
+            ).with_metric("stalled_ms".to_string(), stalled_for.as_millis() as f64)
+             .with_action("Check leader liveness and vote propagation".to_string())
+             .with_timestamp(now));
         }
         
-        // Add to recent alerts
-        self.recent_alerts.push_back((*alert_type, now));
+        self.update_runtime_metrics(metrics, now);
+        alerts
+    }
+    
+    /// Record the finalized chain observed at `now`, restarting the stall timer when it grew
+    fn observe_finalized_chain(&mut self, chain_len: usize, current_slot: SlotNumber, now: SystemTime) {
+        match &mut self.last_finalization {
+            Some((_, slot)) if chain_len <= self.finalized_chain_len => *slot = current_slot,
+            _ => {
+                self.last_finalization = Some((now, current_slot));
+                self.finalized_chain_len = chain_len;
+            }
+        }
+    }
+    
+    /// Record the delays of messages delivered since the last observation and recompute the
+    /// latency percentiles over the most recent `window` delays
+    fn observe_delivery_delays(&mut self, delays: impl IntoIterator<Item = (u64, u64)>, window: usize) {
+        let mut recorded = HashSet::new();
+        for (message_id, delay) in delays {
+            if !self.recorded_deliveries.contains(&message_id) {
+                self.delivery_delays.push_back(delay);
+            }
+            recorded.insert(message_id);
+        }
+        self.recorded_deliveries = recorded;
         
-        // Clean up old alerts
-        let cutoff = now - cooldown * 10;
-        self.recent_alerts.retain(|(_, timestamp)| *timestamp > cutoff);
+        while self.delivery_delays.len() > window {
+            self.delivery_delays.pop_front();
+        }
+        self.network_health.latency_percentiles = latency_percentiles(&self.delivery_delays);
+    }
+    
+    /// Stall alert if the finalized chain has not grown for longer than the configured
+    /// threshold at `now`, critical once twice the threshold has passed
+    fn finalization_stall_alert(&self, config: &MonitorConfig, now: SystemTime) -> Option<RuntimeAlert> {
+        let (since, slot) = self.last_finalization?;
+        let stalled_for = now.duration_since(since).unwrap_or_default();
+        let threshold = Duration::from_millis(config.finalization_stall_threshold_ms);
+        if stalled_for <= threshold {
+            return None;
+        }
         
-        false
+        let severity = if stalled_for >= threshold * 2 {
+            ValidationAlertSeverity::Critical
+        } else {
+            ValidationAlertSeverity::Warning
+        };
+        let mut alert = RuntimeAlert::new(
+            RuntimeAlertType::FinalizationStall,
+            severity,
+            format!("No block finalized for {}ms, slot {} stalled", stalled_for.as_millis(), slot)
+        ).with_metric("stalled_ms".to_string(), stalled_for.as_millis() as f64)
+         .with_metadata("slot".to_string(), slot.to_string())
+         .with_action("Check leader liveness and vote propagation".to_string())
+         .with_timestamp(now);
+        alert.slot = Some(slot);
+        Some(alert)
     }
     
     /// Add alert to aggregation window
@@ -663,37 +920,407 @@ impl RuntimeMonitorState {
     }
 }
 
+/// Nearest-rank p50/p90/p99 of `delays_ms`, empty when there are no delays
+fn latency_percentiles(delays_ms: &VecDeque<u64>) -> BTreeMap<u8, Duration> {
+    let mut sorted: Vec<u64> = delays_ms.iter().copied().collect();
+    sorted.sort_unstable();
+    if sorted.is_empty() {
+        return BTreeMap::new();
+    }
+    
+    [50u8, 90, 99].into_iter()
+        .map(|p| {
+            let rank = (p as usize * sorted.len()).div_ceil(100).max(1);
+            (p, Duration::from_millis(sorted[rank - 1]))
+        })
+        .collect()
+}
+
+/// Threshold alerts for a metric sample taken at `now`
+fn performance_alerts(metrics: &RuntimeMetrics, now: SystemTime) -> Vec<RuntimeAlert> {
+    let mut alerts = Vec::new();
+    
+    // Check throughput
+    if metrics.current_throughput < 0.5 { // Less than 0.5 blocks/sec
+        let alert = RuntimeAlert::new(
+            RuntimeAlertType::LowThroughput,
+            ValidationAlertSeverity::Warning,
+            format!("Low throughput: {} blocks/sec", metrics.current_throughput)
+        ).with_metric("throughput".to_string(), metrics.current_throughput)
+         .with_action("Check network and validator health".to_string());
+        
+        alerts.push(alert.with_timestamp(now));
+    }
+    
+    // Check latency
+    if metrics.avg_latency_ms > 1000.0 { // > 1 second
+        let alert = RuntimeAlert::new(
+            RuntimeAlertType::HighLatency,
+            ValidationAlertSeverity::Warning,
+            format!("High average latency: {}ms", metrics.avg_latency_ms)
+        ).with_metric("latency_ms".to_string(), metrics.avg_latency_ms);
+        
+        alerts.push(alert.with_timestamp(now));
+    }
+    
+    // Check error rate
+    if metrics.error_rate > 10.0 { // > 10 errors/sec
+        let alert = RuntimeAlert::new(
+            RuntimeAlertType::HealthCheckFailure,
+            ValidationAlertSeverity::Error,
+            format!("High error rate: {} errors/sec", metrics.error_rate)
+        ).with_metric("error_rate".to_string(), metrics.error_rate)
+         .with_action("Check system logs for error details".to_string());
+        
+        alerts.push(alert.with_timestamp(now));
+    }
+    
+    // Check queue depths, in name order so replays emit alerts identically
+    let mut queues: Vec<_> = metrics.queue_depths.iter().collect();
+    queues.sort();
+    for (queue_name, depth) in queues {
+        if *depth > 1000 { // Queue backlog
+            let alert = RuntimeAlert::new(
+                RuntimeAlertType::QueueBacklog,
+                ValidationAlertSeverity::Warning,
+                format!("Queue {} has backlog: {} items", queue_name, depth)
+            ).with_metric("queue_depth".to_string(), *depth as f64)
+             .with_metadata("queue_name".to_string(), queue_name.clone())
+             .with_action("Check queue processing and consider scaling".to_string());
+            
+            alerts.push(alert.with_timestamp(now));
+        }
+    }
+    
+    alerts
+}
+
+/// Resource limit alerts for the current usage
+fn resource_alerts(usage: &ResourceUsage) -> Vec<RuntimeAlert> {
+    let mut alerts = Vec::new();
+    let limits = &usage.limits;
+    
+    // Check CPU usage
+    if usage.system_totals.total_cpu_usage_pct > limits.max_cpu_pct {
+        let alert = RuntimeAlert::new(
+            RuntimeAlertType::CPUOverload,
+            ValidationAlertSeverity::Warning,
+            format!("High CPU usage: {}% > {}%", 
+                usage.system_totals.total_cpu_usage_pct, limits.max_cpu_pct)
+        ).with_metric("cpu_usage_pct".to_string(), usage.system_totals.total_cpu_usage_pct)
+         .with_metric("cpu_limit_pct".to_string(), limits.max_cpu_pct)
+         .with_action("Consider reducing load or adding CPU capacity".to_string());
+        
+        alerts.push(alert);
+    }
+    
+    // Check memory usage
+    if usage.system_totals.total_memory_usage_bytes > limits.max_memory_bytes {
+        let alert = RuntimeAlert::new(
+            RuntimeAlertType::MemoryPressure,
+            ValidationAlertSeverity::Warning,
+            format!("High memory usage: {}MB > {}MB", 
+                usage.system_totals.total_memory_usage_bytes / 1024 / 1024,
+                limits.max_memory_bytes / 1024 / 1024)
+        ).with_metric("memory_usage_bytes".to_string(), usage.system_totals.total_memory_usage_bytes as f64)
+         .with_metric("memory_limit_bytes".to_string(), limits.max_memory_bytes as f64)
+         .with_action("Consider reducing memory usage or adding memory".to_string());
+        
+        alerts.push(alert);
+    }
+    
+    // Check disk usage
+    if usage.system_totals.total_disk_usage_bytes > limits.max_disk_bytes {
+        let alert = RuntimeAlert::new(
+            RuntimeAlertType::DiskSpaceWarning,
+            ValidationAlertSeverity::Warning,
+            format!("High disk usage: {}GB > {}GB", 
+                usage.system_totals.total_disk_usage_bytes / 1024 / 1024 / 1024,
+                limits.max_disk_bytes / 1024 / 1024 / 1024)
+        ).with_metric("disk_usage_bytes".to_string(), usage.system_totals.total_disk_usage_bytes as f64)
+         .with_metric("disk_limit_bytes".to_string(), limits.max_disk_bytes as f64)
+         .with_action("Clean up old data or add disk capacity".to_string());
+        
+        alerts.push(alert);
+    }
+    
+    // Check connection count
+    if usage.system_totals.total_connections > limits.max_connections {
+        let alert = RuntimeAlert::new(
+            RuntimeAlertType::ConnectionLimitReached,
+            ValidationAlertSeverity::Warning,
+            format!("High connection count: {} > {}", 
+                usage.system_totals.total_connections, limits.max_connections)
+        ).with_metric("connections".to_string(), usage.system_totals.total_connections as f64)
+         .with_metric("connection_limit".to_string(), limits.max_connections as f64)
+         .with_action("Check for connection leaks or increase limits".to_string());
+        
+        alerts.push(alert);
+    }
+    
+    alerts
+}
+
+/// Network health alerts for the current health summary
+fn network_alerts(health: &NetworkHealth) -> Vec<RuntimeAlert> {
+    let mut alerts = Vec::new();
+    
+    // Check average RTT
+    if health.avg_rtt_ms > 500.0 { // > 500ms
+        let alert = RuntimeAlert::new(
+            RuntimeAlertType::HighLatency,
+            ValidationAlertSeverity::Warning,
+            format!("High network RTT: {}ms", health.avg_rtt_ms)
+        ).with_metric("rtt_ms".to_string(), health.avg_rtt_ms)
+         .with_action("Check network connectivity and routing".to_string());
+        
+        alerts.push(alert);
+    }
+    
+    // Check packet loss
+    if health.packet_loss_pct > 5.0 { // > 5%
+        let alert = RuntimeAlert::new(
+            RuntimeAlertType::HighPacketLoss,
+            ValidationAlertSeverity::Warning,
+            format!("High packet loss: {}%", health.packet_loss_pct)
+        ).with_metric("packet_loss_pct".to_string(), health.packet_loss_pct)
+         .with_action("Check network quality and congestion".to_string());
+        
+        alerts.push(alert);
+    }
+    
+    // Check active partitions
+    if health.active_partitions > 0 {
+        let alert = RuntimeAlert::new(
+            RuntimeAlertType::PartitionDetected,
+            ValidationAlertSeverity::Error,
+            format!("Network partitions detected: {}", health.active_partitions)
+        ).with_metric("partitions".to_string(), health.active_partitions as f64)
+         .with_action("Check network connectivity between validators".to_string());
+        
+        alerts.push(alert);
+    }
+    
+    // Check message drop rate
+    if health.message_drop_rate > 0.1 { // > 10%
+        let alert = RuntimeAlert::new(
+            RuntimeAlertType::HighPacketLoss,
+            ValidationAlertSeverity::Warning,
+            format!("High message drop rate: {}%", health.message_drop_rate * 100.0)
+        ).with_metric("drop_rate_pct".to_string(), health.message_drop_rate * 100.0)
+         .with_action("Check network capacity and message queues".to_string());
+        
+        alerts.push(alert);
+    }
+    
+    alerts
+}
+
+/// Bounded runtime alert channel applying an `AlertOverflowPolicy` and counting shed alerts;
+/// identical alerts within the cooldown are folded into the last delivered one
+#[derive(Debug, Clone)]
+struct AlertPublisher {
+    sender: broadcast::Sender<RuntimeAlert>,
+    capacity: usize,
+    policy: AlertOverflowPolicy,
+    dropped: Arc<AtomicU64>,
+    sent: Arc<AtomicU64>,
+    cooldown: Duration,
+    /// Last delivered alert per type and severity, with its running repeat count
+    delivered: Arc<Mutex<HashMap<String, RuntimeAlert>>>,
+    /// Signalled by subscribers after they receive or unsubscribe, waking blocked publishers
+    room: Arc<Notify>,
+}
+
+impl AlertPublisher {
+    fn new(config: &MonitorConfig) -> Self {
+        // tokio rounds the buffer up to a power of two; track the real size so counts match evictions
+        let capacity = config.alert_channel_capacity.max(1).next_power_of_two();
+        let (sender, _) = broadcast::channel(capacity);
+        Self {
+            sender,
+            capacity,
+            policy: config.alert_overflow_policy,
+            dropped: Arc::new(AtomicU64::new(0)),
+            sent: Arc::new(AtomicU64::new(0)),
+            cooldown: Duration::from_millis(config.alert_cooldown_ms),
+            delivered: Arc::new(Mutex::new(HashMap::new())),
+            room: Arc::new(Notify::new()),
+        }
+    }
+    
+    fn subscribe(&self) -> RuntimeAlertReceiver {
+        RuntimeAlertReceiver {
+            receiver: self.sender.subscribe(),
+            room: RoomSignal(Arc::clone(&self.room)),
+        }
+    }
+    
+    fn is_full(&self) -> bool {
+        self.sender.receiver_count() > 0 && self.sender.len() >= self.capacity
+    }
+    
+    /// Alerts shed so far because a subscriber's buffer was full
+    fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+    
+    /// Alerts sent so far, after deduplication
+    fn sent(&self) -> u64 {
+        self.sent.load(Ordering::Relaxed)
+    }
+    
+    /// Last delivered alert of each type and severity
+    fn delivered(&self) -> Vec<RuntimeAlert> {
+        self.delivered.lock().unwrap().values().cloned().collect()
+    }
+    
+    /// Fold `alert` into the last delivered alert with the same type and severity while that
+    /// one's cooldown runs; otherwise return it for delivery, carrying the previous repeat count
+    fn deduplicate(&self, mut alert: RuntimeAlert) -> Option<RuntimeAlert> {
+        let key = format!("{:?}/{:?}", alert.alert_type, alert.severity);
+        let mut delivered = self.delivered.lock().unwrap();
+        if let Some(last) = delivered.get_mut(&key) {
+            if alert.timestamp.duration_since(last.timestamp).unwrap_or_default() < self.cooldown {
+                last.repeat_count += 1;
+                return None;
+            }
+            alert = alert.with_metric("previous_repeat_count".to_string(), last.repeat_count as f64);
+        }
+        delivered.insert(key, alert.clone());
+        Some(alert)
+    }
+    
+    /// Send `alert` to every subscriber unless it repeats a recent one, shedding it or an
+    /// older one if the buffer is full, or waiting for room under `Block`. Callers must not
+    /// hold the monitor state lock, since a blocked publish lasts until a subscriber receives.
+    async fn publish(&self, alert: RuntimeAlert) {
+        let Some(alert) = self.deduplicate(alert) else {
+            return;
+        };
+        if self.is_full() {
+            match self.policy {
+                AlertOverflowPolicy::DropOldest => {
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                }
+                AlertOverflowPolicy::DropNewest => {
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                    return;
+                }
+                AlertOverflowPolicy::Block => {
+                    loop {
+                        // Register before re-checking so a receive in between is not missed
+                        let room = self.room.notified();
+                        tokio::pin!(room);
+                        room.as_mut().enable();
+                        if !self.is_full() {
+                            break;
+                        }
+                        room.await;
+                    }
+                }
+            }
+        }
+        self.sent.fetch_add(1, Ordering::Relaxed);
+        let _ = self.sender.send(alert);
+    }
+}
+
+/// Subscription to runtime alerts; receiving makes room for publishers blocked under
+/// `AlertOverflowPolicy::Block`
+#[derive(Debug)]
+pub struct RuntimeAlertReceiver {
+    receiver: broadcast::Receiver<RuntimeAlert>,
+    // Declared after `receiver` so it is dropped after it and woken publishers no longer count it
+    room: RoomSignal,
+}
+
+/// Wakes blocked publishers when dropped
+#[derive(Debug)]
+struct RoomSignal(Arc<Notify>);
+
+impl Drop for RoomSignal {
+    fn drop(&mut self) {
+        self.0.notify_waiters();
+    }
+}
+
+impl RuntimeAlertReceiver {
+    /// Receive the next alert, or `RecvError::Lagged` with the number of alerts missed
+    pub async fn recv(&mut self) -> Result<RuntimeAlert, broadcast::error::RecvError> {
+        let alert = self.receiver.recv().await;
+        self.room.0.notify_waiters();
+        alert
+    }
+    
+    /// Receive the next alert if one is buffered
+    pub fn try_recv(&mut self) -> Result<RuntimeAlert, broadcast::error::TryRecvError> {
+        let alert = self.receiver.try_recv();
+        self.room.0.notify_waiters();
+        alert
+    }
+}
+
 /// Main Alpenglow runtime monitor with Actor model integration
 pub struct AlpenglowRuntimeMonitor {
     config: MonitorConfig,
     state: Arc<RwLock<RuntimeMonitorState>>,
     event_sender: mpsc::UnboundedSender<RuntimeMonitorEvent>,
-    event_receiver: Mutex<mpsc::UnboundedReceiver<RuntimeMonitorEvent>>,
-    alert_sender: broadcast::Sender<RuntimeAlert>,
+    event_receiver: tokio::sync::Mutex<mpsc::UnboundedReceiver<RuntimeMonitorEvent>>,
+    alert_sender: AlertPublisher,
     validation_alert_sender: broadcast::Sender<ValidationAlert>,
     running: Arc<Mutex<bool>>,
     actor_bridge: Option<RuntimeActorBridge>,
+    clock: Arc<dyn MonitorClock>,
+    /// JSON-lines file every metric sample is appended to, for later replay
+    recording: Option<PathBuf>,
+    /// Bounded per-validator vote index used to detect equivocation across observations
+    byzantine_checker: ByzantineChecker,
 }
 
 impl AlpenglowRuntimeMonitor {
     /// Create a new Alpenglow runtime monitor
     pub fn new(config: MonitorConfig) -> Self {
         let (event_sender, event_receiver) = mpsc::unbounded_channel();
-        let (alert_sender, _) = broadcast::channel(1000);
+        let alert_sender = AlertPublisher::new(&config);
         let (validation_alert_sender, _) = broadcast::channel(1000);
         
+        let mut state = RuntimeMonitorState::new();
+        state.finalization_latency = HistogramMetric::new(config.finalization_latency_buckets_ms.clone());
+        let byzantine_checker = ByzantineChecker::new(config.validation_config.clone());
+        
         Self {
             config,
-            state: Arc::new(RwLock::new(RuntimeMonitorState::new())),
+            state: Arc::new(RwLock::new(state)),
             event_sender,
-            event_receiver: Mutex::new(event_receiver),
+            event_receiver: tokio::sync::Mutex::new(event_receiver),
             alert_sender,
             validation_alert_sender,
             running: Arc::new(Mutex::new(false)),
             actor_bridge: None,
+            clock: Arc::new(SystemClock),
+            recording: None,
+            byzantine_checker,
         }
     }
     
+    /// Configuration the monitor was created with
+    pub fn config(&self) -> &MonitorConfig {
+        &self.config
+    }
+    
+    /// Use `clock` for alert decisions, e.g. a `MockClock` in tests
+    pub fn with_clock(mut self, clock: impl MonitorClock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+    
+    /// Append every metric sample to `path` so the session can be replayed
+    pub fn with_recording(mut self, path: impl Into<PathBuf>) -> Self {
+        self.recording = Some(path.into());
+        self
+    }
+    
     /// Create runtime monitor with Actor model integration
     pub fn new_with_actor_integration(
         config: MonitorConfig,
@@ -713,7 +1340,7 @@ impl AlpenglowRuntimeMonitor {
     /// Create runtime monitor from Alpenglow config
     pub fn from_alpenglow_config(config: AlpenglowConfig) -> AlpenglowResult<Self> {
         let monitor_config = MonitorConfig::from(config.clone());
-        let model = alpenglow_stateright::create_model(config)?;
+        let model = alpenglow_stateright::integration::create_alpenglow_model(config, HashSet::new())?;
         Self::new_with_actor_integration(monitor_config, model)
     }
 
@@ -722,16 +1349,121 @@ impl AlpenglowRuntimeMonitor {
         self.event_sender.clone()
     }
 
-    /// Subscribe to runtime alerts
-    pub fn subscribe_runtime_alerts(&self) -> broadcast::Receiver<RuntimeAlert> {
+    /// Subscribe to runtime alerts; the buffer and overflow behavior follow
+    /// `MonitorConfig::alert_channel_capacity` and `alert_overflow_policy`, and a subscriber
+    /// that falls behind under `DropOldest` receives `RecvError::Lagged` with the number missed
+    pub fn subscribe_runtime_alerts(&self) -> RuntimeAlertReceiver {
         self.alert_sender.subscribe()
     }
     
+    /// Last delivered runtime alert of each type and severity; `repeat_count` includes the
+    /// identical alerts suppressed since it was delivered
+    pub fn deduplicated_alerts(&self) -> Vec<RuntimeAlert> {
+        self.alert_sender.delivered()
+    }
+    
     /// Subscribe to validation alerts (bridged from validation module)
     pub fn subscribe_validation_alerts(&self) -> broadcast::Receiver<ValidationAlert> {
         self.validation_alert_sender.subscribe()
     }
     
+    /// Record a metric sample at the monitor clock's current time and send the alerts it fires
+    pub async fn record_metrics(&self, metrics: RuntimeMetrics) -> AlpenglowResult<Vec<RuntimeAlert>> {
+        let now = self.clock.now();
+        self.append_sample(&MetricSample { recorded_at: now, metrics: metrics.clone() })?;
+        
+        let alerts = self.state.write().unwrap().record_sample(metrics, &self.config, now);
+        for alert in &alerts {
+            self.alert_sender.publish(alert.clone()).await;
+        }
+        Ok(alerts)
+    }
+    
+    /// Observe the protocol state at the monitor clock's current time, updating the delivery
+    /// latency percentiles from `network_delivery_time`, and send a `FinalizationStall` alert
+    /// if `votor_finalized_chain` has not grown for too long
+    pub async fn observe_protocol_state(&self, protocol_state: &alpenglow_stateright::AlpenglowState) -> Option<RuntimeAlert> {
+        let now = self.clock.now();
+        let alert = {
+            let mut state = self.state.write().unwrap();
+            state.observe_delivery_delays(
+                protocol_state.network_delivery_time.iter().map(|(message, delay)| (message.id, *delay)),
+                self.config.latency_window_size,
+            );
+            state.observe_finalized_chain(protocol_state.votor_finalized_chain.len(), protocol_state.current_slot, now);
+            state.finalization_stall_alert(&self.config, now)?
+        };
+        
+        self.alert_sender.publish(alert.clone()).await;
+        Some(alert)
+    }
+    
+    /// Record an observed vote and send an `Equivocation` alert if the validator already voted
+    /// for a different block in the same slot and view
+    pub async fn observe_vote(&self, vote: &ValidationVote) -> Option<RuntimeAlert> {
+        let alert = self.equivocation_alert(vote)?;
+        self.alert_sender.publish(alert.clone()).await;
+        Some(alert)
+    }
+    
+    /// Record an observed vote, returning the `Equivocation` alert it fires without sending it
+    fn equivocation_alert(&self, vote: &ValidationVote) -> Option<RuntimeAlert> {
+        let earlier = self.byzantine_checker.record_vote(vote)?;
+        
+        let mut alert = RuntimeAlert::new(
+            RuntimeAlertType::Equivocation { validator: vote.validator, slot: vote.slot, view: vote.view },
+            ValidationAlertSeverity::Critical,
+            format!("Validator {} equivocated in slot {} view {}: voted for blocks {} and {}",
+                vote.validator, vote.slot, vote.view, earlier.block_hash, vote.block_hash)
+        ).with_metadata("first_block".to_string(), earlier.block_hash.to_string())
+         .with_metadata("second_block".to_string(), vote.block_hash.to_string())
+         .with_action("Mark the validator Byzantine and preserve both signed votes as evidence".to_string())
+         .with_timestamp(self.clock.now());
+        alert.validator_id = Some(vote.validator);
+        alert.slot = Some(vote.slot);
+        alert.view = Some(vote.view);
+        Some(alert)
+    }
+    
+    /// Feed the samples recorded at `log_path` through the alert logic on a `MockClock`
+    /// and fresh state, returning the alerts the recorded session fired
+    pub fn replay_from_metrics(&self, log_path: impl AsRef<Path>) -> AlpenglowResult<Vec<RuntimeAlert>> {
+        let file = File::open(log_path.as_ref())
+            .map_err(|e| AlpenglowError::IoError(format!("Failed to open metrics log: {}", e)))?;
+        
+        let clock = MockClock::new(UNIX_EPOCH);
+        let mut state = RuntimeMonitorState::new();
+        let mut alerts = Vec::new();
+        
+        for line in BufReader::new(file).lines() {
+            let line = line.map_err(|e| AlpenglowError::IoError(format!("Failed to read metrics log: {}", e)))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let sample: MetricSample = serde_json::from_str(&line)
+                .map_err(|e| AlpenglowError::SerializationError(format!("Invalid metric sample: {}", e)))?;
+            
+            clock.set(sample.recorded_at);
+            alerts.extend(state.record_sample(sample.metrics, &self.config, clock.now()));
+        }
+        
+        Ok(alerts)
+    }
+    
+    /// Append a sample to the recording, if one is configured
+    fn append_sample(&self, sample: &MetricSample) -> AlpenglowResult<()> {
+        let Some(path) = &self.recording else {
+            return Ok(());
+        };
+        
+        let line = serde_json::to_string(sample)
+            .map_err(|e| AlpenglowError::SerializationError(format!("Failed to serialize metric sample: {}", e)))?;
+        let mut file = OpenOptions::new().create(true).append(true).open(path)
+            .map_err(|e| AlpenglowError::IoError(format!("Failed to open metrics log: {}", e)))?;
+        writeln!(file, "{}", line)
+            .map_err(|e| AlpenglowError::IoError(format!("Failed to write metrics log: {}", e)))
+    }
+    
     /// Get Actor bridge for direct integration
     pub fn actor_bridge(&self) -> Option<&RuntimeActorBridge> {
         self.actor_bridge.as_ref()
@@ -742,7 +1474,7 @@ impl AlpenglowRuntimeMonitor {
         {
             let mut running = self.running.lock().unwrap();
             if *running {
-                return Err(AlpenglowError::Other("Monitor is already running".to_string()));
+                return Err(AlpenglowError::StateInconsistency("Monitor is already running".to_string()));
             }
             *running = true;
         }
@@ -755,6 +1487,7 @@ impl AlpenglowRuntimeMonitor {
         let runtime_alert_sender = self.alert_sender.clone();
         let validation_alert_sender = self.validation_alert_sender.clone();
         let running = Arc::clone(&self.running);
+        let clock = Arc::clone(&self.clock);
         
         tokio::spawn(async move {
             let mut cleanup_interval = interval(Duration::from_secs(60));
@@ -774,15 +1507,16 @@ impl AlpenglowRuntimeMonitor {
                     }
                     
                     _ = performance_interval.tick() => {
-                        Self::check_performance_metrics(&state, &config, &runtime_alert_sender).await;
+                        Self::check_performance_metrics(&state, &clock, &runtime_alert_sender).await;
+                        Self::check_finalization_stall(&state, &config, &clock, &runtime_alert_sender).await;
                     }
                     
                     _ = resource_interval.tick() => {
-                        Self::check_resource_usage(&state, &config, &runtime_alert_sender).await;
+                        Self::check_resource_usage(&state, &runtime_alert_sender).await;
                     }
                     
                     _ = network_interval.tick() => {
-                        Self::check_network_health(&state, &config, &runtime_alert_sender).await;
+                        Self::check_network_health(&state, &runtime_alert_sender).await;
                     }
                 }
             }
@@ -803,7 +1537,7 @@ impl AlpenglowRuntimeMonitor {
 
     /// Process incoming events
     async fn process_events(&self) {
-        let mut receiver = self.event_receiver.lock().unwrap();
+        let mut receiver = self.event_receiver.lock().await;
         
         while let Some(event) = receiver.recv().await {
             if !*self.running.lock().unwrap() {
@@ -818,7 +1552,19 @@ impl AlpenglowRuntimeMonitor {
 
     /// Handle a single monitoring event
     async fn handle_event(&self, event: RuntimeMonitorEvent) -> AlpenglowResult<()> {
+        // Publish only once the lock is released; a blocked publish may wait on a subscriber
+        let alerts = self.apply_event(event)?;
+        for alert in alerts {
+            self.alert_sender.publish(alert).await;
+        }
+        
+        Ok(())
+    }
+    
+    /// Update the monitor state with a single event and return the alerts it fires
+    fn apply_event(&self, event: RuntimeMonitorEvent) -> AlpenglowResult<Vec<RuntimeAlert>> {
         let mut state = self.state.write().unwrap();
+        let mut alerts = Vec::new();
         
         match event {
             RuntimeMonitorEvent::ValidationEvent(validation_event) => {
@@ -835,6 +1581,7 @@ impl AlpenglowRuntimeMonitor {
                         ).unwrap_or_default();
                         
                         state.runtime_metrics.avg_latency_ms = latency.as_millis() as f64;
+                        state.finalization_latency.observe(latency.as_millis() as f64);
                         
                         // Check if latency exceeds thresholds
                         if latency.as_millis() > 1000 { // > 1 second
@@ -846,9 +1593,12 @@ impl AlpenglowRuntimeMonitor {
                             ).with_metric("latency_ms".to_string(), latency.as_millis() as f64)
                              .with_metadata("slot".to_string(), block.slot.to_string());
                             
-                            let _ = self.alert_sender.send(alert);
+                            alerts.push(alert);
                         }
                     }
+                    ValidationEvent::VoteCast { vote, .. } => {
+                        alerts.extend(self.equivocation_alert(&vote));
+                    }
                     _ => {} // Other validation events handled by validation module
                 }
             }
@@ -856,7 +1606,9 @@ impl AlpenglowRuntimeMonitor {
             RuntimeMonitorEvent::PerformanceMetricsUpdate(metrics) => {
                 debug!("Performance metrics updated: throughput={}, latency={}ms", 
                     metrics.current_throughput, metrics.avg_latency_ms);
-                state.update_runtime_metrics(metrics);
+                let now = self.clock.now();
+                self.append_sample(&MetricSample { recorded_at: now, metrics: metrics.clone() })?;
+                alerts.extend(state.record_sample(metrics, &self.config, now));
             }
             
             RuntimeMonitorEvent::LatencyMeasurement { operation, latency_ms, timestamp: _ } => {
@@ -865,6 +1617,7 @@ impl AlpenglowRuntimeMonitor {
                 // Update relevant metrics
                 if operation == "finalization" {
                     state.runtime_metrics.avg_latency_ms = latency_ms;
+                    state.finalization_latency.observe(latency_ms);
                     
                     // Check thresholds
                     if latency_ms > 500.0 { // > 500ms
@@ -875,9 +1628,7 @@ impl AlpenglowRuntimeMonitor {
                         ).with_metric("latency_ms".to_string(), latency_ms)
                          .with_metadata("operation".to_string(), operation);
                         
-                        if !state.should_suppress_alert(&RuntimeAlertType::HighLatency, &self.config) {
-                            let _ = self.alert_sender.send(alert);
-                        }
+                        alerts.push(alert);
                     }
                 }
             }
@@ -895,9 +1646,7 @@ impl AlpenglowRuntimeMonitor {
                     ).with_metric("throughput".to_string(), throughput)
                      .with_action("Check network connectivity and validator health".to_string());
                     
-                    if !state.should_suppress_alert(&RuntimeAlertType::LowThroughput, &self.config) {
-                        let _ = self.alert_sender.send(alert);
-                    }
+                    alerts.push(alert);
                 }
             }
             
@@ -919,9 +1668,7 @@ impl AlpenglowRuntimeMonitor {
                  .with_metric("available_bytes".to_string(), available_bytes as f64)
                  .with_action("Consider reducing memory usage or adding more memory".to_string());
                 
-                if !state.should_suppress_alert(&RuntimeAlertType::MemoryPressure, &self.config) {
-                    let _ = self.alert_sender.send(alert);
-                }
+                alerts.push(alert);
             }
             
             RuntimeMonitorEvent::CPUSpike { usage_pct, duration_ms } => {
@@ -935,15 +1682,16 @@ impl AlpenglowRuntimeMonitor {
                     ).with_metric("usage_pct".to_string(), usage_pct)
                      .with_metric("duration_ms".to_string(), duration_ms as f64);
                     
-                    if !state.should_suppress_alert(&RuntimeAlertType::CPUOverload, &self.config) {
-                        let _ = self.alert_sender.send(alert);
-                    }
+                    alerts.push(alert);
                 }
             }
             
             RuntimeMonitorEvent::NetworkHealthUpdate(health) => {
                 debug!("Network health updated: RTT={}ms, Loss={}%", health.avg_rtt_ms, health.packet_loss_pct);
-                state.network_health = health;
+                state.network_health = NetworkHealth {
+                    latency_percentiles: std::mem::take(&mut state.network_health.latency_percentiles),
+                    ..health
+                };
             }
             
             RuntimeMonitorEvent::ConnectivityChange { validator_id, connected } => {
@@ -957,9 +1705,7 @@ impl AlpenglowRuntimeMonitor {
                     ).with_metadata("validator_id".to_string(), validator_id.to_string())
                      .with_action("Check network connectivity to validator".to_string());
                     
-                    if !state.should_suppress_alert(&RuntimeAlertType::ConnectivityLoss, &self.config) {
-                        let _ = self.alert_sender.send(alert);
-                    }
+                    alerts.push(alert);
                 }
             }
             
@@ -976,9 +1722,7 @@ impl AlpenglowRuntimeMonitor {
                      .with_metadata("from".to_string(), from.to_string())
                      .with_metadata("to".to_string(), to.to_string());
                     
-                    if !state.should_suppress_alert(&RuntimeAlertType::HighLatency, &self.config) {
-                        let _ = self.alert_sender.send(alert);
-                    }
+                    alerts.push(alert);
                 }
             }
             
@@ -993,9 +1737,7 @@ impl AlpenglowRuntimeMonitor {
                     ).with_metadata("validator_id".to_string(), validator_id.to_string())
                      .with_action("Check validator health and restart if necessary".to_string());
                     
-                    if !state.should_suppress_alert(&RuntimeAlertType::ValidatorOffline, &self.config) {
-                        let _ = self.alert_sender.send(alert);
-                    }
+                    alerts.push(alert);
                 }
             }
             
@@ -1019,9 +1761,7 @@ impl AlpenglowRuntimeMonitor {
                      .with_metadata("actor_id".to_string(), actor_id.to_string())
                      .with_metadata("message_type".to_string(), message_type);
                     
-                    if !state.should_suppress_alert(&RuntimeAlertType::HighLatency, &self.config) {
-                        let _ = self.alert_sender.send(alert);
-                    }
+                    alerts.push(alert);
                 }
             }
             
@@ -1037,7 +1777,7 @@ impl AlpenglowRuntimeMonitor {
                  .with_metadata("error".to_string(), error)
                  .with_action("Check actor logs and restart if necessary".to_string());
                 
-                let _ = self.alert_sender.send(alert);
+                alerts.push(alert);
             }
             
             _ => {
@@ -1045,508 +1785,57 @@ impl AlpenglowRuntimeMonitor {
             }
         }
         
-        Ok(())
+        Ok(alerts)
     }
 
     /// Check performance metrics and generate alerts
     async fn check_performance_metrics(
         state: &Arc<RwLock<RuntimeMonitorState>>,
-        config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<RuntimeAlert>,
+        clock: &Arc<dyn MonitorClock>,
+        alert_sender: &AlertPublisher,
     ) {
-        let state_guard = state.read().unwrap();
-        let metrics = &state_guard.runtime_metrics;
-        
-        // Check throughput
-        if metrics.current_throughput < 0.5 { // Less than 0.5 blocks/sec
-            let alert = RuntimeAlert::new(
-                RuntimeAlertType::LowThroughput,
-                ValidationAlertSeverity::Warning,
-                format!("Low throughput: {} blocks/sec", metrics.current_throughput)
-            ).with_metric("throughput".to_string(), metrics.current_throughput)
-             .with_action("Check network and validator health".to_string());
-            
-            let _ = alert_sender.send(alert);
-        }
-        
-        // Check latency
-        if metrics.avg_latency_ms > 1000.0 { // > 1 second
-            let alert = RuntimeAlert::new(
-                RuntimeAlertType::HighLatency,
-                ValidationAlertSeverity::Warning,
-                format!("High average latency: {}ms", metrics.avg_latency_ms)
-            ).with_metric("latency_ms".to_string(), metrics.avg_latency_ms);
-            
-            let _ = alert_sender.send(alert);
-        }
+        let alerts = performance_alerts(&state.read().unwrap().runtime_metrics, clock.now());
         
-        // Check error rate
-        if metrics.error_rate > 10.0 { // > 10 errors/sec
-            let alert = RuntimeAlert::new(
-                RuntimeAlertType::HealthCheckFailure,
-                ValidationAlertSeverity::Error,
-                format!("High error rate: {} errors/sec", metrics.error_rate)
-            ).with_metric("error_rate".to_string(), metrics.error_rate)
-             .with_action("Check system logs for error details".to_string());
-            
-            let _ = alert_sender.send(alert);
+        for alert in alerts {
+            alert_sender.publish(alert).await;
         }
+    }
+    
+    /// Alert if the finalized chain has stopped growing, even when no new state is observed
+    async fn check_finalization_stall(
+        state: &Arc<RwLock<RuntimeMonitorState>>,
+        config: &MonitorConfig,
+        clock: &Arc<dyn MonitorClock>,
+        alert_sender: &AlertPublisher,
+    ) {
+        let alert = state.read().unwrap().finalization_stall_alert(config, clock.now());
         
-        // Check queue depths
-        for (queue_name, depth) in &metrics.queue_depths {
-            if *depth > 1000 { // Queue backlog
-                let alert = RuntimeAlert::new(
-                    RuntimeAlertType::QueueBacklog,
-                    ValidationAlertSeverity::Warning,
-                    format!("Queue {} has backlog: {} items", queue_name, depth)
-                ).with_metric("queue_depth".to_string(), *depth as f64)
-                 .with_metadata("queue_name".to_string(), queue_name.clone())
-                 .with_action("Check queue processing and consider scaling".to_string());
-                
-                let _ = alert_sender.send(alert);
-            }
+        if let Some(alert) = alert {
+            alert_sender.publish(alert).await;
         }
     }
     
     /// Check resource usage and generate alerts
     async fn check_resource_usage(
         state: &Arc<RwLock<RuntimeMonitorState>>,
-        config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<RuntimeAlert>,
+        alert_sender: &AlertPublisher,
     ) {
-        let state_guard = state.read().unwrap();
-        let usage = &state_guard.resource_usage;
-        let limits = &usage.limits;
-        
-        // Check CPU usage
-        if usage.system_totals.total_cpu_usage_pct > limits.max_cpu_pct {
-            let alert = RuntimeAlert::new(
-                RuntimeAlertType::CPUOverload,
-                ValidationAlertSeverity::Warning,
-                format!("High CPU usage: {}% > {}%", 
-                    usage.system_totals.total_cpu_usage_pct, limits.max_cpu_pct)
-            ).with_metric("cpu_usage_pct".to_string(), usage.system_totals.total_cpu_usage_pct)
-             .with_metric("cpu_limit_pct".to_string(), limits.max_cpu_pct)
-             .with_action("Consider reducing load or adding CPU capacity".to_string());
-            
-            let _ = alert_sender.send(alert);
-        }
-        
-        // Check memory usage
-        if usage.system_totals.total_memory_usage_bytes > limits.max_memory_bytes {
-            let alert = RuntimeAlert::new(
-                RuntimeAlertType::MemoryPressure,
-                ValidationAlertSeverity::Warning,
-                format!("High memory usage: {}MB > {}MB", 
-                    usage.system_totals.total_memory_usage_bytes / 1024 / 1024,
-                    limits.max_memory_bytes / 1024 / 1024)
-            ).with_metric("memory_usage_bytes".to_string(), usage.system_totals.total_memory_usage_bytes as f64)
-             .with_metric("memory_limit_bytes".to_string(), limits.max_memory_bytes as f64)
-             .with_action("Consider reducing memory usage or adding memory".to_string());
-            
-            let _ = alert_sender.send(alert);
-        }
-        
-        // Check disk usage
-        if usage.system_totals.total_disk_usage_bytes > limits.max_disk_bytes {
-            let alert = RuntimeAlert::new(
-                RuntimeAlertType::DiskSpaceWarning,
-                ValidationAlertSeverity::Warning,
-                format!("High disk usage: {}GB > {}GB", 
-                    usage.system_totals.total_disk_usage_bytes / 1024 / 1024 / 1024,
-                    limits.max_disk_bytes / 1024 / 1024 / 1024)
-            ).with_metric("disk_usage_bytes".to_string(), usage.system_totals.total_disk_usage_bytes as f64)
-             .with_metric("disk_limit_bytes".to_string(), limits.max_disk_bytes as f64)
-             .with_action("Clean up old data or add disk capacity".to_string());
-            
-            let _ = alert_sender.send(alert);
-        }
+        let alerts = resource_alerts(&state.read().unwrap().resource_usage);
         
-        // Check connection count
-        if usage.system_totals.total_connections > limits.max_connections {
-            let alert = RuntimeAlert::new(
-                RuntimeAlertType::ConnectionLimitReached,
-                ValidationAlertSeverity::Warning,
-                format!("High connection count: {} > {}", 
-                    usage.system_totals.total_connections, limits.max_connections)
-            ).with_metric("connections".to_string(), usage.system_totals.total_connections as f64)
-             .with_metric("connection_limit".to_string(), limits.max_connections as f64)
-             .with_action("Check for connection leaks or increase limits".to_string());
-            
-            let _ = alert_sender.send(alert);
+        for alert in alerts {
+            alert_sender.publish(alert).await;
         }
     }
     
     /// Check network health and generate alerts
     async fn check_network_health(
         state: &Arc<RwLock<RuntimeMonitorState>>,
-        config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<RuntimeAlert>,
-    ) {
-        let state_guard = state.read().unwrap();
-        let health = &state_guard.network_health;
-        
-        // Check average RTT
-        if health.avg_rtt_ms > 500.0 { // > 500ms
-            let alert = RuntimeAlert::new(
-                RuntimeAlertType::HighLatency,
-                ValidationAlertSeverity::Warning,
-                format!("High network RTT: {}ms", health.avg_rtt_ms)
-            ).with_metric("rtt_ms".to_string(), health.avg_rtt_ms)
-             .with_action("Check network connectivity and routing".to_string());
-            
-            let _ = alert_sender.send(alert);
-        }
-        
-        // Check packet loss
-        if health.packet_loss_pct > 5.0 { // > 5%
-            let alert = RuntimeAlert::new(
-                RuntimeAlertType::HighPacketLoss,
-                ValidationAlertSeverity::Warning,
-                format!("High packet loss: {}%", health.packet_loss_pct)
-            ).with_metric("packet_loss_pct".to_string(), health.packet_loss_pct)
-             .with_action("Check network quality and congestion".to_string());
-            
-            let _ = alert_sender.send(alert);
-        }
-        
-        // Check active partitions
-        if health.active_partitions > 0 {
-            let alert = RuntimeAlert::new(
-                RuntimeAlertType::PartitionDetected,
-                ValidationAlertSeverity::Error,
-                format!("Network partitions detected: {}", health.active_partitions)
-            ).with_metric("partitions".to_string(), health.active_partitions as f64)
-             .with_action("Check network connectivity between validators".to_string());
-            
-            let _ = alert_sender.send(alert);
-        }
-        
-        // Check message drop rate
-        if health.message_drop_rate > 0.1 { // > 10%
-            let alert = RuntimeAlert::new(
-                RuntimeAlertType::MessageDrops,
-                ValidationAlertSeverity::Warning,
-                format!("High message drop rate: {}%", health.message_drop_rate * 100.0)
-            ).with_metric("drop_rate_pct".to_string(), health.message_drop_rate * 100.0)
-             .with_action("Check network capacity and message queues".to_string());
-            
-            let _ = alert_sender.send(alert);
-        }
-    }
-
-    /// Check for conflicting block finalization in the same slot
-    async fn check_conflicting_finalization(
-        state: &MonitorState,
-        _config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<Alert>,
-    ) {
-        for (&slot, blocks) in &state.proposed_blocks {
-            let finalized_in_slot: Vec<_> = blocks.iter()
-                .filter(|block| state.finalized_blocks.get(&slot).map_or(false, |fb| fb.hash == block.hash))
-                .collect();
-            
-            if finalized_in_slot.len() > 1 {
-                let alert = Alert {
-                    alert_type: AlertType::ConflictingFinalization,
-                    severity: AlertSeverity::Critical,
-                    message: format!("Multiple blocks finalized in slot {}: {:?}", 
-                                   slot, finalized_in_slot.iter().map(|b| &b.hash).collect::<Vec<_>>()),
-                    timestamp: SystemTime::now(),
-                    slot: Some(slot),
-                    view: None,
-                    validators: finalized_in_slot.iter().map(|b| b.proposer.clone()).collect(),
-                    metadata: HashMap::new(),
-                };
-                
-                let _ = alert_sender.send(alert);
-            }
-        }
-    }
-
-    /// Check certificate validity based on stake thresholds
-    async fn check_certificate_validity(
-        state: &MonitorState,
-        config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<Alert>,
-    ) {
-        for ((slot, view), certs) in &state.certificates {
-            for cert in certs {
-                let required_stake = match cert.cert_type {
-                    CertificateType::Fast => (state.total_stake * config.fast_path_threshold_bp as u64) / 10000,
-                    CertificateType::Slow => (state.total_stake * config.slow_path_threshold_bp as u64) / 10000,
-                    CertificateType::Skip => (state.total_stake * config.slow_path_threshold_bp as u64) / 10000,
-                };
-                
-                if cert.total_stake < required_stake {
-                    let alert = Alert {
-                        alert_type: AlertType::InvalidCertificate,
-                        severity: AlertSeverity::Error,
-                        message: format!("Invalid certificate: insufficient stake {} < {} for {:?} certificate in slot {} view {}", 
-                                       cert.total_stake, required_stake, cert.cert_type, slot, view),
-                        timestamp: SystemTime::now(),
-                        slot: Some(*slot),
-                        view: Some(*view),
-                        validators: cert.votes.iter().map(|v| v.validator.clone()).collect(),
-                        metadata: HashMap::new(),
-                    };
-                    
-                    let _ = alert_sender.send(alert);
-                }
-            }
-        }
-    }
-
-    /// Check for double voting by validators
-    async fn check_double_voting(
-        state: &MonitorState,
-        _config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<Alert>,
-    ) {
-        for ((slot, view), votes) in &state.votes {
-            let mut validator_votes: HashMap<String, Vec<&VoteInfo>> = HashMap::new();
-            
-            for vote in votes {
-                validator_votes.entry(vote.validator.clone()).or_default().push(vote);
-            }
-            
-            for (validator, validator_vote_list) in validator_votes {
-                if validator_vote_list.len() > 1 {
-                    let unique_blocks: HashSet<_> = validator_vote_list.iter()
-                        .map(|v| &v.block_hash)
-                        .collect();
-                    
-                    if unique_blocks.len() > 1 {
-                        let alert = Alert {
-                            alert_type: AlertType::DoubleVoting,
-                            severity: AlertSeverity::Error,
-                            message: format!("Double voting detected: validator {} voted for multiple blocks in slot {} view {}: {:?}", 
-                                           validator, slot, view, unique_blocks),
-                            timestamp: SystemTime::now(),
-                            slot: Some(*slot),
-                            view: Some(*view),
-                            validators: vec![validator.clone()],
-                            metadata: HashMap::new(),
-                        };
-                        
-                        let _ = alert_sender.send(alert);
-                        
-                        // Mark validator as suspected Byzantine
-                        // Note: In a real implementation, this would need proper synchronization
-                    }
-                }
-            }
-        }
-    }
-
-    /// Check chain consistency across validators
-    async fn check_chain_consistency(
-        state: &MonitorState,
-        _config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<Alert>,
-    ) {
-        // Check if all finalized blocks form a consistent chain
-        let mut finalized_slots: Vec<_> = state.finalized_blocks.keys().cloned().collect();
-        finalized_slots.sort();
-        
-        for window in finalized_slots.windows(2) {
-            let prev_slot = window[0];
-            let curr_slot = window[1];
-            
-            if let (Some(prev_block), Some(curr_block)) = 
-                (state.finalized_blocks.get(&prev_slot), state.finalized_blocks.get(&curr_slot)) {
-                
-                if curr_block.parent_hash != prev_block.hash {
-                    let alert = Alert {
-                        alert_type: AlertType::ChainInconsistency,
-                        severity: AlertSeverity::Critical,
-                        message: format!("Chain inconsistency: block {} in slot {} does not reference parent {} from slot {}", 
-                                       curr_block.hash, curr_slot, prev_block.hash, prev_slot),
-                        timestamp: SystemTime::now(),
-                        slot: Some(curr_slot),
-                        view: None,
-                        validators: vec![curr_block.proposer.clone()],
-                        metadata: HashMap::new(),
-                    };
-                    
-                    let _ = alert_sender.send(alert);
-                }
-            }
-        }
-    }
-
-    /// Check Byzantine stake threshold
-    async fn check_byzantine_threshold(
-        state: &MonitorState,
-        config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<Alert>,
-    ) {
-        let byzantine_stake = state.byzantine_stake();
-        let threshold = (state.total_stake * config.byzantine_threshold_bp as u64) / 10000;
-        
-        if byzantine_stake > threshold {
-            let alert = Alert {
-                alert_type: AlertType::ByzantineThresholdExceeded,
-                severity: AlertSeverity::Critical,
-                message: format!("Byzantine stake threshold exceeded: {} > {} ({}%)", 
-                               byzantine_stake, threshold, (byzantine_stake * 100) / state.total_stake),
-                timestamp: SystemTime::now(),
-                slot: None,
-                view: None,
-                validators: state.validators.iter()
-                    .filter(|(_, v)| v.suspected_byzantine)
-                    .map(|(id, _)| id.clone())
-                    .collect(),
-                metadata: HashMap::new(),
-            };
-            
-            let _ = alert_sender.send(alert);
-        }
-    }
-
-    /// Check finalization progress
-    async fn check_finalization_progress(
-        state: &MonitorState,
-        config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<Alert>,
-    ) {
-        if !state.is_past_gst(config) {
-            return; // Don't check liveness before GST
-        }
-
-        let honest_stake = state.honest_stake();
-        let required_stake = (state.total_stake * 3) / 5; // 60% for liveness
-        
-        if honest_stake >= required_stake {
-            // Check if current slot has been finalized within timeout
-            let timeout_threshold = SystemTime::now() - Duration::from_millis(config.finalization_timeout_ms);
-            
-            if !state.finalized_blocks.contains_key(&state.current_slot) {
-                // Check if there are any proposed blocks for current slot that should have been finalized
-                if let Some(blocks) = state.proposed_blocks.get(&state.current_slot) {
-                    for block in blocks {
-                        if block.timestamp < timeout_threshold {
-                            let alert = Alert {
-                                alert_type: AlertType::FinalizationTimeout,
-                                severity: AlertSeverity::Warning,
-                                message: format!("Finalization timeout: block {} in slot {} not finalized within {}ms", 
-                                               block.hash, state.current_slot, config.finalization_timeout_ms),
-                                timestamp: SystemTime::now(),
-                                slot: Some(state.current_slot),
-                                view: None,
-                                validators: vec![block.proposer.clone()],
-                                metadata: HashMap::new(),
-                            };
-                            
-                            let _ = alert_sender.send(alert);
-                        }
-                    }
-                }
-            }
-        }
-    }
-
-    /// Check view timeouts
-    async fn check_view_timeouts(
-        state: &MonitorState,
-        config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<Alert>,
-    ) {
-        let now = SystemTime::now();
-        let timeout_threshold = Duration::from_millis(config.finalization_timeout_ms * 2);
-        
-        for (validator, &view) in &state.validator_views {
-            if view > config.max_view {
-                let alert = Alert {
-                    alert_type: AlertType::ViewTimeout,
-                    severity: AlertSeverity::Warning,
-                    message: format!("Validator {} reached high view number: {}", validator, view),
-                    timestamp: now,
-                    slot: Some(state.current_slot),
-                    view: Some(view),
-                    validators: vec![validator.clone()],
-                    metadata: HashMap::new(),
-                };
-                
-                let _ = alert_sender.send(alert);
-            }
-        }
-    }
-
-    /// Check fast path completion
-    async fn check_fast_path_completion(
-        state: &MonitorState,
-        config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<Alert>,
-    ) {
-        if !state.is_past_gst(config) {
-            return;
-        }
-
-        let responsive_stake = state.honest_stake(); // Simplified: assume all honest validators are responsive
-        let fast_path_threshold = (state.total_stake * config.fast_path_threshold_bp as u64) / 10000;
-        
-        if responsive_stake >= fast_path_threshold {
-            // Check if recent slots used fast path
-            for (&slot, certs) in &state.certificates {
-                if slot >= state.current_slot.saturating_sub(5) { // Check last 5 slots
-                    let has_fast_cert = certs.iter().any(|c| c.cert_type == CertificateType::Fast);
-                    
-                    if !has_fast_cert && state.finalized_blocks.contains_key(&slot) {
-                        let alert = Alert {
-                            alert_type: AlertType::SlowFinalization,
-                            severity: AlertSeverity::Info,
-                            message: format!("Slow path used in slot {} despite sufficient responsive stake", slot),
-                            timestamp: SystemTime::now(),
-                            slot: Some(slot),
-                            view: None,
-                            validators: vec![],
-                            metadata: HashMap::new(),
-                        };
-                        
-                        let _ = alert_sender.send(alert);
-                    }
-                }
-            }
-        }
-    }
-
-    /// Check bounded finalization time
-    async fn check_bounded_finalization(
-        state: &MonitorState,
-        config: &MonitorConfig,
-        alert_sender: &broadcast::Sender<Alert>,
+        alert_sender: &AlertPublisher,
     ) {
-        if !state.is_past_gst(config) {
-            return;
-        }
-
-        let responsive_stake = state.honest_stake();
-        let fast_path_threshold = (state.total_stake * config.fast_path_threshold_bp as u64) / 10000;
-        
-        let bound = if responsive_stake >= fast_path_threshold {
-            Duration::from_millis(config.network_delay_bound_ms) // Fast path bound
-        } else {
-            Duration::from_millis(config.network_delay_bound_ms * 2) // Slow path bound
-        };
+        let alerts = network_alerts(&state.read().unwrap().network_health);
         
-        // Check recent finalization latencies
-        for &(slot, latency) in state.finalization_latencies.iter().rev().take(10) {
-            if latency > bound {
-                let alert = Alert {
-                    alert_type: AlertType::SlowFinalization,
-                    severity: AlertSeverity::Warning,
-                    message: format!("Finalization exceeded bound: {}ms > {}ms for slot {}", 
-                                   latency.as_millis(), bound.as_millis(), slot),
-                    timestamp: SystemTime::now(),
-                    slot: Some(slot),
-                    view: None,
-                    validators: vec![],
-                    metadata: HashMap::new(),
-                };
-                
-                let _ = alert_sender.send(alert);
-            }
+        for alert in alerts {
+            alert_sender.publish(alert).await;
         }
     }
 
@@ -1558,7 +1847,8 @@ impl AlpenglowRuntimeMonitor {
             runtime_metrics: state.runtime_metrics.clone(),
             network_health: state.network_health.clone(),
             resource_usage: state.resource_usage.clone(),
-            alert_count: state.recent_alerts.len(),
+            alert_count: self.alert_sender.sent() as usize,
+            dropped_alerts: self.alert_sender.dropped(),
             uptime: SystemTime::now().duration_since(state.start_time).unwrap_or_default(),
             last_updated: SystemTime::now(),
         }
@@ -1588,6 +1878,16 @@ impl AlpenglowRuntimeMonitor {
         }
     }
     
+    /// Record a finalization latency in the latency histogram
+    pub fn record_finalization_latency(&self, latency_ms: f64) {
+        self.state.write().unwrap().finalization_latency.observe(latency_ms);
+    }
+    
+    /// Histogram of the finalization latencies seen so far (milliseconds)
+    pub fn finalization_latency_histogram(&self) -> HistogramMetric {
+        self.state.read().unwrap().finalization_latency.clone()
+    }
+    
     /// Export metrics for external monitoring systems (Prometheus, etc.)
     pub fn export_metrics(&self) -> HashMap<String, f64> {
         let state = self.state.read().unwrap();
@@ -1610,6 +1910,9 @@ impl AlpenglowRuntimeMonitor {
         metrics.insert("alpenglow_network_jitter_ms".to_string(), state.network_health.jitter_ms);
         metrics.insert("alpenglow_network_partitions".to_string(), state.network_health.active_partitions as f64);
         metrics.insert("alpenglow_network_drop_rate_pct".to_string(), state.network_health.message_drop_rate * 100.0);
+        for (percentile, latency) in &state.network_health.latency_percentiles {
+            metrics.insert(format!("alpenglow_network_latency_p{}_ms", percentile), latency.as_secs_f64() * 1000.0);
+        }
         
         // Resource usage
         metrics.insert("alpenglow_system_cpu_pct".to_string(), state.resource_usage.system_totals.total_cpu_usage_pct);
@@ -1619,7 +1922,8 @@ impl AlpenglowRuntimeMonitor {
         metrics.insert("alpenglow_system_connections".to_string(), state.resource_usage.system_totals.total_connections as f64);
         
         // Alert metrics
-        metrics.insert("alpenglow_alerts_total".to_string(), state.recent_alerts.len() as f64);
+        metrics.insert("alpenglow_alerts_total".to_string(), self.alert_sender.sent() as f64);
+        metrics.insert("alpenglow_alerts_dropped_total".to_string(), self.alert_sender.dropped() as f64);
         
         metrics
     }
@@ -1632,6 +1936,9 @@ pub struct RuntimeMonitorStats {
     pub network_health: NetworkHealth,
     pub resource_usage: ResourceUsage,
     pub alert_count: usize,
+    /// Runtime alerts shed because a subscriber's buffer was full
+    #[serde(default)]
+    pub dropped_alerts: u64,
     pub uptime: Duration,
     pub last_updated: SystemTime,
 }
@@ -1756,6 +2063,160 @@ mod tests {
         assert_eq!(alert.alert_type, RuntimeAlertType::LowThroughput);
     }
 
+    #[tokio::test]
+    async fn test_replay_regenerates_stall_alert() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("metrics.jsonl");
+        let config = MonitorConfig {
+            stall_timeout_ms: 1000,
+            ..MonitorConfig::default()
+        };
+        let clock = MockClock::new(UNIX_EPOCH + Duration::from_secs(1_000_000));
+        let monitor = AlpenglowRuntimeMonitor::new(config)
+            .with_clock(clock.clone())
+            .with_recording(&log_path);
+        let sample = |throughput| RuntimeMetrics {
+            current_throughput: throughput,
+            ..Default::default()
+        };
+        
+        // Blocks finalize once, then throughput stops for longer than the stall timeout
+        let mut recorded = monitor.record_metrics(sample(2.0)).await.unwrap();
+        for _ in 0..3 {
+            clock.advance(Duration::from_millis(600));
+            recorded.extend(monitor.record_metrics(sample(0.0)).await.unwrap());
+        }
+        let stalls: Vec<_> = recorded.iter()
+            .filter(|alert| alert.alert_type == RuntimeAlertType::FinalizationStall)
+            .collect();
+        assert_eq!(stalls.len(), 2);
+        assert_eq!(stalls[0].message, "No finalization progress for 1200ms");
+        
+        // The publisher delivers the stall once and folds the repeat within the cooldown into it
+        let delivered: Vec<_> = monitor.deduplicated_alerts().into_iter()
+            .filter(|alert| alert.alert_type == RuntimeAlertType::FinalizationStall)
+            .collect();
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(delivered[0].repeat_count, 2);
+        assert_eq!(delivered[0].message, "No finalization progress for 1200ms");
+        
+        // Replaying the recording regenerates exactly the same alerts
+        let replayed = monitor.replay_from_metrics(&log_path).unwrap();
+        let fired = |alerts: &[RuntimeAlert]| alerts.iter()
+            .map(|alert| (alert.alert_type.clone(), alert.message.clone(), alert.timestamp))
+            .collect::<Vec<_>>();
+        assert_eq!(fired(&replayed), fired(&recorded));
+    }
+
+    #[tokio::test]
+    async fn test_finalization_stall_escalates() {
+        let config = MonitorConfig {
+            finalization_stall_threshold_ms: 1000,
+            ..MonitorConfig::default()
+        };
+        let clock = MockClock::new(UNIX_EPOCH + Duration::from_secs(1_000_000));
+        let monitor = AlpenglowRuntimeMonitor::new(config).with_clock(clock.clone());
+        let mut protocol_state = alpenglow_stateright::AlpenglowState::init(&test_configs()[0]);
+        protocol_state.current_slot = 3;
+        
+        // Nothing fires until the threshold passes without a finalization
+        assert!(monitor.observe_protocol_state(&protocol_state).await.is_none());
+        clock.advance(Duration::from_millis(900));
+        assert!(monitor.observe_protocol_state(&protocol_state).await.is_none());
+        
+        clock.advance(Duration::from_millis(600));
+        let warning = monitor.observe_protocol_state(&protocol_state).await.unwrap();
+        assert_eq!(warning.alert_type, RuntimeAlertType::FinalizationStall);
+        assert_eq!(warning.severity, ValidationAlertSeverity::Warning);
+        assert_eq!(warning.slot, Some(3));
+        
+        // At twice the threshold the stall becomes critical
+        clock.advance(Duration::from_millis(500));
+        let critical = monitor.observe_protocol_state(&protocol_state).await.unwrap();
+        assert_eq!(critical.severity, ValidationAlertSeverity::Critical);
+        
+        // A finalized block restarts the timer
+        protocol_state.votor_finalized_chain.push(alpenglow_stateright::Block {
+            slot: 3,
+            view: 1,
+            hash: 1,
+            parent: 0,
+            proposer: 0,
+            transactions: Default::default(),
+            timestamp: 0,
+            signature: 0,
+            data: Vec::new(),
+        });
+        assert!(monitor.observe_protocol_state(&protocol_state).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_latency_percentiles_from_delivery_delays() {
+        let alpenglow_config = test_configs()[0].clone();
+        let mut protocol_state = alpenglow_stateright::AlpenglowState::init(&alpenglow_config);
+        let message = |id| alpenglow_stateright::NetworkMessage {
+            id,
+            msg_type: alpenglow_stateright::MessageType::Vote,
+            sender: 0,
+            recipient: alpenglow_stateright::MessageRecipient::Broadcast,
+            payload: Vec::new(),
+            timestamp: 0,
+            signature: 0,
+        };
+        // Message `id` took `id` milliseconds to deliver
+        for id in 1..=100 {
+            protocol_state.network_delivery_time.insert(message(id), id);
+        }
+        
+        let monitor = AlpenglowRuntimeMonitor::new(MonitorConfig::default());
+        monitor.observe_protocol_state(&protocol_state).await;
+        let percentiles = monitor.get_runtime_stats().network_health.latency_percentiles;
+        assert_eq!(percentiles[&50], Duration::from_millis(50));
+        assert_eq!(percentiles[&90], Duration::from_millis(90));
+        assert_eq!(percentiles[&99], Duration::from_millis(99));
+        assert_eq!(monitor.export_metrics()["alpenglow_network_latency_p99_ms"], 99.0);
+        
+        // Observing the same deliveries again does not count them twice
+        monitor.observe_protocol_state(&protocol_state).await;
+        assert_eq!(monitor.get_runtime_stats().network_health.latency_percentiles, percentiles);
+        
+        // A ten-delay window keeps only the slowest, most recent deliveries 91..=100
+        let monitor = AlpenglowRuntimeMonitor::new(MonitorConfig {
+            latency_window_size: 10,
+            ..MonitorConfig::default()
+        });
+        monitor.observe_protocol_state(&protocol_state).await;
+        let percentiles = monitor.get_runtime_stats().network_health.latency_percentiles;
+        assert_eq!(percentiles[&50], Duration::from_millis(95));
+        assert_eq!(percentiles[&99], Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_equivocation_alert() {
+        let monitor = AlpenglowRuntimeMonitor::new(MonitorConfig::default());
+        let mut receiver = monitor.subscribe_runtime_alerts();
+        let vote = |validator, view, block_hash| ValidationVote {
+            validator,
+            view,
+            slot: 4,
+            block_hash,
+            signature: vec![1],
+            timestamp: 0,
+        };
+        
+        // Votes for different views, or repeats of the same vote, are not equivocation
+        assert!(monitor.observe_vote(&vote(2, 1, 10)).await.is_none());
+        assert!(monitor.observe_vote(&vote(2, 2, 11)).await.is_none());
+        assert!(monitor.observe_vote(&vote(2, 1, 10)).await.is_none());
+        assert!(monitor.observe_vote(&vote(3, 1, 12)).await.is_none());
+        
+        // A later observation conflicting with an indexed vote fires the alert
+        let alert = monitor.observe_vote(&vote(2, 1, 12)).await.unwrap();
+        assert_eq!(alert.alert_type, RuntimeAlertType::Equivocation { validator: 2, slot: 4, view: 1 });
+        assert_eq!(alert.validator_id, Some(2));
+        assert_eq!(receiver.recv().await.unwrap().alert_type, alert.alert_type);
+    }
+
     #[tokio::test]
     async fn test_resource_monitoring() {
         let config = MonitorConfig::default();
@@ -1782,6 +2243,102 @@ mod tests {
         assert_eq!(alert.alert_type, RuntimeAlertType::MemoryPressure);
     }
 
+    #[tokio::test]
+    async fn test_alert_overflow_policies() {
+        let alert = |i| RuntimeAlert::new(
+            RuntimeAlertType::LowThroughput,
+            ValidationAlertSeverity::Info,
+            format!("alert {}", i)
+        );
+        let monitor_with = |policy| AlpenglowRuntimeMonitor::new(MonitorConfig {
+            alert_channel_capacity: 4,
+            alert_overflow_policy: policy,
+            alert_cooldown_ms: 0,
+            ..MonitorConfig::default()
+        });
+        
+        // DropOldest evicts the two oldest alerts and the lagging subscriber is told how many it missed
+        let monitor = monitor_with(AlertOverflowPolicy::DropOldest);
+        let mut receiver = monitor.subscribe_runtime_alerts();
+        for i in 0..6 {
+            monitor.alert_sender.publish(alert(i)).await;
+        }
+        assert_eq!(monitor.get_runtime_stats().dropped_alerts, 2);
+        assert!(matches!(receiver.recv().await, Err(broadcast::error::RecvError::Lagged(2))));
+        assert_eq!(receiver.recv().await.unwrap().message, "alert 2");
+        
+        // DropNewest keeps the buffered alerts and discards the overflow
+        let monitor = monitor_with(AlertOverflowPolicy::DropNewest);
+        let mut receiver = monitor.subscribe_runtime_alerts();
+        for i in 0..6 {
+            monitor.alert_sender.publish(alert(i)).await;
+        }
+        assert_eq!(monitor.get_runtime_stats().dropped_alerts, 2);
+        assert_eq!(receiver.recv().await.unwrap().message, "alert 0");
+        
+        // Block holds the publisher until the subscriber makes room and sheds nothing
+        let monitor = monitor_with(AlertOverflowPolicy::Block);
+        let mut receiver = monitor.subscribe_runtime_alerts();
+        for i in 0..4 {
+            monitor.alert_sender.publish(alert(i)).await;
+        }
+        let publisher = monitor.alert_sender.clone();
+        let mut blocked = tokio::spawn(async move { publisher.publish(alert(4)).await });
+        assert!(timeout(Duration::from_millis(50), &mut blocked).await.is_err());
+        assert_eq!(receiver.recv().await.unwrap().message, "alert 0");
+        timeout(Duration::from_secs(1), blocked).await.unwrap().unwrap();
+        assert_eq!(monitor.get_runtime_stats().dropped_alerts, 0);
+        for i in 1..5 {
+            assert_eq!(receiver.recv().await.unwrap().message, format!("alert {}", i));
+        }
+        
+        // Dropping the last subscriber releases a blocked publisher
+        for i in 5..9 {
+            monitor.alert_sender.publish(alert(i)).await;
+        }
+        let publisher = monitor.alert_sender.clone();
+        let blocked = tokio::spawn(async move { publisher.publish(alert(9)).await });
+        drop(receiver);
+        timeout(Duration::from_secs(1), blocked).await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_identical_alerts_deduplicated_within_cooldown() {
+        let monitor = AlpenglowRuntimeMonitor::new(MonitorConfig {
+            alert_cooldown_ms: 1000,
+            ..MonitorConfig::default()
+        });
+        let mut receiver = monitor.subscribe_runtime_alerts();
+        let start = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let partition = |offset_ms| RuntimeAlert::new(
+            RuntimeAlertType::PartitionDetected,
+            ValidationAlertSeverity::Error,
+            "Network partitions detected: 1".to_string()
+        ).with_timestamp(start + Duration::from_millis(offset_ms));
+        
+        // Ten partition alerts inside the cooldown are delivered once and counted on that alert
+        for i in 0..10 {
+            monitor.alert_sender.publish(partition(i * 50)).await;
+        }
+        assert_eq!(receiver.recv().await.unwrap().repeat_count, 1);
+        assert!(matches!(receiver.try_recv(), Err(broadcast::error::TryRecvError::Empty)));
+        let deduplicated = monitor.deduplicated_alerts();
+        assert_eq!(deduplicated.len(), 1);
+        assert_eq!(deduplicated[0].repeat_count, 10);
+        
+        // After the cooldown the next occurrence fires again with the accumulated count attached
+        monitor.alert_sender.publish(partition(1500)).await;
+        let refired = receiver.recv().await.unwrap();
+        assert_eq!(refired.repeat_count, 1);
+        assert_eq!(refired.metrics["previous_repeat_count"], 10.0);
+        
+        // A different severity is tracked separately
+        let mut warning = partition(1600);
+        warning.severity = ValidationAlertSeverity::Warning;
+        monitor.alert_sender.publish(warning).await;
+        assert_eq!(receiver.recv().await.unwrap().severity, ValidationAlertSeverity::Warning);
+    }
+
     #[tokio::test]
     async fn test_network_monitoring() {
         let config = MonitorConfig::default();
@@ -1818,9 +2375,9 @@ mod tests {
         // Send validation event through bridge
         let validation_event = ValidationEvent::BlockFinalized {
             block: crate::validation::Block {
-                hash: [1; 32],
+                hash: 1,
                 slot: 1,
-                parent_hash: [0; 32],
+                parent_hash: 0,
                 timestamp: 1000,
                 proposer: 1,
                 transactions: vec![],
@@ -1829,7 +2386,7 @@ mod tests {
                 cert_type: crate::validation::CertificateType::Fast,
                 slot: 1,
                 view: 1,
-                block_hash: [1; 32],
+                block_hash: 1,
                 votes: vec![],
                 total_stake: 800,
                 timestamp: 1000,
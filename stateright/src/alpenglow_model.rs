@@ -102,6 +102,18 @@ impl NetworkMessage {
         // Simplified validation - in practice would verify cryptographic signature
         self.signature == self.sender as Signature && !self.msg_type.is_empty()
     }
+
+    /// Total order key for messages, whose JSON payload is not itself `Ord`
+    pub fn ordering_key(&self) -> (u64, ValidatorId, String, String, String, Signature) {
+        (
+            self.timestamp,
+            self.sender,
+            self.msg_type.clone(),
+            self.recipient.clone(),
+            self.payload.to_string(),
+            self.signature,
+        )
+    }
 }
 
 /// Alpenglow action types - mirrors TLA+ Next actions exactly
@@ -1284,8 +1296,14 @@ impl AlpenglowModel {
     }
     
     /// Get all possible actions from a state - mirrors TLA+ Next
+    ///
+    /// Actions are enumerated in a fixed order so exploration is reproducible: grouped by
+    /// action kind as below, validators in ascending id order and messages by `ordering_key`.
     pub fn actions(&self, state: &AlpenglowState) -> Vec<AlpenglowAction> {
         let mut actions = Vec::new();
+        let mut validators: Vec<(&ValidatorId, &VotorState)> = state.votor_states.iter().collect();
+        validators.sort_unstable_by_key(|(validator_id, _)| **validator_id);
+        let honest_validators = state.honest_validators();
         
         // Always can advance clock
         actions.push(AlpenglowAction::AdvanceClock);
@@ -1298,15 +1316,15 @@ impl AlpenglowModel {
         }
         
         // View advancement for validators with expired timeouts
-        for (&validator_id, votor_state) in &state.votor_states {
-            if state.honest_validators().contains(&validator_id) &&
+        for &(&validator_id, votor_state) in &validators {
+            if honest_validators.contains(&validator_id) &&
                votor_state.is_timeout_expired() {
                 actions.push(AlpenglowAction::AdvanceView { validator: validator_id });
             }
         }
         
         // Votor actions
-        for (&validator_id, votor_state) in &state.votor_states {
+        for &(&validator_id, votor_state) in &validators {
             // Clock tick
             actions.push(AlpenglowAction::VotorAction {
                 action: VotorMessage::ClockTick { current_time: state.clock },
@@ -1352,7 +1370,9 @@ impl AlpenglowModel {
         });
         
         // Network actions for message delivery
-        for message in &state.messages {
+        let mut messages: Vec<&NetworkMessage> = state.messages.iter().collect();
+        messages.sort_by_cached_key(|message| message.ordering_key());
+        for message in messages {
             actions.push(AlpenglowAction::NetworkAction {
                 action: NetworkActionType::DeliverMessage { message: message.clone() },
             });
@@ -1364,7 +1384,9 @@ impl AlpenglowModel {
         }
         
         // Byzantine actions
-        for &validator_id in &state.byzantine_validators {
+        let mut byzantine_validators: Vec<ValidatorId> = state.byzantine_validators.iter().copied().collect();
+        byzantine_validators.sort_unstable();
+        for validator_id in byzantine_validators {
             // Double voting
             if let Some(votor_state) = state.votor_states.get(&validator_id) {
                 let vote1 = Vote {
@@ -1755,40 +1777,9 @@ impl TlaCompatible for AlpenglowState {
         self.to_tla_string()
     }
     
-    /// Import TLA+ state from another AlpenglowState representation (copy selected fields)
+    /// Import TLA+ state from another AlpenglowState representation (copy every field)
     fn import_tla_state(&mut self, state: &Self) -> AlpenglowResult<()> {
-        // Copy time and scheduling
-        self.clock = state.clock;
-        self.current_slot = state.current_slot;
-        
-        // Copy voter and rotor states by deep cloning
-        self.votor_states = state.votor_states.clone();
-        self.rotor_states = state.rotor_states.clone();
-        
-        // Copy network buffers and messages
-        self.network_message_queue = state.network_message_queue.clone();
-        self.network_message_buffer = state.network_message_buffer.clone();
-        self.messages = state.messages.clone();
-        self.network_dropped_messages = state.network_dropped_messages;
-        self.network_delivery_time = state.network_delivery_time.clone();
-        
-        // Copy finalization data
-        self.finalized_blocks = state.finalized_blocks.clone();
-        self.finalized_by_slot = state.finalized_by_slot.clone();
-        self.latency_metrics = state.latency_metrics.clone();
-        
-        // Copy validator sets and failure info
-        self.failure_states = state.failure_states.clone();
-        self.byzantine_validators = state.byzantine_validators.clone();
-        self.offline_validators = state.offline_validators.clone();
-        
-        // Copy partitions
-        self.network_partitions = state.network_partitions.clone();
-        
-        // Copy counters and metrics
-        self.nonce_counter = state.nonce_counter;
-        self.bandwidth_metrics = state.bandwidth_metrics.clone();
-        
+        *self = state.clone();
         Ok(())
     }
     
@@ -1955,11 +1946,8 @@ impl AlpenglowState {
             return Ok(0u64.into());
         }
         
-        // Use string hash for deterministic conversion
-        let mut hasher = std::collections::hash_map::DefaultHasher::new();
-        use std::hash::{Hash, Hasher};
-        hash_str.hash(&mut hasher);
-        let hash = hasher.finish();
+        // Use stable string hash for deterministic conversion
+        let hash = crate::utils::stable_hash(0, hash_str.as_bytes());
         Ok(hash.into())
     }
     
@@ -2164,6 +2152,43 @@ mod tests {
         assert!(actions.iter().any(|a| matches!(a, AlpenglowAction::AdvanceClock)));
     }
     
+    #[test]
+    fn test_actions_deterministic_order() {
+        let config = Config::new().with_validators(4);
+        let model = AlpenglowModel::new(config);
+        let messages: Vec<NetworkMessage> = (0..4)
+            .map(|sender| NetworkMessage::broadcast("vote".to_string(), sender, serde_json::json!({"view": 1}), 100))
+            .collect();
+        
+        // Same contents inserted in opposite orders into independently hashed sets
+        let mut state = model.init_state();
+        state.set_byzantine_validators([3, 0].iter().cloned().collect());
+        state.messages.extend(messages.iter().cloned());
+        let mut reordered = model.init_state();
+        reordered.set_byzantine_validators([0, 3].iter().cloned().collect());
+        reordered.messages.extend(messages.iter().rev().cloned());
+        
+        let actions = model.actions(&state);
+        assert_eq!(actions, model.actions(&state));
+        assert_eq!(actions, model.actions(&state.clone()));
+        assert_eq!(actions, model.actions(&reordered));
+        
+        let senders: Vec<ValidatorId> = actions.iter()
+            .filter_map(|action| match action {
+                AlpenglowAction::NetworkAction { action: NetworkActionType::DeliverMessage { message } } => Some(message.sender),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(senders, vec![0, 1, 2, 3]);
+        let double_voters: Vec<ValidatorId> = actions.iter()
+            .filter_map(|action| match action {
+                AlpenglowAction::ByzantineAction { validator, .. } => Some(*validator),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(double_voters, vec![0, 3]);
+    }
+    
     #[test]
     fn test_advance_clock() {
         let config = Config::new().with_validators(3);
@@ -33,14 +33,22 @@
 //! // Run verification (example)
 //! // model.verify_safety_properties();
 //! ```
+//!
+//! ## Reproducibility
+//!
+//! Every deterministic choice the model makes (stake-weighted leader selection, Rotor relay
+//! assignment, `HonestScheduler` ordering) is derived from `Config::seed` through the stable
+//! hashes in `utils`. Two runs with the same seed and configuration produce identical traces
+//! and verification results on any platform and Rust version.
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet, BTreeMap, BTreeSet};
+use std::collections::{HashMap, HashSet, BTreeMap, BTreeSet, BinaryHeap, VecDeque};
 use std::fmt::Debug;
-use std::hash::{Hash, Hasher};
-use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
 use std::time::{Duration, Instant};
 use std::fs;
-use std::path::Path;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 // use chrono;
 
 /// Result type for Alpenglow operations
@@ -111,6 +119,8 @@ pub mod alpenglow_model;
 pub mod integration;
 pub mod rotor_performance;
 pub mod network;
+pub mod simulation;
+pub mod tla_backend;
 
 // Re-export main components and all core types for test access
 pub use votor::{
@@ -132,6 +142,7 @@ pub use network::{
     NetworkPartition, MessageSignature, NetworkConfig, NetworkActorMessage,
     NetworkSpec
 };
+pub use simulation::{Scheduler, HonestScheduler, SimulationResult, SlotFinalization};
 pub use integration::{
     AlpenglowNode, AlpenglowMessage, ProtocolConfig,
     // Core types from integration module
@@ -229,6 +240,7 @@ pub trait Verifiable {
 
 /// Transaction type - mirrors TLA+ Transaction
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Transaction {
     pub id: u64,
     pub sender: ValidatorId,
@@ -238,6 +250,7 @@ pub struct Transaction {
 
 /// Block type - mirrors TLA+ Block exactly
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Block {
     pub slot: SlotNumber,
     pub view: ViewNumber,
@@ -252,6 +265,7 @@ pub struct Block {
 
 /// Vote type - mirrors TLA+ Vote exactly
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Vote {
     pub voter: ValidatorId,
     pub slot: SlotNumber,
@@ -264,6 +278,7 @@ pub struct Vote {
 
 /// Vote type enumeration - mirrors TLA+ VoteType
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum VoteType {
     Proposal,
     Echo,
@@ -273,6 +288,7 @@ pub enum VoteType {
 
 /// Certificate type - mirrors TLA+ Certificate exactly
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Certificate {
     pub slot: SlotNumber,
     pub view: ViewNumber,
@@ -285,6 +301,7 @@ pub struct Certificate {
 
 /// Certificate type enumeration - mirrors TLA+ CertificateType
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum CertificateType {
     Fast,
     Slow,
@@ -298,6 +315,7 @@ pub enum CertificateType {
 /// - Signatures are represented as u64 placeholders rather than actual cryptographic signatures
 /// - In a production implementation, this would require proper BLS signature aggregation
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AggregatedSignature {
     pub signers: BTreeSet<ValidatorId>,
     pub message: MessageHash,
@@ -318,14 +336,18 @@ pub struct ValidatorState {
 
 /// Validator status enumeration - mirrors TLA+ ValidatorStatus
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum ValidatorStatus {
     Honest,
     Byzantine,
     Offline,
+    /// Zero-stake watchtower that receives and validates everything but never votes or proposes
+    Observer,
 }
 
 /// Erasure coded piece type - mirrors TLA+ ErasureCodedPiece
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ErasureCodedPiece {
     pub block_id: BlockHash,
     pub index: u32,
@@ -337,6 +359,7 @@ pub struct ErasureCodedPiece {
 
 /// Network message type - mirrors TLA+ NetworkMessage
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct NetworkMessage {
     pub id: u64,
     pub msg_type: MessageType,
@@ -349,6 +372,7 @@ pub struct NetworkMessage {
 
 /// Message type enumeration - mirrors TLA+ MessageType
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum MessageType {
     Block,
     Vote,
@@ -363,6 +387,7 @@ pub enum MessageType {
 
 /// Message recipient type
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum MessageRecipient {
     Validator(ValidatorId),
     Broadcast,
@@ -395,6 +420,9 @@ pub enum RotorAction {
 pub enum NetworkAction {
     DeliverMessage { message: NetworkMessage },
     DropMessage { message: NetworkMessage },
+    /// Hold a queued message back until `delay` after it was sent, within the partial
+    /// synchrony bounds of `PartialSynchronyModel::schedule_delivery`
+    DelayMessage { message: NetworkMessage, delay: TimeValue },
     PartitionNetwork { partition: BTreeSet<ValidatorId> },
     HealPartition,
 }
@@ -406,6 +434,28 @@ pub enum ByzantineAction {
     InvalidBlock { validator: ValidatorId },
     WithholdShreds { validator: ValidatorId },
     Equivocate { validator: ValidatorId },
+    /// Hold back every queued message `validator` sent by a further `delay`
+    DelayMessages { validator: ValidatorId, delay: TimeValue },
+}
+
+/// Behavior a Byzantine validator prefers when a scheduler picks its actions
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum ByzantineStrategy {
+    Equivocate,
+    WithholdShreds,
+    DoubleVote,
+}
+
+impl ByzantineStrategy {
+    /// The Byzantine action this strategy chooses for `validator` in `view`
+    pub fn action(&self, validator: ValidatorId, view: ViewNumber) -> ByzantineAction {
+        match self {
+            ByzantineStrategy::Equivocate => ByzantineAction::Equivocate { validator },
+            ByzantineStrategy::WithholdShreds => ByzantineAction::WithholdShreds { validator },
+            ByzantineStrategy::DoubleVote => ByzantineAction::DoubleVote { validator, view },
+        }
+    }
 }
 
 /// Main action enumeration combining all protocol actions
@@ -414,6 +464,8 @@ pub enum AlpenglowAction {
     AdvanceClock,
     AdvanceSlot,
     AdvanceView { validator: ValidatorId },
+    /// Set the drift of a validator's local clock relative to the global clock
+    SkewClock { validator: ValidatorId, offset: i64 },
     Votor(VotorAction),
     Rotor(RotorAction),
     Network(NetworkAction),
@@ -422,6 +474,7 @@ pub enum AlpenglowAction {
 
 /// Repair request type - mirrors TLA+ RepairRequest
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct RepairRequest {
     pub requester: ValidatorId,
     pub block_id: BlockHash,
@@ -429,8 +482,19 @@ pub struct RepairRequest {
     pub timestamp: TimeValue,
 }
 
+/// When validators attempt to aggregate received votes into a certificate
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum AggregationBatching {
+    /// Aggregate only when the validator's local clock is a multiple of the interval
+    Interval(TimeValue),
+    /// Aggregate only once the count of votes for the view have arrived since the last aggregation
+    VoteCount(usize),
+}
+
 /// Global configuration for the Alpenglow protocol
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Config {
     /// Number of validators in the network
     pub validator_count: usize,
@@ -505,6 +569,68 @@ pub struct Config {
     /// Network timing parameters
     pub network_delay: u64,
     pub timeout_ms: u64,
+    
+    /// Seed for leader selection, relay assignment and scheduling; equal seeds reproduce runs
+    #[serde(default)]
+    pub seed: u64,
+    
+    /// Finalize through a fast certificate whenever one exists for the block
+    #[serde(default = "default_prefer_fast_path")]
+    pub prefer_fast_path: bool,
+    
+    /// Memory budget in bytes; verification warns when its estimate exceeds this
+    #[serde(default = "default_memory_budget_bytes")]
+    pub memory_budget_bytes: usize,
+    
+    /// Shorten timeouts while online honest stake reaches the fast-path threshold and
+    /// lengthen them when only the slow path is achievable
+    #[serde(default)]
+    pub fast_path_aware_timeouts: bool,
+    
+    /// Largest drift of a validator's local clock from the global clock the protocol tolerates
+    #[serde(default = "default_max_clock_skew")]
+    pub max_clock_skew: TimeValue,
+    
+    /// Time a cast vote spends on the network before recipients see it; 0 records votes
+    /// at every recipient instantly, otherwise votes are gossiped as network messages
+    #[serde(default)]
+    pub vote_gossip_delay: TimeValue,
+    
+    /// Non-voting validators that only validate the states they observe
+    #[serde(default)]
+    pub observers: BTreeSet<ValidatorId>,
+    
+    /// Largest fraction of enqueued messages the network may drop for a scenario to stay realistic
+    #[serde(default = "default_max_message_loss_rate")]
+    pub max_message_loss_rate: f64,
+    
+    /// Batch certificate aggregation instead of attempting it on every vote collection
+    #[serde(default)]
+    pub aggregation_batching: Option<AggregationBatching>,
+    
+    /// Initial status of validators that do not start honest, such as Byzantine or offline ones
+    #[serde(default)]
+    pub validator_statuses: BTreeMap<ValidatorId, ValidatorStatus>,
+    
+    /// Reject configurations whose `byzantine_threshold` highest-stake validators hold a third of the stake
+    #[serde(default)]
+    pub check_byzantine_stake: bool,
+}
+
+fn default_prefer_fast_path() -> bool {
+    true
+}
+
+fn default_memory_budget_bytes() -> usize {
+    2 * 1024 * 1024 * 1024
+}
+
+fn default_max_clock_skew() -> TimeValue {
+    100
+}
+
+fn default_max_message_loss_rate() -> f64 {
+    0.2
 }
 
 impl Default for Config {
@@ -522,6 +648,60 @@ pub struct VerificationResult {
     pub total_states_explored: usize,
     pub violations_found: Vec<PropertyViolation>,
     pub performance_metrics: PerformanceMetrics,
+    /// Deepest BFS level a visited state was found at (the initial states are level 0)
+    pub max_depth: usize,
+    /// Average number of enabled actions per visited state
+    pub avg_branching_factor: f64,
+    /// Configuration warnings raised before exploration, such as an exceeded memory budget
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    /// Safety checks each configured observer ran over the explored states
+    #[serde(default)]
+    pub observer_metrics: BTreeMap<ValidatorId, ObserverMetrics>,
+}
+
+impl VerificationResult {
+    /// Violations ordered by category (safety before byzantine, liveness and performance),
+    /// then criticality, then shortest counterexample first
+    pub fn ranked_violations(&self) -> Vec<PropertyViolation> {
+        let mut ranked = self.violations_found.clone();
+        ranked.sort_by_key(|violation| {
+            let (category, criticality) = violation_severity(&violation.property_name);
+            (category, criticality, violation.violation_step)
+        });
+        ranked
+    }
+}
+
+/// Category rank and criticality of a property named either as in `available_properties`
+/// or in CamelCase as reported by `RichModelChecker`; unknown properties rank last
+fn violation_severity(property_name: &str) -> (u8, PropertyCriticality) {
+    let snake = match property_name {
+        "VotorSafety" => "safety_no_conflicting_finalization".to_string(),
+        "ValidCertificates" => "certificate_validity".to_string(),
+        "ValidErasureCode" => "erasure_coding_validity".to_string(),
+        "ProgressGuarantee" => "liveness_eventual_progress".to_string(),
+        name => name.chars().enumerate().fold(String::new(), |mut snake, (i, c)| {
+            if c.is_ascii_uppercase() && i > 0 {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+            snake
+        }),
+    };
+    
+    ModelChecker::available_properties().into_iter()
+        .find(|info| info.name == snake)
+        .map(|info| {
+            let category = match info.category {
+                PropertyCategory::Safety => 0,
+                PropertyCategory::Byzantine => 1,
+                PropertyCategory::Liveness => 2,
+                PropertyCategory::Performance => 3,
+            };
+            (category, info.criticality)
+        })
+        .unwrap_or((4, PropertyCriticality::Low))
 }
 
 /// Property verification result
@@ -541,6 +721,8 @@ pub enum PropertyStatus {
     Violated,
     Unknown,
     Timeout,
+    /// The property cannot hold by construction (e.g. liveness with too many faults)
+    NotApplicable,
 }
 
 /// State information for export
@@ -561,6 +743,78 @@ pub struct PropertyViolation {
     pub description: String,
 }
 
+/// Destination for property violations, fired as exploration finds each one.
+/// A reporting error aborts the verification run.
+pub trait ViolationReporter: Debug + Send + Sync {
+    fn report(&self, violation: &PropertyViolation, config: &Config) -> AlpenglowResult<()>;
+}
+
+/// Writes a one-line summary of each violation to stderr
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogReporter;
+
+impl ViolationReporter for LogReporter {
+    fn report(&self, violation: &PropertyViolation, _config: &Config) -> AlpenglowResult<()> {
+        eprintln!(
+            "Property {} violated at step {} after {:?}: {}",
+            violation.property_name, violation.violation_step, violation.action, violation.description
+        );
+        Ok(())
+    }
+}
+
+/// Appends each violation, counterexample state included, as one JSON line to a file
+#[derive(Debug, Clone)]
+pub struct JsonFileReporter {
+    path: PathBuf,
+}
+
+impl JsonFileReporter {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl ViolationReporter for JsonFileReporter {
+    fn report(&self, violation: &PropertyViolation, _config: &Config) -> AlpenglowResult<()> {
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| {
+                let line = serde_json::to_string(violation).map_err(std::io::Error::from)?;
+                writeln!(file, "{}", line)
+            })
+            .map_err(|e| AlpenglowError::IoError(format!("Failed to report violation to {}: {}", self.path.display(), e)))
+    }
+}
+
+/// Function called with each violation and the configuration it was found under
+pub type ViolationCallback = dyn Fn(&PropertyViolation, &Config) + Send + Sync;
+
+/// Hands each violation to a user-supplied function
+#[derive(Clone)]
+pub struct CallbackReporter(Arc<ViolationCallback>);
+
+impl CallbackReporter {
+    pub fn new(callback: impl Fn(&PropertyViolation, &Config) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+}
+
+impl Debug for CallbackReporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CallbackReporter")
+    }
+}
+
+impl ViolationReporter for CallbackReporter {
+    fn report(&self, violation: &PropertyViolation, config: &Config) -> AlpenglowResult<()> {
+        (self.0)(violation, config);
+        Ok(())
+    }
+}
+
 /// Performance metrics for verification
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PerformanceMetrics {
@@ -570,6 +824,228 @@ pub struct PerformanceMetrics {
     pub property_check_time_ms: HashMap<String, u64>,
 }
 
+/// Class of actions a fairness constraint applies to, ignoring action parameters
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ActionClass {
+    AdvanceClock,
+    AdvanceSlot,
+    AdvanceView,
+    SkewClock,
+    Votor,
+    Rotor,
+    Network,
+    Byzantine,
+}
+
+impl ActionClass {
+    /// Class of `action`
+    pub fn of(action: &AlpenglowAction) -> Self {
+        match action {
+            AlpenglowAction::AdvanceClock => ActionClass::AdvanceClock,
+            AlpenglowAction::AdvanceSlot => ActionClass::AdvanceSlot,
+            AlpenglowAction::AdvanceView { .. } => ActionClass::AdvanceView,
+            AlpenglowAction::SkewClock { .. } => ActionClass::SkewClock,
+            AlpenglowAction::Votor(_) => ActionClass::Votor,
+            AlpenglowAction::Rotor(_) => ActionClass::Rotor,
+            AlpenglowAction::Network(_) => ActionClass::Network,
+            AlpenglowAction::Byzantine(_) => ActionClass::Byzantine,
+        }
+    }
+}
+
+/// Fairness strength - mirrors TLA+ WF and SF
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Fairness {
+    /// An action class continuously enabled from some point on is eventually taken
+    Weak,
+    /// An action class enabled infinitely often is eventually taken
+    Strong,
+}
+
+/// Fairness assumptions restricting which infinite behaviors liveness is checked against
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FairnessSpec {
+    pub constraints: Vec<(Fairness, ActionClass)>,
+}
+
+impl FairnessSpec {
+    /// No fairness: every schedule, including stuttering forever, is admissible
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Assume weak fairness on `class`
+    pub fn weak(mut self, class: ActionClass) -> Self {
+        self.constraints.push((Fairness::Weak, class));
+        self
+    }
+    
+    /// Assume strong fairness on `class`
+    pub fn strong(mut self, class: ActionClass) -> Self {
+        self.constraints.push((Fairness::Strong, class));
+        self
+    }
+}
+
+/// Order in which the model checker explores pending states
+#[derive(Debug, Clone, Copy)]
+pub enum ExplorationStrategy {
+    /// Breadth-first: shallowest states first, so counterexamples are shortest
+    Bfs,
+    /// Highest-scoring states first, ties broken by discovery order
+    Guided(fn(&AlpenglowState) -> i64),
+}
+
+impl ExplorationStrategy {
+    /// Priority of the `order`-th discovered state; the highest is explored first
+    fn priority(&self, state: &AlpenglowState, order: u64) -> (i64, i64) {
+        let order = order as i64;
+        match self {
+            ExplorationStrategy::Bfs => (0, -order),
+            ExplorationStrategy::Guided(heuristic) => (heuristic(state), -order),
+        }
+    }
+}
+
+/// How `RichModelChecker::verify_with_progress` searches the state space
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStrategy {
+    /// Keep a frontier of pending states, explored in the order of the checker's `ExplorationStrategy`
+    Bfs,
+    /// Follow one path at a time with an explicit stack, at most `max_depth` actions deep
+    Dfs { max_depth: usize },
+}
+
+/// A discovered state waiting to be explored, with its depth and the fingerprint of the
+/// explored state and the action it was reached by
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PendingState {
+    state: AlpenglowState,
+    depth: usize,
+    parent: Option<(u64, AlpenglowAction)>,
+}
+
+/// A pending state in a frontier, in discovery order `order`
+#[derive(Debug)]
+struct Prioritized {
+    priority: (i64, i64),
+    order: u64,
+    pending: PendingState,
+}
+
+impl PartialEq for Prioritized {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl Eq for Prioritized {}
+
+impl PartialOrd for Prioritized {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Prioritized {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+/// Pending states of a frontier search, popped in the order of an `ExplorationStrategy`.
+/// Priorities are computed once per state, so every strategy pushes and pops in O(log n).
+#[derive(Debug)]
+struct Frontier {
+    strategy: ExplorationStrategy,
+    heap: BinaryHeap<Prioritized>,
+    discovered: u64,
+}
+
+impl Frontier {
+    /// Frontier over `pending`, given in discovery order
+    fn new(strategy: ExplorationStrategy, pending: impl IntoIterator<Item = PendingState>) -> Self {
+        let mut frontier = Self { strategy, heap: BinaryHeap::new(), discovered: 0 };
+        for pending in pending {
+            frontier.push(pending);
+        }
+        frontier
+    }
+    
+    fn push(&mut self, pending: PendingState) {
+        let priority = self.strategy.priority(&pending.state, self.discovered);
+        self.heap.push(Prioritized { priority, order: self.discovered, pending });
+        self.discovered += 1;
+    }
+    
+    fn pop(&mut self) -> Option<PendingState> {
+        self.heap.pop().map(|prioritized| prioritized.pending)
+    }
+    
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+    
+    /// The pending states in discovery order, independent of the strategy
+    fn into_pending(self) -> Vec<PendingState> {
+        let mut pending = self.heap.into_vec();
+        pending.sort_by_key(|prioritized| prioritized.order);
+        pending.into_iter().map(|prioritized| prioritized.pending).collect()
+    }
+}
+
+/// Results and violations of the property checks run on one state or transition
+type PropertyChecks = (HashMap<String, PropertyResult>, Vec<PropertyViolation>);
+
+/// Safety results of an explored state, its enabled action count, and its successors with
+/// the results of the transitions to them
+#[derive(Debug)]
+struct Expansion {
+    checked: PropertyChecks,
+    enabled: usize,
+    successors: Vec<(AlpenglowAction, AlpenglowState, PropertyChecks)>,
+}
+
+/// What a search has recorded over the states it explored
+#[derive(Debug, Default)]
+struct SearchFindings {
+    property_results: HashMap<String, PropertyResult>,
+    violations_found: Vec<PropertyViolation>,
+    observer_metrics: BTreeMap<ValidatorId, ObserverMetrics>,
+    enabled_actions: usize,
+}
+
+/// Version of the checkpoint layout and of the state fingerprint scheme stored in it. Bump it
+/// whenever `SearchCheckpoint` or `AlpenglowState::fingerprint` changes, so checkpoints written
+/// by an older build are rejected instead of resumed against incompatible fingerprints.
+const CHECKPOINT_FORMAT_VERSION: u32 = 2;
+
+/// Search state of a frontier run of `verify_with_progress`, enough to continue it later
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SearchCheckpoint {
+    /// `CHECKPOINT_FORMAT_VERSION` of the build that took the checkpoint; kept first so it can
+    /// be read before the rest of the checkpoint is decoded
+    format_version: u32,
+    config: Config,
+    max_states: usize,
+    exploration_depth: usize,
+    /// Pending states in discovery order
+    pending: Vec<PendingState>,
+    /// Fingerprints of the states explored so far
+    visited: HashSet<u64>,
+    /// Fingerprint of the explored state and the action each explored non-initial state was reached by
+    #[serde(default)]
+    parents: HashMap<u64, (u64, AlpenglowAction)>,
+    /// The explored states themselves, kept only while state collection is enabled
+    explored_states: Vec<AlpenglowState>,
+    depth: usize,
+    enabled_actions: usize,
+    property_results: HashMap<String, PropertyResult>,
+    violations_found: Vec<PropertyViolation>,
+    #[serde(default)]
+    observer_metrics: BTreeMap<ValidatorId, ObserverMetrics>,
+}
+
 /// Model checker with enhanced capabilities
 #[derive(Debug, Clone)]
 pub struct RichModelChecker {
@@ -581,6 +1057,19 @@ pub struct RichModelChecker {
     pub representative_sampling_enabled: bool,
     pub trace_collection_enabled: bool,
     pub scenario_filter: Option<String>,
+    /// Reporters fired for every violation found during verification
+    pub violation_reporters: Vec<Arc<dyn ViolationReporter>>,
+    /// Fairness assumed when searching for liveness counterexamples
+    pub fairness: FairnessSpec,
+    /// Order in which pending states are explored
+    pub strategy: ExplorationStrategy,
+    /// Whether to search with a frontier or depth-first along a single path
+    pub search_strategy: SearchStrategy,
+    /// Where the last frontier run stopped, written by `save_checkpoint`
+    #[cfg(feature = "checkpoint")]
+    last_search: Option<SearchCheckpoint>,
+    /// Search state the next frontier run continues from
+    resume_from: Option<SearchCheckpoint>,
 }
 
 impl RichModelChecker {
@@ -595,7 +1084,82 @@ impl RichModelChecker {
             representative_sampling_enabled: false,
             trace_collection_enabled: false,
             scenario_filter: None,
+            violation_reporters: Vec::new(),
+            fairness: FairnessSpec::new(),
+            strategy: ExplorationStrategy::Bfs,
+            search_strategy: SearchStrategy::Bfs,
+            #[cfg(feature = "checkpoint")]
+            last_search: None,
+            resume_from: None,
+        }
+    }
+    
+    /// Write where the last `verify_with_progress` run stopped (pending frontier, visited
+    /// fingerprints, property results and violations) to `path` in bincode, so a long run can
+    /// be continued with `resume_from_checkpoint`. Fails if no frontier run has happened yet.
+    #[cfg(feature = "checkpoint")]
+    pub fn save_checkpoint<P: AsRef<Path>>(&self, path: P) -> AlpenglowResult<()> {
+        let search = self.last_search.as_ref()
+            .ok_or_else(|| AlpenglowError::StateInconsistency("No verification run to checkpoint".to_string()))?;
+        let bytes = bincode::serialize(search)
+            .map_err(|e| AlpenglowError::SerializationError(format!("Failed to serialize checkpoint: {}", e)))?;
+        
+        fs::write(path, bytes)
+            .map_err(|e| AlpenglowError::IoError(format!("Failed to write checkpoint file: {}", e)))?;
+        
+        Ok(())
+    }
+    
+    /// Checker whose next `verify_with_progress` run continues the search saved at `path`.
+    /// The state budget and depth limit are those of the saved run; raise them with
+    /// `set_max_states` and `set_exploration_depth` to explore further. `config` must be the
+    /// configuration the checkpoint was taken with, by a build with the same checkpoint format.
+    #[cfg(feature = "checkpoint")]
+    pub fn resume_from_checkpoint<P: AsRef<Path>>(path: P, config: Config) -> AlpenglowResult<Self> {
+        let bytes = fs::read(path)
+            .map_err(|e| AlpenglowError::IoError(format!("Failed to read checkpoint file: {}", e)))?;
+        let format_version: u32 = bincode::deserialize(&bytes)
+            .map_err(|e| AlpenglowError::SerializationError(format!("Failed to read checkpoint format version: {}", e)))?;
+        if format_version != CHECKPOINT_FORMAT_VERSION {
+            return Err(AlpenglowError::SerializationError(format!(
+                "Checkpoint format version {} is not supported (expected {})",
+                format_version, CHECKPOINT_FORMAT_VERSION
+            )));
+        }
+        let search: SearchCheckpoint = bincode::deserialize(&bytes)
+            .map_err(|e| AlpenglowError::SerializationError(format!("Failed to deserialize checkpoint: {}", e)))?;
+        if search.config != config {
+            return Err(AlpenglowError::InvalidConfig("Checkpoint was taken with a different configuration".to_string()));
         }
+        
+        let mut checker = Self::new(config);
+        checker.max_states = search.max_states;
+        checker.exploration_depth = search.exploration_depth;
+        checker.state_collection_enabled = !search.explored_states.is_empty();
+        checker.resume_from = Some(search);
+        Ok(checker)
+    }
+    
+    /// Count one validated state violating `violations` for every configured observer
+    fn record_observations(&self, observer_metrics: &mut BTreeMap<ValidatorId, ObserverMetrics>, violations: &[PropertyViolation]) {
+        for observer in &self.config.observers {
+            let metrics = observer_metrics.entry(*observer).or_default();
+            metrics.states_validated += 1;
+            for violation in violations {
+                *metrics.violations.entry(violation.property_name.clone()).or_default() += 1;
+            }
+        }
+    }
+    
+    /// Warnings about the configuration to report with every verification result
+    fn config_warnings(&self) -> Vec<String> {
+        self.config.memory_budget_warning().into_iter().collect()
+    }
+    
+    /// Check liveness only against behaviors satisfying `fairness`
+    pub fn with_fairness(mut self, fairness: FairnessSpec) -> Self {
+        self.fairness = fairness;
+        self
     }
     
     /// Enable state collection for export
@@ -628,49 +1192,192 @@ impl RichModelChecker {
         self.trace_collection_enabled = true;
     }
     
+    /// Set the order in which pending states are explored
+    pub fn set_strategy(&mut self, strategy: ExplorationStrategy) {
+        self.strategy = strategy;
+    }
+    
+    /// Choose between frontier search and bounded depth-first search.
+    ///
+    /// A frontier holds every discovered but unexplored state, which for BFS grows with the
+    /// width of the deepest level and runs out of memory long before deep states are reached.
+    /// Depth-first search only keeps the current path, at most `max_depth` states and their
+    /// unexplored successors, plus the shallowest depth of each visited state, so it reaches
+    /// deep states such as late finalizations cheaply. The price is coverage: within a
+    /// `max_states` budget it explores a few deep paths rather than every shallow state, states
+    /// reached again by a shorter path are explored again, and counterexamples it finds need
+    /// not be the shortest ones.
+    pub fn set_search_strategy(&mut self, search_strategy: SearchStrategy) {
+        self.search_strategy = search_strategy;
+    }
+    
     /// Set scenario filter
     pub fn set_scenario_filter(&mut self, scenario: String) {
         self.scenario_filter = Some(scenario);
     }
     
-    /// Verify model and return detailed results
+    /// Register a reporter that receives each violation as it is found
+    pub fn add_violation_reporter(&mut self, reporter: impl ViolationReporter + 'static) {
+        self.violation_reporters.push(Arc::new(reporter));
+    }
+    
+    /// Check that every fair behavior eventually reaches a state satisfying `goal` (TLA+ `<>goal`).
+    ///
+    /// Explores the states before `goal` holds, within `max_states` and `exploration_depth`, and
+    /// searches them for a lasso: a cycle that never reaches `goal` and satisfies `fairness`.
+    /// Every state may stutter, as in TLA+, so without fairness any state can loop forever.
+    /// States at the exploration bound have unknown futures and are never part of a lasso.
+    /// The counterexample is the stem of the lasso, the actions leading into its cycle.
+    pub fn check_eventually(&self, property_name: &str, goal: impl Fn(&AlpenglowState) -> bool) -> PropertyResult {
+        let model = AlpenglowModel::new(self.config.clone());
+        let mut states: Vec<AlpenglowState> = Vec::new();
+        // Index of each known state, keyed by fingerprint as in the other searches
+        let mut indices: HashMap<u64, usize> = HashMap::new();
+        let mut parents: Vec<Option<(usize, AlpenglowAction)>> = Vec::new();
+        let mut depths: Vec<usize> = Vec::new();
+        let mut edges: BTreeMap<usize, Vec<(usize, ActionClass)>> = BTreeMap::new();
+        let mut enabled: BTreeMap<usize, BTreeSet<ActionClass>> = BTreeMap::new();
+        
+        for state in model.init_states() {
+            let fingerprint = state.fingerprint();
+            if !goal(&state) && !indices.contains_key(&fingerprint) {
+                indices.insert(fingerprint, states.len());
+                states.push(state);
+                parents.push(None);
+                depths.push(0);
+            }
+        }
+        
+        let mut next = 0;
+        while next < states.len() {
+            let index = next;
+            next += 1;
+            if depths[index] >= self.exploration_depth {
+                continue;
+            }
+            
+            let state = states[index].clone();
+            let mut actions = Vec::new();
+            model.actions(&state, &mut actions);
+            let mut successors = Vec::new();
+            let mut classes = BTreeSet::new();
+            for action in actions {
+                let Some(successor) = model.next_state(&state, action.clone()) else { continue };
+                let class = ActionClass::of(&action);
+                classes.insert(class);
+                if goal(&successor) {
+                    continue;
+                }
+                let fingerprint = successor.fingerprint();
+                let target = match indices.get(&fingerprint) {
+                    Some(&target) => target,
+                    None if states.len() < self.max_states => {
+                        indices.insert(fingerprint, states.len());
+                        states.push(successor);
+                        parents.push(Some((index, action)));
+                        depths.push(depths[index] + 1);
+                        states.len() - 1
+                    }
+                    None => continue,
+                };
+                successors.push((target, class));
+            }
+            edges.insert(index, successors);
+            enabled.insert(index, classes);
+        }
+        
+        // Only expanded states have known futures
+        let expanded: BTreeSet<usize> = edges.keys().copied().collect();
+        let lasso = fair_cycle(&expanded, &edges, &enabled, &self.fairness.constraints);
+        
+        let counterexample = lasso.map(|cycle| {
+            let mut stem = Vec::new();
+            let mut current = *cycle.iter().next().unwrap();
+            while let Some((parent, action)) = &parents[current] {
+                stem.push(action.clone());
+                current = *parent;
+            }
+            stem.reverse();
+            stem
+        });
+        
+        PropertyResult {
+            property_name: property_name.to_string(),
+            status: if counterexample.is_some() { PropertyStatus::Violated } else { PropertyStatus::Satisfied },
+            violation_count: counterexample.iter().count(),
+            first_violation_step: counterexample.as_ref().map(|stem| stem.len()),
+            counterexample,
+        }
+    }
+    
+    /// Fire every registered reporter for each of `violations`
+    fn report_violations(&self, violations: &[PropertyViolation]) -> AlpenglowResult<()> {
+        for violation in violations {
+            for reporter in &self.violation_reporters {
+                reporter.report(violation, &self.config)?;
+            }
+        }
+        Ok(())
+    }
+    
+    /// Verify model and return detailed results. Reachable states are explored breadth-first
+    /// within `max_states` and `exploration_depth`, checking safety on every state and
+    /// transition; liveness and performance are checked on the initial state.
     pub fn verify_model(&mut self) -> AlpenglowResult<VerificationResult> {
         let start_time = Instant::now();
-        let mut property_results = HashMap::new();
         let mut collected_states = Vec::new();
-        let mut violations_found = Vec::new();
-        
-        // Create initial model
         let model = AlpenglowModel::new(self.config.clone());
+        let mut findings = SearchFindings::default();
+        let mut visited: HashSet<u64> = HashSet::new();
+        let mut parents: HashMap<u64, (u64, AlpenglowAction)> = HashMap::new();
+        let mut pending: VecDeque<PendingState> = model.init_states().into_iter()
+            .map(|state| PendingState { state, depth: 0, parent: None })
+            .collect();
+        let mut max_depth = 0;
+        let mut peak_queue_size = pending.len();
         
-        // Collect initial state if enabled
-        if self.state_collection_enabled {
-            collected_states.push(StateInfo {
-                state: model.state.clone(),
-                state_type: "initial".to_string(),
-                metadata: HashMap::new(),
-            });
+        while visited.len() < self.max_states {
+            let Some(PendingState { state, depth, parent }) = pending.pop_front() else { break };
+            let fingerprint = state.fingerprint();
+            if depth > self.exploration_depth || !visited.insert(fingerprint) {
+                continue;
+            }
+            parents.extend(parent.map(|parent| (fingerprint, parent)));
+            max_depth = max_depth.max(depth);
+            
+            let expansion = self.expand(&model, &state);
+            let successors = self.record_expansion(&mut findings, expansion, depth, || Some(Self::trace_to(&parents, fingerprint)))?;
+            pending.extend(successors.into_iter().map(|(action, next)| PendingState {
+                state: next,
+                depth: depth + 1,
+                parent: Some((fingerprint, action)),
+            }));
+            peak_queue_size = peak_queue_size.max(pending.len());
+            if self.state_collection_enabled {
+                collected_states.push(StateInfo {
+                    state,
+                    state_type: if depth == 0 { "initial" } else { "explored" }.to_string(),
+                    metadata: HashMap::new(),
+                });
+            }
         }
+        let SearchFindings { mut property_results, mut violations_found, observer_metrics, enabled_actions } = findings;
         
-        // Run property checks
-        let safety_result = self.check_all_safety_properties(&model.state);
-        property_results.extend(safety_result.0);
-        violations_found.extend(safety_result.1);
-        
-        let liveness_result = self.check_all_liveness_properties(&model.state);
-        property_results.extend(liveness_result.0);
-        violations_found.extend(liveness_result.1);
+        let (liveness_results, liveness_violations) = self.check_all_liveness_properties(&model.state);
+        let (performance_results, performance_violations) = self.check_all_performance_properties(&model.state);
+        property_results.extend(liveness_results);
+        property_results.extend(performance_results);
+        self.report_violations(&liveness_violations)?;
+        self.report_violations(&performance_violations)?;
+        violations_found.extend(liveness_violations);
+        violations_found.extend(performance_violations);
         
-        let performance_result = self.check_all_performance_properties(&model.state);
-        property_results.extend(performance_result.0);
-        violations_found.extend(performance_result.1);
-        
-        // Calculate performance metrics
         let duration = start_time.elapsed();
+        let states_explored = visited.len();
         let performance_metrics = PerformanceMetrics {
-            states_per_second: collected_states.len() as f64 / duration.as_secs_f64(),
-            memory_usage_mb: 0.0, // Placeholder
-            peak_queue_size: collected_states.len(),
+            states_per_second: states_explored as f64 / duration.as_secs_f64(),
+            memory_usage_mb: self.config.estimate_memory_for_verification() as f64 / (1024.0 * 1024.0),
+            peak_queue_size,
             property_check_time_ms: HashMap::new(),
         };
         
@@ -678,69 +1385,612 @@ impl RichModelChecker {
             property_results,
             collected_states,
             verification_time_ms: duration.as_millis() as u64,
-            total_states_explored: 1,
+            total_states_explored: states_explored,
             violations_found,
             performance_metrics,
+            max_depth,
+            avg_branching_factor: if states_explored == 0 { 0.0 } else { enabled_actions as f64 / states_explored as f64 },
+            warnings: self.config_warnings(),
+            observer_metrics,
         })
     }
     
-    /// Check all safety properties
-    fn check_all_safety_properties(&self, state: &AlpenglowState) -> (HashMap<String, PropertyResult>, Vec<PropertyViolation>) {
-        let mut results = HashMap::new();
-        let mut violations = Vec::new();
-        
-        // Safety properties from property mapping
-        let properties = vec![
-            ("VotorSafety", properties::safety_no_conflicting_finalization_detailed(state, &self.config)),
-            ("ValidCertificates", properties::certificate_validity_detailed(state, &self.config)),
-            ("ByzantineResilience", properties::byzantine_resilience_detailed(state, &self.config)),
-            ("BandwidthSafety", properties::bandwidth_safety_detailed(state, &self.config)),
-            ("ValidErasureCode", properties::erasure_coding_validity_detailed(state, &self.config)),
-            ("ReconstructionCorrectness", properties::chain_consistency_detailed(state, &self.config)),
-        ];
-        
-        for (name, check_result) in properties {
-            let status = if check_result.passed {
-                PropertyStatus::Satisfied
-            } else {
-                PropertyStatus::Violated
-            };
-            
-            let property_result = PropertyResult {
-                property_name: name.to_string(),
-                status: status.clone(),
-                violation_count: if check_result.passed { 0 } else { 1 },
-                first_violation_step: if check_result.passed { None } else { Some(0) },
-                counterexample: None,
-            };
-            
-            results.insert(name.to_string(), property_result);
+    /// Explore the model in the order of `strategy`, writing one JSON line each time the search
+    /// first reaches a new depth (states explored, pending states, elapsed time and violations
+    /// so far at the previous depth) and a final summary line. Breadth-first, that is one line
+    /// per BFS level. Exploration stops after `exploration_depth` levels or `max_states` states;
+    /// a failed write aborts the run, so readers can stop it by closing the stream. Every
+    /// violated property records the actions leading to its first violation as counterexample.
+    /// Under `SearchStrategy::Dfs` the search runs depth-first instead (see `set_search_strategy`).
+    ///
+    /// A frontier search continues from a checkpoint loaded by `resume_from_checkpoint`, and
+    /// where it stops can be saved with `save_checkpoint`.
+    pub fn verify_with_progress(&mut self, mut writer: impl Write) -> AlpenglowResult<VerificationResult> {
+        if let SearchStrategy::Dfs { max_depth } = self.search_strategy {
+            return self.verify_depth_first(writer, max_depth);
+        }
+        let start_time = Instant::now();
+        let model = AlpenglowModel::new(self.config.clone());
+        let SearchCheckpoint {
+            pending,
+            mut visited,
+            mut parents,
+            mut explored_states,
+            mut depth,
+            enabled_actions,
+            property_results,
+            violations_found,
+            observer_metrics,
+            ..
+        } = self.resume_from.take().unwrap_or_else(|| SearchCheckpoint {
+            format_version: CHECKPOINT_FORMAT_VERSION,
+            config: self.config.clone(),
+            max_states: self.max_states,
+            exploration_depth: self.exploration_depth,
+            pending: model.init_states().into_iter().map(|state| PendingState { state, depth: 0, parent: None }).collect(),
+            visited: HashSet::new(),
+            parents: HashMap::new(),
+            explored_states: Vec::new(),
+            depth: 0,
+            enabled_actions: 0,
+            property_results: HashMap::new(),
+            violations_found: Vec::new(),
+            observer_metrics: BTreeMap::new(),
+        });
+        let mut findings = SearchFindings { property_results, violations_found, observer_metrics, enabled_actions };
+        let mut pending = Frontier::new(self.strategy, pending);
+        let mut peak_frontier = pending.len();
+        
+        let emit = |writer: &mut dyn Write, line: serde_json::Value| {
+            writeln!(writer, "{}", line)
+                .and_then(|_| writer.flush())
+                .map_err(|e| AlpenglowError::IoError(format!("Failed to write progress: {}", e)))
+        };
+        let level = |depth: usize, states_explored: usize, frontier_size: usize, violations: &[PropertyViolation]| {
+            let violated: BTreeSet<&str> = violations.iter().map(|v| v.property_name.as_str()).collect();
+            serde_json::json!({
+                "type": "level",
+                "depth": depth,
+                "states_explored": states_explored,
+                "frontier_size": frontier_size,
+                "elapsed_ms": start_time.elapsed().as_millis() as u64,
+                "violations": violated,
+            })
+        };
+        while visited.len() < self.max_states {
+            let Some(PendingState { state, depth: state_depth, parent }) = pending.pop() else { break };
+            let fingerprint = state.fingerprint();
+            if state_depth > self.exploration_depth || !visited.insert(fingerprint) {
+                continue;
+            }
+            parents.extend(parent.map(|parent| (fingerprint, parent)));
+            if state_depth > depth {
+                emit(&mut writer, level(depth, visited.len() - 1, pending.len() + 1, &findings.violations_found))?;
+                depth = state_depth;
+            }
             
-            if !check_result.passed {
-                violations.push(PropertyViolation {
-                    property_name: name.to_string(),
-                    violation_step: 0,
-                    state: state.clone(),
-                    action: AlpenglowAction::AdvanceClock, // Placeholder
-                    description: check_result.error.unwrap_or_else(|| "Property violation".to_string()),
-                });
+            let expansion = self.expand(&model, &state);
+            let successors = self.record_expansion(&mut findings, expansion, state_depth, || Some(Self::trace_to(&parents, fingerprint)))?;
+            for (action, next) in successors {
+                pending.push(PendingState { state: next, depth: state_depth + 1, parent: Some((fingerprint, action)) });
             }
+            peak_frontier = peak_frontier.max(pending.len());
+            if self.state_collection_enabled {
+                explored_states.push(state);
+            }
+        }
+        let SearchFindings { property_results, violations_found, observer_metrics, enabled_actions } = findings;
+        if !visited.is_empty() {
+            emit(&mut writer, level(depth, visited.len(), pending.len(), &violations_found))?;
         }
+        let levels = if visited.is_empty() { 0 } else { depth + 1 };
         
-        (results, violations)
-    }
-    
-    /// Check all liveness properties
-    fn check_all_liveness_properties(&self, state: &AlpenglowState) -> (HashMap<String, PropertyResult>, Vec<PropertyViolation>) {
-        let mut results = HashMap::new();
+        let duration = start_time.elapsed();
+        emit(&mut writer, serde_json::json!({
+            "type": "summary",
+            "states_explored": visited.len(),
+            "levels": levels,
+            "elapsed_ms": duration.as_millis() as u64,
+            "violations": violations_found.len(),
+            "passed": violations_found.is_empty(),
+        }))?;
+        
+        let collected_states = explored_states.iter().map(|state| StateInfo {
+            state: state.clone(),
+            state_type: "explored".to_string(),
+            metadata: HashMap::new(),
+        }).collect();
+        let states_explored = visited.len();
+        let avg_branching_factor = if visited.is_empty() { 0.0 } else { enabled_actions as f64 / states_explored as f64 };
+        #[cfg(feature = "checkpoint")]
+        {
+            self.last_search = Some(SearchCheckpoint {
+                format_version: CHECKPOINT_FORMAT_VERSION,
+                config: self.config.clone(),
+                max_states: self.max_states,
+                exploration_depth: self.exploration_depth,
+                pending: pending.into_pending(),
+                visited,
+                parents,
+                explored_states,
+                depth,
+                enabled_actions,
+                property_results: property_results.clone(),
+                violations_found: violations_found.clone(),
+                observer_metrics: observer_metrics.clone(),
+            });
+        }
+        
+        Ok(VerificationResult {
+            property_results,
+            collected_states,
+            verification_time_ms: duration.as_millis() as u64,
+            total_states_explored: states_explored,
+            violations_found,
+            performance_metrics: PerformanceMetrics {
+                states_per_second: states_explored as f64 / duration.as_secs_f64(),
+                memory_usage_mb: self.config.estimate_memory_for_verification() as f64 / (1024.0 * 1024.0),
+                peak_queue_size: peak_frontier,
+                property_check_time_ms: HashMap::new(),
+            },
+            max_depth: depth,
+            avg_branching_factor,
+            warnings: self.config_warnings(),
+            observer_metrics,
+        })
+    }
+    
+    /// Iterative depth-first search for `verify_with_progress` under `SearchStrategy::Dfs`.
+    ///
+    /// Successors are tried last enumerated first or, under `ExplorationStrategy::Guided`,
+    /// highest-scoring first. A state is explored again when reached by a shorter path than
+    /// before, so everything within `max_depth` actions is covered given enough `max_states`.
+    /// Writes a level line whenever the path first gets deeper and a final summary line. Every
+    /// violated property records the actions leading to its first violation as counterexample;
+    /// the violating states themselves are only kept when violation collection is enabled.
+    fn verify_depth_first(&mut self, mut writer: impl Write, max_depth: usize) -> AlpenglowResult<VerificationResult> {
+        let start_time = Instant::now();
+        let model = AlpenglowModel::new(self.config.clone());
+        let mut findings = SearchFindings::default();
+        let mut collected_states = Vec::new();
+        // Shallowest depth each visited state has been explored at
+        let mut depths: HashMap<u64, usize> = HashMap::new();
+        let mut roots = model.init_states();
+        roots.reverse();
+        // One frame per state on the current path holding its unexplored successors, tried from
+        // the back; `path` holds the actions between consecutive frames
+        let mut stack: Vec<Vec<(AlpenglowAction, AlpenglowState)>> = Vec::new();
+        let mut path: Vec<AlpenglowAction> = Vec::new();
+        let mut expanded = 0;
+        let mut deepest = 0;
+        let mut peak_stack = 0;
+        
+        let mut emit = |line: serde_json::Value| {
+            writeln!(writer, "{}", line)
+                .and_then(|_| writer.flush())
+                .map_err(|e| AlpenglowError::IoError(format!("Failed to write progress: {}", e)))
+        };
+        
+        while expanded < self.max_states {
+            let (state, action) = match stack.last_mut() {
+                Some(successors) => match successors.pop() {
+                    Some((action, next)) => (next, Some(action)),
+                    None => {
+                        stack.pop();
+                        path.pop();
+                        continue;
+                    }
+                },
+                None => match roots.pop() {
+                    Some(root) => (root, None),
+                    None => break,
+                },
+            };
+            let depth = stack.len();
+            match depths.get(&state.fingerprint()) {
+                Some(&shallowest) if shallowest <= depth => continue,
+                _ => depths.insert(state.fingerprint(), depth),
+            };
+            path.extend(action);
+            expanded += 1;
+            if depth > deepest {
+                let violated: BTreeSet<&str> = findings.violations_found.iter().map(|v| v.property_name.as_str()).collect();
+                emit(serde_json::json!({
+                    "type": "level",
+                    "depth": deepest,
+                    "states_explored": depths.len() - 1,
+                    "frontier_size": stack.len(),
+                    "elapsed_ms": start_time.elapsed().as_millis() as u64,
+                    "violations": violated,
+                }))?;
+                deepest = depth;
+            }
+            
+            let expansion = self.expand(&model, &state);
+            let mut successors = self.record_expansion(&mut findings, expansion, depth, || Some(path.clone()))?;
+            if depth >= max_depth {
+                successors.clear();
+            }
+            if let ExplorationStrategy::Guided(heuristic) = self.strategy {
+                successors.sort_by_cached_key(|(_, next)| heuristic(next));
+            }
+            if self.state_collection_enabled {
+                collected_states.push(StateInfo {
+                    state,
+                    state_type: "explored".to_string(),
+                    metadata: HashMap::new(),
+                });
+            }
+            stack.push(successors);
+            peak_stack = peak_stack.max(stack.len());
+        }
+        let SearchFindings { property_results, mut violations_found, observer_metrics, enabled_actions } = findings;
+        let violation_count = violations_found.len();
+        if !self.violation_collection_enabled {
+            violations_found.clear();
+        }
+        
+        let duration = start_time.elapsed();
+        let states_explored = depths.len();
+        emit(serde_json::json!({
+            "type": "summary",
+            "states_explored": states_explored,
+            "levels": if states_explored == 0 { 0 } else { deepest + 1 },
+            "elapsed_ms": duration.as_millis() as u64,
+            "violations": violation_count,
+            "passed": violation_count == 0,
+        }))?;
+        
+        Ok(VerificationResult {
+            property_results,
+            collected_states,
+            verification_time_ms: duration.as_millis() as u64,
+            total_states_explored: states_explored,
+            violations_found,
+            performance_metrics: PerformanceMetrics {
+                states_per_second: states_explored as f64 / duration.as_secs_f64(),
+                memory_usage_mb: self.config.estimate_memory_for_verification() as f64 / (1024.0 * 1024.0),
+                peak_queue_size: peak_stack,
+                property_check_time_ms: HashMap::new(),
+            },
+            max_depth: deepest,
+            avg_branching_factor: if expanded == 0 { 0.0 } else { enabled_actions as f64 / expanded as f64 },
+            warnings: self.config_warnings(),
+            observer_metrics,
+        })
+    }
+    
+    /// Run the safety checks on `state` and expand it, checking every transition to a successor.
+    /// Only reads the checker, so the states of a level can be expanded in parallel.
+    fn expand(&self, model: &AlpenglowModel, state: &AlpenglowState) -> Expansion {
+        let checked = self.check_all_safety_properties(state);
+        let mut actions = Vec::new();
+        model.actions(state, &mut actions);
+        let enabled = actions.len();
+        let successors = actions.into_iter().filter_map(|action| {
+            let next = model.next_state(state, action.clone())?;
+            let checked = self.check_transition_properties(state, &action, &next);
+            Some((action, next, checked))
+        }).collect();
+        Expansion { checked, enabled, successors }
+    }
+    
+    /// Merge the expansion of a state found `depth` levels deep into `findings`, reporting its
+    /// violations, and return its successors with the actions leading to them. `trace` gives
+    /// the actions reaching the state, kept as counterexample of first violations.
+    fn record_expansion(
+        &self,
+        findings: &mut SearchFindings,
+        expansion: Expansion,
+        depth: usize,
+        trace: impl Fn() -> Option<Vec<AlpenglowAction>>,
+    ) -> AlpenglowResult<Vec<(AlpenglowAction, AlpenglowState)>> {
+        let (results, violations) = expansion.checked;
+        self.record_observations(&mut findings.observer_metrics, &violations);
+        Self::record_level_results(&mut findings.property_results, results, depth, &trace);
+        let violations: Vec<_> = violations.into_iter().map(|violation| PropertyViolation {
+            violation_step: depth,
+            ..violation
+        }).collect();
+        self.report_violations(&violations)?;
+        findings.violations_found.extend(violations);
+        findings.enabled_actions += expansion.enabled;
+        
+        let mut successors = Vec::new();
+        for (action, next, (results, violations)) in expansion.successors {
+            Self::record_level_results(&mut findings.property_results, results, depth + 1, || {
+                trace().map(|mut trace| {
+                    trace.push(action.clone());
+                    trace
+                })
+            });
+            let violations: Vec<_> = violations.into_iter().map(|violation| PropertyViolation {
+                violation_step: depth + 1,
+                ..violation
+            }).collect();
+            self.report_violations(&violations)?;
+            findings.violations_found.extend(violations);
+            successors.push((action, next));
+        }
+        Ok(successors)
+    }
+    
+    /// Merge the results of checking one state, found `depth` levels deep, into `property_results`;
+    /// `trace` gives the actions reaching the state, kept as counterexample of a first violation
+    fn record_level_results(
+        property_results: &mut HashMap<String, PropertyResult>,
+        results: HashMap<String, PropertyResult>,
+        depth: usize,
+        trace: impl Fn() -> Option<Vec<AlpenglowAction>>,
+    ) {
+        for (name, result) in results {
+            let violated = result.status == PropertyStatus::Violated;
+            let entry = property_results.entry(name).or_insert_with(|| PropertyResult {
+                status: PropertyStatus::Satisfied,
+                violation_count: 0,
+                first_violation_step: None,
+                counterexample: None,
+                ..result
+            });
+            if violated {
+                if entry.status != PropertyStatus::Violated {
+                    entry.status = PropertyStatus::Violated;
+                    entry.first_violation_step = Some(depth);
+                    entry.counterexample = trace();
+                }
+                entry.violation_count += 1;
+            }
+        }
+    }
+    
+    /// Actions leading from an initial state to the explored state with `fingerprint`
+    fn trace_to(parents: &HashMap<u64, (u64, AlpenglowAction)>, mut fingerprint: u64) -> Vec<AlpenglowAction> {
+        let mut trace = Vec::new();
+        while let Some((parent, action)) = parents.get(&fingerprint) {
+            trace.push(action.clone());
+            fingerprint = *parent;
+        }
+        trace.reverse();
+        trace
+    }
+    
+    /// Breadth-first search like `verify_with_progress` under `ExplorationStrategy::Bfs`, with
+    /// the states of each level checked and expanded by `workers` threads.
+    ///
+    /// Visited fingerprints are kept in a `DashSet` shared with the workers, which fingerprint
+    /// the successors they generate and drop those already visited. Worker results are merged
+    /// in frontier order, and the next level is the not yet visited successors in that order,
+    /// so the states explored, `property_results` and violations are the same as those of the
+    /// serial search. No progress lines are written.
+    #[cfg(feature = "parallel-verification")]
+    pub fn verify_model_parallel(&mut self, workers: usize) -> AlpenglowResult<VerificationResult> {
+        use dashmap::DashSet;
+        use rayon::prelude::*;
+        
+        let start_time = Instant::now();
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(workers.max(1))
+            .build()
+            .map_err(|e| AlpenglowError::InvalidConfig(format!("Cannot start {} verification workers: {}", workers, e)))?;
+        let model = AlpenglowModel::new(self.config.clone());
+        let mut findings = SearchFindings::default();
+        let mut collected_states = Vec::new();
+        let visited: DashSet<u64> = DashSet::new();
+        let mut parents: HashMap<u64, (u64, AlpenglowAction)> = HashMap::new();
+        let mut candidates: Vec<_> = model.init_states().into_iter()
+            .map(|state| {
+                let fingerprint = state.fingerprint();
+                (state, fingerprint, None)
+            })
+            .collect();
+        let mut explored = 0;
+        let mut depth = 0;
+        let mut peak_frontier = 0;
+        
+        while explored < self.max_states && depth <= self.exploration_depth {
+            // The level is the first unvisited candidates, as many as the state budget allows
+            let mut frontier = Vec::new();
+            for (state, fingerprint, parent) in candidates {
+                if explored + frontier.len() >= self.max_states {
+                    break;
+                }
+                if visited.insert(fingerprint) {
+                    parents.extend(parent.map(|parent| (fingerprint, parent)));
+                    frontier.push((state, fingerprint));
+                }
+            }
+            if frontier.is_empty() {
+                break;
+            }
+            peak_frontier = peak_frontier.max(frontier.len());
+            
+            // Check and expand the level in parallel; the visited set is only read until the merge
+            let checker = &*self;
+            let visited = &visited;
+            let expansions: Vec<_> = pool.install(|| frontier.par_iter().map(|(state, _)| {
+                let expansion = checker.expand(&model, state);
+                let fresh: Vec<Option<u64>> = expansion.successors.iter()
+                    .map(|(_, next, _)| Some(next.fingerprint()).filter(|fingerprint| !visited.contains(fingerprint)))
+                    .collect();
+                (expansion, fresh)
+            }).collect());
+            
+            // Merge in frontier order, exactly as the serial search records its results
+            let mut next_candidates = Vec::new();
+            for ((state, fingerprint), (expansion, fresh)) in frontier.into_iter().zip(expansions) {
+                let successors = self.record_expansion(&mut findings, expansion, depth, || Some(Self::trace_to(&parents, fingerprint)))?;
+                next_candidates.extend(successors.into_iter().zip(fresh).filter_map(|((action, next), fresh)| {
+                    fresh.map(|next_fingerprint| (next, next_fingerprint, Some((fingerprint, action))))
+                }));
+                explored += 1;
+                if self.state_collection_enabled {
+                    collected_states.push(StateInfo {
+                        state,
+                        state_type: "explored".to_string(),
+                        metadata: HashMap::new(),
+                    });
+                }
+            }
+            
+            if next_candidates.is_empty() || explored >= self.max_states {
+                break;
+            }
+            candidates = next_candidates;
+            depth += 1;
+        }
+        
+        let SearchFindings { property_results, violations_found, observer_metrics, enabled_actions } = findings;
+        let duration = start_time.elapsed();
+        Ok(VerificationResult {
+            property_results,
+            collected_states,
+            verification_time_ms: duration.as_millis() as u64,
+            total_states_explored: explored,
+            violations_found,
+            performance_metrics: PerformanceMetrics {
+                states_per_second: explored as f64 / duration.as_secs_f64(),
+                memory_usage_mb: self.config.estimate_memory_for_verification() as f64 / (1024.0 * 1024.0),
+                peak_queue_size: peak_frontier,
+                property_check_time_ms: HashMap::new(),
+            },
+            max_depth: depth,
+            avg_branching_factor: if explored == 0 { 0.0 } else { enabled_actions as f64 / explored as f64 },
+            warnings: self.config_warnings(),
+            observer_metrics,
+        })
+    }
+    
+    /// Check all safety properties
+    fn check_all_safety_properties(&self, state: &AlpenglowState) -> (HashMap<String, PropertyResult>, Vec<PropertyViolation>) {
+        let mut results = HashMap::new();
+        let mut violations = Vec::new();
+        
+        // Safety properties from property mapping
+        let properties = vec![
+            ("VotorSafety", properties::safety_no_conflicting_finalization_detailed(state, &self.config)),
+            ("ValidCertificates", properties::certificate_validity_detailed(state, &self.config)),
+            ("ByzantineResilience", properties::byzantine_resilience_detailed(state, &self.config)),
+            ("ByzantineStakeResilience", properties::byzantine_stake_resilience_detailed(state, &self.config)),
+            ("BandwidthSafety", properties::bandwidth_safety_detailed(state, &self.config)),
+            ("BandwidthResetsPerRound", properties::bandwidth_resets_per_round_detailed(state, &self.config)),
+            ("ValidErasureCode", properties::erasure_coding_validity_detailed(state, &self.config)),
+            ("ReconstructionCorrectness", properties::chain_consistency_detailed(state, &self.config)),
+            ("VoteTimestampMonotonic", properties::vote_timestamp_monotonic_detailed(state, &self.config)),
+            ("ReconstructionRequiresKDistinct", properties::reconstruction_requires_k_distinct_detailed(state, &self.config)),
+            ("RelayStakeWeighting", properties::relay_stake_weighting_detailed(state, &self.config)),
+            ("TotalStakeConserved", properties::total_stake_conserved_detailed(state, &self.config)),
+            ("NoPrematureViewAdvance", properties::no_premature_view_advance_detailed(state, &self.config)),
+            ("NoSkipAndCommitSameView", properties::no_skip_and_commit_same_view_detailed(state, &self.config)),
+            ("CertificateViewInRange", properties::certificate_view_in_range_detailed(state, &self.config)),
+            ("MessageAccountingConsistent", properties::message_accounting_consistent_detailed(state, &self.config)),
+            ("CertificateReferencesKnownBlock", properties::certificate_references_known_block_detailed(state, &self.config)),
+            ("ClockSkewBounded", properties::clock_skew_bounded_detailed(state, &self.config)),
+            ("RotorLeaderCorrect", properties::rotor_leader_correct_detailed(state, &self.config)),
+            ("StakeIntegrity", properties::stake_integrity_detailed(state, &self.config)),
+            ("ShredSignatureValid", properties::shred_signature_valid_detailed(state, &self.config)),
+            ("FinalizedTotalOrder", properties::finalized_total_order_detailed(state, &self.config)),
+            ("CertificateSignerVoteConsistency", properties::certificate_signer_vote_consistency_detailed(state, &self.config)),
+            ("SafetyNoDoubleVoting", properties::safety_no_double_voting_detailed(state, &self.config)),
+            ("SafetyValidCertificates", properties::safety_valid_certificates_detailed(state, &self.config)),
+        ];
+        
+        for (name, check_result) in properties {
+            let status = if check_result.passed {
+                PropertyStatus::Satisfied
+            } else {
+                PropertyStatus::Violated
+            };
+            
+            let property_result = PropertyResult {
+                property_name: name.to_string(),
+                status: status.clone(),
+                violation_count: if check_result.passed { 0 } else { 1 },
+                first_violation_step: if check_result.passed { None } else { Some(0) },
+                counterexample: None,
+            };
+            
+            results.insert(name.to_string(), property_result);
+            
+            if !check_result.passed {
+                violations.push(PropertyViolation {
+                    property_name: name.to_string(),
+                    violation_step: 0,
+                    state: state.clone(),
+                    action: AlpenglowAction::AdvanceClock, // Placeholder
+                    description: check_result.error.unwrap_or_else(|| "Property violation".to_string()),
+                });
+            }
+        }
+        
+        (results, violations)
+    }
+    
+    /// Check the invariants that relate a state to its successor under `action`
+    fn check_transition_properties(&self, previous: &AlpenglowState, action: &AlpenglowAction, next: &AlpenglowState) -> (HashMap<String, PropertyResult>, Vec<PropertyViolation>) {
+        let mut results = HashMap::new();
+        let mut violations = Vec::new();
+        
+        let properties = vec![
+            ("ViewMonotonicPerValidator", properties::view_monotonic_per_validator_detailed(previous, next, &self.config)),
+        ];
+        
+        for (name, check_result) in properties {
+            let property_result = PropertyResult {
+                property_name: name.to_string(),
+                status: if check_result.passed { PropertyStatus::Satisfied } else { PropertyStatus::Violated },
+                violation_count: if check_result.passed { 0 } else { 1 },
+                first_violation_step: if check_result.passed { None } else { Some(0) },
+                counterexample: None,
+            };
+            results.insert(name.to_string(), property_result);
+            
+            if !check_result.passed {
+                violations.push(PropertyViolation {
+                    property_name: name.to_string(),
+                    violation_step: 0,
+                    state: next.clone(),
+                    action: action.clone(),
+                    description: check_result.error.unwrap_or_else(|| "Property violation".to_string()),
+                });
+            }
+        }
+        
+        (results, violations)
+    }
+    
+    /// Check all liveness properties
+    fn check_all_liveness_properties(&self, state: &AlpenglowState) -> (HashMap<String, PropertyResult>, Vec<PropertyViolation>) {
+        let mut results = HashMap::new();
         let mut violations = Vec::new();
         
         let properties = vec![
             ("ProgressGuarantee", properties::progress_guarantee_detailed(state, &self.config)),
             ("ViewProgression", properties::view_progression_detailed(state, &self.config)),
             ("BlockDelivery", properties::block_delivery_detailed(state, &self.config)),
+            ("RotorDeliveryBeforeFinalization", properties::rotor_delivery_before_finalization_detailed(state, &self.config)),
+            ("LeaderCoverage", properties::leader_coverage_detailed(state, &self.config)),
+            ("RepairRequestsResolvable", properties::repair_requests_resolvable_detailed(state, &self.config)),
+            ("CertificateResolution", properties::certificate_resolution_detailed(state, &self.config)),
+            ("LeaderHasActiveTimeout", properties::leader_has_active_timeout_detailed(state, &self.config)),
+            ("FastPathLiveness", properties::fast_path_liveness_detailed(state, &self.config)),
+            ("SlowPathLiveness", properties::slow_path_liveness_detailed(state, &self.config)),
         ];
         
+        // Liveness that is impossible by construction is not applicable rather than violated
+        let model = AlpenglowModel::with_state(self.config.clone(), state.clone());
+        if model.liveness_feasible().is_err() {
+            for (name, _) in properties {
+                results.insert(name.to_string(), PropertyResult {
+                    property_name: name.to_string(),
+                    status: PropertyStatus::NotApplicable,
+                    violation_count: 0,
+                    first_violation_step: None,
+                    counterexample: None,
+                });
+            }
+            return (results, violations);
+        }
+        
         for (name, check_result) in properties {
             let status = if check_result.passed {
                 PropertyStatus::Satisfied
@@ -781,6 +2031,8 @@ impl RichModelChecker {
             ("DeltaBoundedDelivery", properties::delta_bounded_delivery_detailed(state, &self.config)),
             ("ThroughputOptimization", properties::throughput_optimization_detailed(state, &self.config)),
             ("CongestionControl", properties::congestion_control_detailed(state, &self.config)),
+            ("PrefersFastPath", properties::prefers_fast_path_detailed(state, &self.config)),
+            ("DropRateBounded", properties::drop_rate_bounded_detailed(state, &self.config)),
         ];
         
         for (name, check_result) in properties {
@@ -822,10 +2074,96 @@ pub struct AlpenglowModel {
     pub config: Config,
     /// Current state
     pub state: AlpenglowState,
+    /// Time source driving `AdvanceClock`; logical unless set with `with_clock_source`
+    #[serde(skip)]
+    clock_source: SharedClockSource,
+}
+
+/// Source of time for the model's `clock`
+pub trait ClockSource: Debug + Send + Sync {
+    /// Current time in `TimeValue` units
+    fn now_tv(&self) -> TimeValue;
+    
+    /// Clock value after an `AdvanceClock` from `current`; always at least one tick later
+    fn advance(&self, current: TimeValue) -> TimeValue {
+        (current + 1).max(self.now_tv())
+    }
+}
+
+/// Logical time: `AdvanceClock` moves the clock forward by exactly one tick
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogicalClock;
+
+impl ClockSource for LogicalClock {
+    /// Logical time has no source of its own; the model's clock is the time
+    fn now_tv(&self) -> TimeValue {
+        0
+    }
+    
+    fn advance(&self, current: TimeValue) -> TimeValue {
+        current + 1
+    }
+}
+
+/// Monotonic wall-clock time since creation, in units of `unit` per `TimeValue`
+#[derive(Debug, Clone, Copy)]
+pub struct WallClock {
+    start: Instant,
+    unit: Duration,
+}
+
+impl WallClock {
+    /// Wall clock counting milliseconds from now
+    pub fn new() -> Self {
+        Self::with_unit(Duration::from_millis(1))
+    }
+    
+    /// Wall clock counting `unit`s from now
+    pub fn with_unit(unit: Duration) -> Self {
+        Self { start: Instant::now(), unit }
+    }
+}
+
+impl Default for WallClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClockSource for WallClock {
+    fn now_tv(&self) -> TimeValue {
+        (self.start.elapsed().as_nanos() / self.unit.as_nanos().max(1)) as TimeValue
+    }
+}
+
+/// Shared handle to a model's `ClockSource`; `None` is the default `LogicalClock`.
+/// Handles are equal when both are logical or both share the same source.
+#[derive(Debug, Clone, Default)]
+pub struct SharedClockSource(Option<Arc<dyn ClockSource>>);
+
+impl SharedClockSource {
+    /// Clock value after an `AdvanceClock` from `current`
+    pub fn advance(&self, current: TimeValue) -> TimeValue {
+        match &self.0 {
+            Some(source) => source.advance(current),
+            None => LogicalClock.advance(current),
+        }
+    }
+}
+
+impl PartialEq for SharedClockSource {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (None, None) => true,
+            (Some(source), Some(other)) => Arc::ptr_eq(source, other),
+            _ => false,
+        }
+    }
 }
 
 /// Alpenglow state - mirrors TLA+ Alpenglow state variables exactly
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AlpenglowState {
     // Time and scheduling - mirrors TLA+ time variables
     pub clock: TimeValue,
@@ -839,15 +2177,28 @@ pub struct AlpenglowState {
     pub votor_finalized_chain: Vec<Block>,
     pub votor_skip_votes: BTreeMap<ValidatorId, BTreeMap<ViewNumber, BTreeSet<Vote>>>,
     pub votor_timeout_expiry: BTreeMap<ValidatorId, TimeValue>,
+    /// Drift of each validator's local clock from `clock`; timeouts are judged in local time
+    pub validator_clock_offset: BTreeMap<ValidatorId, i64>,
     pub votor_received_votes: BTreeMap<ValidatorId, BTreeMap<ViewNumber, BTreeSet<Vote>>>,
+    /// Every view advance taken by each validator, in order
+    pub votor_view_advances: BTreeMap<ValidatorId, Vec<ViewAdvance>>,
+    /// Received votes for each view when the validator last aggregated them under vote-count batching
+    pub votor_aggregated_votes: BTreeMap<ValidatorId, BTreeMap<ViewNumber, usize>>,
     
     // Rotor propagation state - mirrors TLA+ Rotor variables
     pub rotor_block_shreds: BTreeMap<BlockHash, BTreeMap<ValidatorId, BTreeSet<ErasureCodedPiece>>>,
+    /// Leader whose key signed each distributed block's shreds
+    pub rotor_shred_signers: BTreeMap<BlockHash, ValidatorId>,
+    /// Shred indices assigned to each relay for the most recently distributed block
     pub rotor_relay_assignments: BTreeMap<ValidatorId, Vec<u32>>,
     pub rotor_reconstruction_state: BTreeMap<ValidatorId, Vec<ReconstructionState>>,
     pub rotor_delivered_blocks: BTreeMap<ValidatorId, BTreeSet<BlockHash>>,
+    /// Clock time at which each validator first reconstructed each block
+    pub rotor_delivery_times: BTreeMap<ValidatorId, BTreeMap<BlockHash, TimeValue>>,
     pub rotor_repair_requests: BTreeSet<RepairRequest>,
     pub rotor_bandwidth_usage: BTreeMap<ValidatorId, u64>,
+    /// Slot in which the current `rotor_bandwidth_usage` accrued; usage resets on `AdvanceSlot`
+    pub rotor_bandwidth_slot: SlotNumber,
     pub rotor_shred_assignments: BTreeMap<ValidatorId, BTreeSet<u32>>,
     pub rotor_received_shreds: BTreeMap<ValidatorId, BTreeSet<ErasureCodedPiece>>,
     pub rotor_reconstructed_blocks: BTreeMap<ValidatorId, BTreeSet<Block>>,
@@ -857,22 +2208,97 @@ pub struct AlpenglowState {
     pub network_message_buffer: BTreeMap<ValidatorId, BTreeSet<NetworkMessage>>,
     pub network_partitions: BTreeSet<BTreeSet<ValidatorId>>,
     pub network_dropped_messages: u64,
+    /// Messages ever placed on `network_message_queue`
+    pub network_enqueued_messages: u64,
+    /// Messages taken off the queue and delivered to at least one recipient
+    pub network_delivered_messages: u64,
     pub network_delivery_time: BTreeMap<NetworkMessage, TimeValue>,
+    /// Earliest delivery time of queued messages the network has held back
+    pub network_earliest_delivery: BTreeMap<NetworkMessage, TimeValue>,
     
     // Additional state variables - mirrors TLA+ additional variables
     /// Finalized blocks by slot - consolidated field for tracking finalized blocks
     pub finalized_blocks: BTreeMap<SlotNumber, BTreeSet<Block>>,
+    /// Clock time at which each block was first finalized
+    pub finalization_times: BTreeMap<BlockHash, TimeValue>,
+    /// Certificate path (fast or slow) each block was first finalized through
+    pub finalization_paths: BTreeMap<BlockHash, CertificateType>,
     pub delivered_blocks: BTreeSet<Block>,
     pub messages: BTreeSet<NetworkMessage>,
     pub failure_states: BTreeMap<ValidatorId, ValidatorStatus>,
+    /// Strategies of validators turned Byzantine during a run
+    pub byzantine_strategies: BTreeMap<ValidatorId, ByzantineStrategy>,
     pub block_id: BlockHash,
     pub collected_pieces: BTreeSet<u32>,
     pub complete: bool,
 }
 
+/// Validation performed by an observer over the states the model checker explored
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ObserverMetrics {
+    /// Number of states the observer ran the safety checks on
+    pub states_validated: u64,
+    /// Number of validated states violating each safety property
+    pub violations: BTreeMap<String, u64>,
+}
+
+/// What a validator is doing in its current view
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum ValidatorPhase {
+    /// Leader of the view that has not yet voted on its own proposal
+    Proposing,
+    /// Waiting to cast its vote for the view
+    Voting,
+    /// Voted, waiting for enough votes to form a certificate
+    AwaitingVotes,
+    /// Timeout expired without a certificate for the view
+    TimedOut,
+    /// A certificate for the view exists
+    Finalizing,
+}
+
+/// One value that differs between two states
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Dotted path to the value, starting with the state field name, e.g. `votor_view.2`
+    pub path: String,
+    /// Value in the first state as JSON, or `None` if absent there
+    pub old: Option<String>,
+    /// Value in the second state as JSON, or `None` if absent there
+    pub new: Option<String>,
+}
+
+impl std::fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            self.old.as_deref().unwrap_or("<absent>"),
+            self.new.as_deref().unwrap_or("<absent>")
+        )
+    }
+}
+
+/// Record of a validator moving past a view
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ViewAdvance {
+    pub from_view: ViewNumber,
+    /// Validator-local clock value when the view was left
+    pub clock: TimeValue,
+    /// Timeout expiry in effect when the view was left
+    pub timeout_expiry: TimeValue,
+    /// Whether a skip certificate justified leaving the view
+    pub skip_certified: bool,
+}
+
 /// Minimal placeholder for reconstruction state used in rotor module.
 /// Kept simple to satisfy type usage in this file.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ReconstructionState {
     pub block_id: BlockHash,
     pub pieces_collected: usize,
@@ -882,7 +2308,18 @@ impl AlpenglowModel {
     /// Create a new Alpenglow model with the given configuration
     pub fn new(config: Config) -> Self {
         let state = AlpenglowState::init(&config);
-        Self { config, state }
+        Self::with_state(config, state)
+    }
+    
+    /// Create a model at `state`, driven by the logical clock
+    pub fn with_state(config: Config, state: AlpenglowState) -> Self {
+        Self { config, state, clock_source: SharedClockSource::default() }
+    }
+    
+    /// Drive `AdvanceClock` from `source`, e.g. a `WallClock` for real-time simulation
+    pub fn with_clock_source(mut self, source: impl ClockSource + 'static) -> Self {
+        self.clock_source = SharedClockSource(Some(Arc::new(source)));
+        self
     }
     
     /// Get the current state
@@ -895,10 +2332,72 @@ impl AlpenglowModel {
         &self.config
     }
     
+    /// Stake of validators that are neither offline nor Byzantine
+    pub fn online_honest_stake(&self) -> StakeAmount {
+        self.config.stake_distribution.iter()
+            .filter(|(validator, _)| {
+                self.state.failure_states.get(validator).cloned().unwrap_or(ValidatorStatus::Honest) == ValidatorStatus::Honest
+            })
+            .map(|(_, stake)| *stake)
+            .sum()
+    }
+    
+    /// Check that liveness is achievable: online honest stake reaches the slow-path threshold
+    /// and Byzantine stake stays below one third. Returns why liveness is impossible otherwise.
+    pub fn liveness_feasible(&self) -> Result<(), String> {
+        let stake_with_status = |wanted: ValidatorStatus| -> StakeAmount {
+            self.config.stake_distribution.iter()
+                .filter(|(validator, _)| {
+                    self.state.failure_states.get(validator).cloned().unwrap_or(ValidatorStatus::Honest) == wanted
+                })
+                .map(|(_, stake)| *stake)
+                .sum()
+        };
+        let honest_stake = stake_with_status(ValidatorStatus::Honest);
+        let byzantine_stake = stake_with_status(ValidatorStatus::Byzantine);
+        let offline_stake = stake_with_status(ValidatorStatus::Offline);
+        let total_stake = self.config.total_stake;
+        
+        let mut reasons = Vec::new();
+        if honest_stake < self.config.slow_path_threshold {
+            reasons.push(format!(
+                "online honest stake {} of {} is below the slow-path threshold {} ({} offline, {} Byzantine)",
+                honest_stake, total_stake, self.config.slow_path_threshold, offline_stake, byzantine_stake
+            ));
+        }
+        if byzantine_stake * 3 >= total_stake {
+            reasons.push(format!(
+                "Byzantine stake {} is not below one third of total stake {}",
+                byzantine_stake, total_stake
+            ));
+        }
+        
+        if reasons.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Liveness impossible by construction: {}", reasons.join("; ")))
+        }
+    }
+    
+    /// Mark `validator` Byzantine from now on, preferring the actions of `strategy`
+    pub fn set_byzantine(&mut self, validator: ValidatorId, strategy: ByzantineStrategy) -> AlpenglowResult<()> {
+        if validator >= self.config.validator_count as ValidatorId {
+            return Err(AlpenglowError::InvalidConfig(format!("Unknown validator {}", validator)));
+        }
+        
+        self.state.failure_states.insert(validator, ValidatorStatus::Byzantine);
+        self.state.byzantine_strategies.insert(validator, strategy);
+        Ok(())
+    }
+    
     /// Check if an action is enabled in the current state
     pub fn action_enabled(&self, action: &AlpenglowAction) -> bool {
         match action {
-            AlpenglowAction::AdvanceClock => true,
+            AlpenglowAction::AdvanceClock => {
+                // After GST the clock cannot pass a queued message's delivery deadline
+                self.state.network_message_queue.iter()
+                    .all(|message| self.state.clock < self.delivery_deadline(message))
+            },
             AlpenglowAction::AdvanceSlot => {
                 // Can advance slot if current slot has finalized blocks
                 self.state.finalized_blocks.get(&self.state.current_slot)
@@ -909,7 +2408,10 @@ impl AlpenglowModel {
                 // Can advance view if timeout expired
                 let current_view = self.state.votor_view.get(validator).copied().unwrap_or(1);
                 let timeout_expiry = self.state.votor_timeout_expiry.get(validator).copied().unwrap_or(0);
-                current_view < self.config.max_view && self.state.clock >= timeout_expiry
+                current_view < self.config.max_view && self.state.local_clock(*validator) >= timeout_expiry
+            },
+            AlpenglowAction::SkewClock { validator, .. } => {
+                *validator < self.config.validator_count as ValidatorId
             },
             AlpenglowAction::Votor(votor_action) => self.votor_action_enabled(votor_action),
             AlpenglowAction::Rotor(rotor_action) => self.rotor_action_enabled(rotor_action),
@@ -930,19 +2432,29 @@ impl AlpenglowModel {
         
         match action {
             AlpenglowAction::AdvanceClock => {
-                new_state.clock += 1;
+                new_state.clock = self.clock_source.advance(new_state.clock);
             },
             AlpenglowAction::AdvanceSlot => {
                 new_state.current_slot += 1;
+                
+                // Bandwidth limits are per round, so usage starts over in the new slot
+                for usage in new_state.rotor_bandwidth_usage.values_mut() {
+                    *usage = 0;
+                }
+                new_state.rotor_bandwidth_slot = new_state.current_slot;
             },
             AlpenglowAction::AdvanceView { validator } => {
                 let current_view = new_state.votor_view.get(&validator).copied().unwrap_or(1);
+                new_state.record_view_advance(validator, false);
                 new_state.votor_view.insert(validator, current_view + 1);
                 
                 // Update timeout expiry with exponential backoff using safe calculation
-                let new_timeout = self.calculate_timeout(new_state.clock, current_view);
+                let new_timeout = self.calculate_timeout(new_state.local_clock(validator), current_view);
                 new_state.votor_timeout_expiry.insert(validator, new_timeout);
             },
+            AlpenglowAction::SkewClock { validator, offset } => {
+                new_state.validator_clock_offset.insert(validator, offset);
+            },
             AlpenglowAction::Votor(votor_action) => {
                 self.execute_votor_action(&mut new_state, votor_action)?;
             },
@@ -957,6 +2469,9 @@ impl AlpenglowModel {
             },
         }
         
+        // The relay leader follows slot and view advancement
+        new_state.current_rotor = utils::leader_for_view(&self.config, new_state.current_view());
+        
         Ok(new_state)
     }
     
@@ -965,15 +2480,16 @@ impl AlpenglowModel {
         match action {
             VotorAction::ProposeBlock { validator, view } => {
                 let current_view = self.state.votor_view.get(validator).copied().unwrap_or(1);
-                *view == current_view && self.is_leader_for_view(*validator, *view)
+                *view == current_view && self.is_leader_for_view(*validator, *view) &&
+                !self.config.observers.contains(validator)
             },
             VotorAction::CastVote { validator, view, .. } => {
                 let current_view = self.state.votor_view.get(validator).copied().unwrap_or(1);
-                *view == current_view
+                *view == current_view && !self.config.observers.contains(validator)
             },
             VotorAction::CollectVotes { validator, view } => {
                 let current_view = self.state.votor_view.get(validator).copied().unwrap_or(1);
-                *view == current_view
+                *view == current_view && self.at_aggregation_boundary(*validator, *view)
             },
             VotorAction::FinalizeBlock { validator: _, certificate } => {
                 let current_view = self.state.votor_view.get(&0).copied().unwrap_or(1);
@@ -983,15 +2499,19 @@ impl AlpenglowModel {
             VotorAction::SubmitSkipVote { validator, view } => {
                 let current_view = self.state.votor_view.get(validator).copied().unwrap_or(1);
                 let timeout_expiry = self.state.votor_timeout_expiry.get(validator).copied().unwrap_or(0);
-                *view == current_view && self.state.clock >= timeout_expiry
+                *view == current_view && current_view < self.config.max_view &&
+                self.state.local_clock(*validator) >= timeout_expiry &&
+                !self.config.observers.contains(validator)
             },
             VotorAction::CollectSkipVotes { validator, view } => {
+                // Collecting skip votes can advance the view, so it is bounded like AdvanceView
                 let current_view = self.state.votor_view.get(validator).copied().unwrap_or(1);
-                *view == current_view
+                *view == current_view && current_view < self.config.max_view
             },
             VotorAction::Timeout { validator } => {
+                let current_view = self.state.votor_view.get(validator).copied().unwrap_or(1);
                 let timeout_expiry = self.state.votor_timeout_expiry.get(validator).copied().unwrap_or(0);
-                self.state.clock >= timeout_expiry
+                current_view < self.config.max_view && self.state.local_clock(*validator) >= timeout_expiry
             },
         }
     }
@@ -1003,9 +2523,11 @@ impl AlpenglowModel {
                 *leader == block.proposer && !self.state.rotor_block_shreds.contains_key(&block.hash)
             },
             RotorAction::RelayShreds { validator, block_id } => {
+                // A relay that would exceed the round's bandwidth waits for the next slot
                 self.state.rotor_block_shreds.get(block_id)
                     .and_then(|shreds| shreds.get(validator))
-                    .map_or(false, |validator_shreds| !validator_shreds.is_empty())
+                    .map_or(false, |validator_shreds| !validator_shreds.is_empty()) &&
+                self.relay_cost(&self.state, *validator, *block_id) <= self.remaining_bandwidth(&self.state, *validator)
             },
             RotorAction::AttemptReconstruction { validator, block_id } => {
                 self.can_reconstruct(*validator, *block_id) &&
@@ -1031,10 +2553,17 @@ impl AlpenglowModel {
         match action {
             NetworkAction::DeliverMessage { message } => {
                 self.state.network_message_queue.contains(message)
+                    && self.state.clock >= self.earliest_delivery(&self.state, message)
             },
             NetworkAction::DropMessage { message } => {
                 self.state.network_message_queue.contains(message)
             },
+            NetworkAction::DelayMessage { message, delay } => {
+                // Only a delay that holds the message back further changes anything
+                self.state.network_message_queue.contains(message)
+                    && PartialSynchronyModel::schedule_delivery(&self.config, message.timestamp, *delay)
+                        > self.earliest_delivery(&self.state, message)
+            },
             NetworkAction::PartitionNetwork { .. } => true,
             NetworkAction::HealPartition => !self.state.network_partitions.is_empty(),
         }
@@ -1055,6 +2584,9 @@ impl AlpenglowModel {
             ByzantineAction::Equivocate { validator } => {
                 matches!(self.state.failure_states.get(validator), Some(ValidatorStatus::Byzantine))
             },
+            ByzantineAction::DelayMessages { validator, .. } => {
+                matches!(self.state.failure_states.get(validator), Some(ValidatorStatus::Byzantine))
+            },
         }
     }
     
@@ -1082,71 +2614,66 @@ impl AlpenglowModel {
                     .insert(new_block);
             },
             VotorAction::CastVote { validator, block, view } => {
-                let vote = Vote {
-                    voter: validator,
-                    slot: block.slot,
-                    view,
-                    block: block.hash,
-                    vote_type: VoteType::Commit,
-                    signature: validator as u64, // Simplified signature
-                    timestamp: state.clock,
-                };
-                
-                // Store vote under all validators (recipients) for collection
-                for recipient in 0..self.config.validator_count {
-                    let recipient_id = recipient as ValidatorId;
-                    state.votor_received_votes
-                        .entry(recipient_id)
-                        .or_default()
-                        .entry(view)
-                        .or_default()
-                        .insert(vote.clone());
-                }
-                    
-                state.votor_voted_blocks
-                    .entry(validator)
-                    .or_default()
-                    .entry(view)
-                    .or_default()
-                    .insert(block);
+                self.cast_vote(state, validator, block, view)?;
             },
             VotorAction::CollectVotes { validator, view } => {
                 if let Some(votes) = state.votor_received_votes.get(&validator).and_then(|v| v.get(&view)) {
-                    let voted_stake: StakeAmount = votes.iter()
-                        .map(|vote| self.config.stake_distribution.get(&vote.voter).copied().unwrap_or(0))
-                        .sum();
+                    // Only votes for the same block count towards its certificate
+                    let mut votes_by_block: BTreeMap<BlockHash, Vec<&Vote>> = BTreeMap::new();
+                    for vote in votes {
+                        votes_by_block.entry(vote.block).or_default().push(vote);
+                    }
                     
-                    if voted_stake >= self.config.slow_path_threshold && !votes.is_empty() {
-                        let first_vote = votes.iter().next().unwrap();
-                        let cert_type = if voted_stake >= self.config.fast_path_threshold {
-                            CertificateType::Fast
-                        } else {
-                            CertificateType::Slow
-                        };
+                    let mut certificates = Vec::new();
+                    for (block, votes) in votes_by_block {
+                        let voted_stake: StakeAmount = votes.iter()
+                            .map(|vote| self.config.stake_distribution.get(&vote.voter).copied().unwrap_or(0))
+                            .sum();
                         
-                        let certificate = Certificate {
-                            slot: first_vote.slot,
-                            view,
-                            block: first_vote.block,
-                            cert_type,
-                            validators: votes.iter().map(|v| v.voter).collect(),
-                            stake: voted_stake,
-                            signatures: AggregatedSignature {
-                                signers: votes.iter().map(|v| v.voter).collect(),
-                                message: first_vote.block,
-                                signatures: votes.iter().map(|v| v.signature).collect(),
-                                valid: true,
-                            },
-                        };
-                        
-                        state.votor_generated_certs
-                            .entry(view)
-                            .or_default()
-                            .insert(certificate);
+                        if voted_stake >= self.config.slow_path_threshold {
+                            let cert_type = if voted_stake >= self.config.fast_path_threshold {
+                                CertificateType::Fast
+                            } else {
+                                CertificateType::Slow
+                            };
+                            
+                            certificates.push(Certificate {
+                                slot: votes[0].slot,
+                                view,
+                                block,
+                                cert_type,
+                                validators: votes.iter().map(|v| v.voter).collect(),
+                                stake: voted_stake,
+                                signatures: AggregatedSignature {
+                                    signers: votes.iter().map(|v| v.voter).collect(),
+                                    message: block,
+                                    signatures: votes.iter().map(|v| v.signature).collect(),
+                                    valid: true,
+                                },
+                            });
+                        }
+                    }
+                    
+                    state.votor_generated_certs
+                        .entry(view)
+                        .or_default()
+                        .extend(certificates);
+                    
+                    // The next vote-count batch starts from the votes aggregated now
+                    if matches!(self.config.aggregation_batching, Some(AggregationBatching::VoteCount(_))) {
+                        let received = votes.len();
+                        state.votor_aggregated_votes.entry(validator).or_default().insert(view, received);
                     }
                 }
             },
             VotorAction::FinalizeBlock { validator: _, certificate } => {
+                // Prefer a fast certificate for the same block, slot and view when one was generated
+                let certificate = if self.config.prefer_fast_path {
+                    properties::matching_fast_certificate(state, &certificate).cloned().unwrap_or(certificate)
+                } else {
+                    certificate
+                };
+                
                 // Find the block to finalize
                 if let Some(block) = state.votor_voted_blocks.values()
                     .flat_map(|view_blocks| view_blocks.values())
@@ -1158,6 +2685,8 @@ impl AlpenglowModel {
                         .entry(certificate.slot)
                         .or_default()
                         .insert(block.clone());
+                    state.finalization_times.entry(block.hash).or_insert(state.clock);
+                    state.finalization_paths.entry(block.hash).or_insert(certificate.cert_type);
                 }
             },
             VotorAction::SubmitSkipVote { validator, view } => {
@@ -1179,8 +2708,9 @@ impl AlpenglowModel {
                     .insert(skip_vote);
                     
                 // Advance view
+                state.record_view_advance(validator, false);
                 state.votor_view.insert(validator, view + 1);
-                let new_timeout = self.calculate_timeout(state.clock, view);
+                let new_timeout = self.calculate_timeout(state.local_clock(validator), view);
                 state.votor_timeout_expiry.insert(validator, new_timeout);
             },
             VotorAction::CollectSkipVotes { validator, view } => {
@@ -1189,17 +2719,38 @@ impl AlpenglowModel {
                         .map(|vote| self.config.stake_distribution.get(&vote.voter).copied().unwrap_or(0))
                         .sum();
                     
+                    // Aggregate the skip votes into a certificate, as CollectVotes does for commit votes
+                    if skip_stake >= self.config.slow_path_threshold {
+                        let certificate = Certificate {
+                            slot: view,
+                            view,
+                            block: 0, // No block for skip
+                            cert_type: CertificateType::Skip,
+                            validators: skip_votes.iter().map(|v| v.voter).collect(),
+                            stake: skip_stake,
+                            signatures: AggregatedSignature {
+                                signers: skip_votes.iter().map(|v| v.voter).collect(),
+                                message: 0,
+                                signatures: skip_votes.iter().map(|v| v.signature).collect(),
+                                valid: true,
+                            },
+                        };
+                        state.votor_generated_certs.entry(view).or_default().insert(certificate);
+                    }
+                    
                     if skip_stake >= (2 * self.config.total_stake) / 3 {
+                        state.record_view_advance(validator, true);
                         state.votor_view.insert(validator, view + 1);
-                        let new_timeout = self.calculate_timeout(state.clock, view);
+                        let new_timeout = self.calculate_timeout(state.local_clock(validator), view);
                         state.votor_timeout_expiry.insert(validator, new_timeout);
                     }
                 }
             },
             VotorAction::Timeout { validator } => {
                 let current_view = state.votor_view.get(&validator).copied().unwrap_or(1);
+                state.record_view_advance(validator, false);
                 state.votor_view.insert(validator, current_view + 1);
-                let new_timeout = self.calculate_timeout(state.clock, current_view);
+                let new_timeout = self.calculate_timeout(state.local_clock(validator), current_view);
                 state.votor_timeout_expiry.insert(validator, new_timeout);
             },
         }
@@ -1209,8 +2760,9 @@ impl AlpenglowModel {
     /// Execute a Rotor action
     fn execute_rotor_action(&self, state: &mut AlpenglowState, action: RotorAction) -> AlpenglowResult<()> {
         match action {
-            RotorAction::ShredAndDistribute { leader: _, block } => {
+            RotorAction::ShredAndDistribute { leader, block } => {
                 let shreds = self.erasure_encode(&block);
+                state.rotor_shred_signers.insert(block.hash, leader);
                 let assignments = self.assign_pieces_to_relays(&shreds);
                 
                 let mut block_shreds = HashMap::new();
@@ -1229,30 +2781,49 @@ impl AlpenglowModel {
                     .map(|(k, v)| (k, v.into_iter().collect()))
                     .collect();
                 state.rotor_block_shreds.insert(block.hash, btree_shreds);
+                
+                // The leader pays for all N shreds, not just the K-worth of block data
+                *state.rotor_bandwidth_usage.entry(leader).or_default() += utils::shred_bytes(&shreds);
                 state.rotor_relay_assignments = assignments;
             },
             RotorAction::RelayShreds { validator, block_id } => {
-                if let Some(block_shreds) = state.rotor_block_shreds.get_mut(&block_id) {
-                    if let Some(my_shreds) = block_shreds.get(&validator).cloned() {
-                        // Relay to other validators
-                        for other_validator in 0..self.config.validator_count {
-                            let other_id = other_validator as ValidatorId;
-                            if other_id != validator {
-                                block_shreds.entry(other_id).or_default().extend(my_shreds.iter().cloned());
-                            }
+                // Forged shreds are dropped rather than relayed
+                let my_shreds: Option<Vec<_>> = state.rotor_block_shreds.get(&block_id)
+                    .and_then(|bs| bs.get(&validator))
+                    .map(|held| held.iter()
+                        .filter(|piece| state.shred_authentic(&self.config, piece))
+                        .cloned()
+                        .collect());
+                let cost = self.relay_cost(state, validator, block_id);
+                if let (Some(my_shreds), Some(block_shreds)) = (my_shreds, state.rotor_block_shreds.get_mut(&block_id)) {
+                    // Relay to other validators
+                    for other_validator in 0..self.config.validator_count {
+                        let other_id = other_validator as ValidatorId;
+                        if other_id != validator {
+                            block_shreds.entry(other_id).or_default().extend(my_shreds.iter().cloned());
                         }
                     }
+                    *state.rotor_bandwidth_usage.entry(validator).or_default() += cost;
                 }
             },
             RotorAction::AttemptReconstruction { validator, block_id } => {
-                if let Some(pieces) = state.rotor_block_shreds.get(&block_id).and_then(|bs| bs.get(&validator)) {
-                    if pieces.len() >= self.config.k as usize {
-                        match self.reconstruct_block(pieces) {
+                if let Some(held) = state.rotor_block_shreds.get(&block_id).and_then(|bs| bs.get(&validator)) {
+                    let pieces: BTreeSet<_> = held.iter()
+                        .filter(|piece| state.shred_authentic(&self.config, piece))
+                        .cloned()
+                        .collect();
+                    if properties::distinct_valid_shred_count(&pieces, block_id, &self.config) >= self.config.k as usize {
+                        match self.reconstruct_block(&pieces) {
                             Ok(reconstructed_block) => {
                                 state.rotor_delivered_blocks
                                     .entry(validator)
                                     .or_default()
                                     .insert(block_id);
+                                state.rotor_delivery_times
+                                    .entry(validator)
+                                    .or_default()
+                                    .entry(block_id)
+                                    .or_insert(state.clock);
                                 state.rotor_reconstructed_blocks
                                     .entry(validator)
                                     .or_default()
@@ -1286,10 +2857,12 @@ impl AlpenglowModel {
                 if let Some(my_pieces) = state.rotor_block_shreds.get(&request.block_id).and_then(|bs| bs.get(&validator)) {
                     let requested_pieces: BTreeSet<_> = my_pieces.iter()
                         .filter(|p| request.missing_indices.contains(&p.index))
+                        .filter(|p| state.shred_authentic(&self.config, p))
                         .cloned()
                         .collect();
                     
                     if !requested_pieces.is_empty() {
+                        *state.rotor_bandwidth_usage.entry(validator).or_default() += utils::shred_bytes(&requested_pieces);
                         state.rotor_block_shreds
                             .entry(request.block_id)
                             .or_default()
@@ -1309,9 +2882,12 @@ impl AlpenglowModel {
         match action {
             NetworkAction::DeliverMessage { message } => {
                 state.network_message_queue.remove(&message);
+                state.network_earliest_delivery.remove(&message);
                 
                 // Check network partitions before delivering
                 let sender_partition = self.find_validator_partition(state, message.sender);
+                let mut delivered = false;
+                let mut recipients = Vec::new();
                 
                 match message.recipient {
                     MessageRecipient::Validator(validator_id) => {
@@ -1321,7 +2897,9 @@ impl AlpenglowModel {
                             state.network_message_buffer
                                 .entry(validator_id)
                                 .or_default()
-                                .insert(message);
+                                .insert(message.clone());
+                            recipients.push(validator_id);
+                            delivered = true;
                         }
                     },
                     MessageRecipient::Broadcast => {
@@ -1334,15 +2912,44 @@ impl AlpenglowModel {
                                     .entry(validator_id)
                                     .or_default()
                                     .insert(message.clone());
+                                recipients.push(validator_id);
+                                delivered = true;
                             }
                         }
                     },
                 }
+                
+                // Gossiped votes become visible to vote collection on arrival
+                if message.msg_type == MessageType::Vote {
+                    if let Ok(vote) = bincode::deserialize::<Vote>(&message.payload) {
+                        for recipient in &recipients {
+                            state.votor_received_votes
+                                .entry(*recipient)
+                                .or_default()
+                                .entry(vote.view)
+                                .or_default()
+                                .insert(vote.clone());
+                        }
+                    }
+                }
+                
+                // A message no recipient could receive across the partition is lost
+                if delivered {
+                    state.network_delivery_time.insert(message.clone(), state.clock.saturating_sub(message.timestamp));
+                    state.network_delivered_messages += 1;
+                } else {
+                    state.network_dropped_messages += 1;
+                }
             },
             NetworkAction::DropMessage { message } => {
                 state.network_message_queue.remove(&message);
+                state.network_earliest_delivery.remove(&message);
                 state.network_dropped_messages += 1;
             },
+            NetworkAction::DelayMessage { message, delay } => {
+                let earliest = PartialSynchronyModel::schedule_delivery(&self.config, message.timestamp, delay);
+                state.network_earliest_delivery.insert(message, earliest);
+            },
             NetworkAction::PartitionNetwork { partition } => {
                 state.network_partitions.insert(partition);
             },
@@ -1353,33 +2960,73 @@ impl AlpenglowModel {
         Ok(())
     }
     
+    /// Cast `validator`'s commit vote for `block` in `view`, delivering it to every validator
+    /// or gossiping it when `vote_gossip_delay` is set
+    fn cast_vote(&self, state: &mut AlpenglowState, validator: ValidatorId, block: Block, view: ViewNumber) -> AlpenglowResult<()> {
+        let vote = Vote {
+            voter: validator,
+            slot: block.slot,
+            view,
+            block: block.hash,
+            vote_type: VoteType::Commit,
+            signature: validator as u64, // Simplified signature
+            timestamp: state.clock,
+        };
+        
+        if self.config.vote_gossip_delay > 0 {
+            // Gossip the vote; recipients record it once the message is delivered
+            let message = NetworkMessage {
+                id: state.network_enqueued_messages + 1,
+                msg_type: MessageType::Vote,
+                sender: validator,
+                recipient: MessageRecipient::Broadcast,
+                payload: bincode::serialize(&vote)
+                    .map_err(|e| AlpenglowError::SerializationError(e.to_string()))?,
+                timestamp: state.clock,
+                signature: vote.signature,
+            };
+            state.enqueue_message(message);
+        } else {
+            // Store vote under all validators (recipients) for collection
+            for recipient in 0..self.config.validator_count {
+                let recipient_id = recipient as ValidatorId;
+                state.votor_received_votes
+                    .entry(recipient_id)
+                    .or_default()
+                    .entry(view)
+                    .or_default()
+                    .insert(vote.clone());
+            }
+        }
+        
+        state.votor_voted_blocks
+            .entry(validator)
+            .or_default()
+            .entry(view)
+            .or_default()
+            .insert(block);
+        Ok(())
+    }
+    
     /// Execute a Byzantine action
     fn execute_byzantine_action(&self, state: &mut AlpenglowState, action: ByzantineAction) -> AlpenglowResult<()> {
         match action {
             ByzantineAction::DoubleVote { validator, view } => {
-                // Create two conflicting votes
-                let _vote1 = Vote {
-                    voter: validator,
-                    slot: view,
-                    view,
-                    block: 1,
-                    vote_type: VoteType::Commit,
-                    signature: validator as u64,
-                    timestamp: state.clock,
-                };
-                let _vote2 = Vote {
-                    voter: validator,
-                    slot: view,
-                    view,
-                    block: 2,
-                    vote_type: VoteType::Commit,
-                    signature: validator as u64,
-                    timestamp: state.clock,
-                };
-                
-                // Deliver to all validators
-                for _other_validator in 0..self.config.validator_count {
-                    // Process double vote delivery (placeholder)
+                // Cast two conflicting commit votes the way an honest vote is cast
+                let parent = state.votor_finalized_chain.last().map_or(0, |b| b.hash);
+                for hash in [1, 2] {
+                    let block = Block {
+                        slot: view,
+                        view,
+                        hash,
+                        parent,
+                        proposer: validator,
+                        transactions: BTreeSet::new(),
+                        timestamp: state.clock,
+                        signature: validator as u64,
+                        data: vec![],
+                    };
+                    self.cast_vote(state, validator, block, view)?;
                 }
             },
             ByzantineAction::InvalidBlock { validator } => {
@@ -1427,8 +3074,20 @@ impl AlpenglowModel {
                     signature: validator as u64,
                 };
                 
-                state.network_message_queue.insert(msg1);
-                state.network_message_queue.insert(msg2);
+                state.enqueue_message(msg1);
+                state.enqueue_message(msg2);
+            },
+            ByzantineAction::DelayMessages { validator, delay } => {
+                // The network still delivers within the partial synchrony bounds
+                let outbound: Vec<NetworkMessage> = state.network_message_queue.iter()
+                    .filter(|message| message.sender == validator)
+                    .cloned()
+                    .collect();
+                for message in outbound {
+                    let held = self.earliest_delivery(state, &message).saturating_sub(message.timestamp);
+                    let earliest = PartialSynchronyModel::schedule_delivery(&self.config, message.timestamp, held.saturating_add(delay));
+                    state.network_earliest_delivery.insert(message, earliest);
+                }
             },
         }
         Ok(())
@@ -1436,48 +3095,113 @@ impl AlpenglowModel {
     
     /// Check if validator is leader for view (stake-weighted selection)
     fn is_leader_for_view(&self, validator: ValidatorId, view: ViewNumber) -> bool {
-        self.compute_leader_for_view(view) == validator
+        self.compute_leader_window(view).0 == validator
+    }
+    
+    /// Whether `validator` may aggregate its votes for `view` now under `aggregation_batching`
+    fn at_aggregation_boundary(&self, validator: ValidatorId, view: ViewNumber) -> bool {
+        match self.config.aggregation_batching {
+            None => true,
+            Some(AggregationBatching::Interval(interval)) => self.state.local_clock(validator).is_multiple_of(interval.max(1)),
+            Some(AggregationBatching::VoteCount(count)) => {
+                let received = self.state.votor_received_votes.get(&validator)
+                    .and_then(|views| views.get(&view))
+                    .map_or(0, |votes| votes.len());
+                let aggregated = self.state.votor_aggregated_votes.get(&validator)
+                    .and_then(|views| views.get(&view))
+                    .copied()
+                    .unwrap_or(0);
+                received >= aggregated + count.max(1)
+            }
+        }
     }
     
     /// Compute leader for view using stake-weighted selection with deterministic hash
     pub fn compute_leader_for_view(&self, view: ViewNumber) -> ValidatorId {
-        let total_stake = self.config.total_stake;
-        if total_stake == 0 {
-            return 0;
-        }
-        
-        // Use deterministic hash of the view number
-        let mut hasher = DefaultHasher::new();
-        view.hash(&mut hasher);
-        let hash_value = hasher.finish();
-        let target = hash_value % total_stake;
-        
-        let mut cumulative_stake = 0;
-        
-        for validator in 0..self.config.validator_count {
-            let validator_id = validator as ValidatorId;
-            let stake = self.config.stake_distribution.get(&validator_id).copied().unwrap_or(0);
-            cumulative_stake += stake;
-            if cumulative_stake > target {
-                return validator_id;
+        utils::leader_for_view(&self.config, view)
+    }
+    
+    /// Leader of `view` and the VRF proof its selection can be verified with through
+    /// `utils::verify_leader_proof`
+    pub fn compute_leader_with_proof(&self, view: ViewNumber) -> (ValidatorId, VRFProof) {
+        utils::leader_with_proof(&self.config, view)
+    }
+    
+    /// Leader of the `leader_window_size` consecutive views containing `view`, and those views
+    pub fn compute_leader_window(&self, view: ViewNumber) -> (ValidatorId, std::ops::Range<ViewNumber>) {
+        utils::leader_window(&self.config, view)
+    }
+    
+    /// Time by which `message` must leave the queue: `delta` after it was sent, or after GST
+    /// for messages sent before the network stabilized
+    pub fn delivery_deadline(&self, message: &NetworkMessage) -> TimeValue {
+        PartialSynchronyModel::delivery_deadline(&self.config, message.timestamp)
+    }
+    
+    /// Time from which `message` may be delivered: as scheduled by a `DelayMessage`, otherwise
+    /// once it was sent, or after the gossip delay for votes
+    pub fn earliest_delivery(&self, state: &AlpenglowState, message: &NetworkMessage) -> TimeValue {
+        state.network_earliest_delivery.get(message).copied().unwrap_or_else(|| {
+            if message.msg_type == MessageType::Vote {
+                message.timestamp.saturating_add(self.config.vote_gossip_delay)
+            } else {
+                message.timestamp
             }
-        }
-        
-        0 // Fallback
+        })
+    }
+    
+    /// Bandwidth `validator` may still use in the current round under `bandwidth_limit`
+    pub fn remaining_bandwidth(&self, state: &AlpenglowState, validator: ValidatorId) -> u64 {
+        let used = state.rotor_bandwidth_usage.get(&validator).copied().unwrap_or(0);
+        self.config.bandwidth_limit.saturating_sub(used)
+    }
+    
+    /// Bytes `validator` sends relaying its authentic shreds of `block_id` to every other validator
+    fn relay_cost(&self, state: &AlpenglowState, validator: ValidatorId, block_id: BlockHash) -> u64 {
+        let held = state.rotor_block_shreds.get(&block_id).and_then(|shreds| shreds.get(&validator));
+        let bytes = utils::shred_bytes(held.into_iter().flatten().filter(|piece| state.shred_authentic(&self.config, piece)));
+        bytes * self.config.validator_count.saturating_sub(1) as u64
     }
     
-    /// Check if validator can reconstruct block
+    /// Check if validator can reconstruct block (needs K distinct valid shred indices)
     fn can_reconstruct(&self, validator: ValidatorId, block_id: BlockHash) -> bool {
         self.state.rotor_block_shreds.get(&block_id)
             .and_then(|shreds| shreds.get(&validator))
-            .map_or(false, |pieces| pieces.len() >= self.config.k as usize)
+            .map_or(false, |pieces| {
+                let authentic: BTreeSet<_> = pieces.iter()
+                    .filter(|piece| self.state.shred_authentic(&self.config, piece))
+                    .cloned()
+                    .collect();
+                properties::distinct_valid_shred_count(&authentic, block_id, &self.config) >= self.config.k as usize
+            })
     }
     
     /// Safe timeout calculation helper to prevent overflow
     fn calculate_timeout(&self, base_time: TimeValue, view: ViewNumber) -> TimeValue {
         let exponent = (view + 1).min(63); // Cap to prevent overflow
         let multiplier = 2_u64.saturating_pow(exponent as u32);
-        base_time.saturating_add(self.config.timeout_delta.saturating_mul(multiplier))
+        
+        // Aggressive timeouts while the fast path is achievable, lenient ones otherwise
+        let delta = if !self.config.fast_path_aware_timeouts {
+            self.config.timeout_delta
+        } else if self.online_honest_stake() >= self.config.fast_path_threshold {
+            (self.config.timeout_delta / 2).max(1)
+        } else {
+            self.config.timeout_delta.saturating_mul(3) / 2
+        };
+        
+        // Scale towards the observed delay: half of `max_network_delay` keeps `delta`, a network
+        // at the bound stretches it by half, an instant one shrinks it by half
+        let delta = if self.config.adaptive_timeouts && !self.state.network_delivery_time.is_empty() {
+            let observed = utils::observed_mean_delay(&self.state).min(self.config.max_network_delay);
+            let reference = (self.config.max_network_delay / 2).max(1);
+            let scaled = delta.saturating_mul(observed) / reference;
+            ((delta.saturating_add(scaled)) / 2).max(1)
+        } else {
+            delta
+        };
+        
+        base_time.saturating_add(delta.saturating_mul(multiplier))
     }
     
     /// Find which partition a validator belongs to
@@ -1491,8 +3215,25 @@ impl AlpenglowModel {
         None
     }
     
-    /// Erasure encode a block
+    /// Erasure encode a block with a systematic Reed-Solomon code.
+    ///
+    /// The serialized block, prefixed with its length, is packed into field elements and split
+    /// evenly across the K data shreds. Position `s` of every shred lies on one polynomial of
+    /// degree below K through the data shreds' values at x = 1..=K; parity shred `x` carries
+    /// its value at x = K+1..=N, so any K shreds determine the rest.
     fn erasure_encode(&self, block: &Block) -> Vec<ErasureCodedPiece> {
+        let k = self.config.k.max(1) as usize;
+        let bytes = bincode::serialize(block).unwrap_or_default();
+        let mut words = vec![bytes.len() as u64];
+        words.extend(bytes.chunks(ERASURE_WORD_BYTES).map(|chunk| {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(word)
+        }));
+        let width = words.len().div_ceil(k);
+        words.resize(width * k, 0);
+        let data: Vec<Vec<u64>> = words.chunks(width).map(|chunk| chunk.to_vec()).collect();
+        
         let mut shreds = Vec::new();
         
         // Data shreds (indices 1..K)
@@ -1501,24 +3242,37 @@ impl AlpenglowModel {
                 block_id: block.hash,
                 index: i,
                 total_pieces: self.config.n,
-                data: vec![block.hash, i as u64], // Simplified data
+                data: data[i as usize - 1].clone(),
                 is_parity: false,
-                signature: block.signature,
+                signature: 0,
             });
         }
         
         // Parity shreds (indices K+1..N)
         for i in (self.config.k + 1)..=self.config.n {
+            let parity = (0..width)
+                .map(|position| {
+                    let points: Vec<(u64, u64)> = data.iter().enumerate()
+                        .map(|(shred, words)| (shred as u64 + 1, words[position]))
+                        .collect();
+                    erasure_interpolate(&points, i as u64)
+                })
+                .collect();
             shreds.push(ErasureCodedPiece {
                 block_id: block.hash,
                 index: i,
                 total_pieces: self.config.n,
-                data: vec![block.hash, i as u64], // Simplified parity
+                data: parity,
                 is_parity: true,
-                signature: block.signature,
+                signature: 0,
             });
         }
         
+        // The proposer signs every shred so relays can reject forgeries
+        for shred in &mut shreds {
+            shred.signature = utils::shred_signature(&self.config, block.proposer, shred);
+        }
+        
         shreds
     }
     
@@ -1532,15 +3286,17 @@ impl AlpenglowModel {
             assignments.insert(validator_id, Vec::new());
         }
         
+        // Rotate each block's pieces through the round-robin positions by a seeded amount, so the
+        // seed decides which pieces a relay gets but not how many
+        let rotation = shreds.first()
+            .map_or(0, |shred| utils::seeded_hash(self.config.seed, shred.block_id) as usize % shreds.len());
+        
         // Distribute pieces in round-robin fashion weighted by stake
         for (piece_idx, shred) in shreds.iter().enumerate() {
             // Calculate which validator should get this piece based on stake-weighted round-robin
             let mut cumulative_stake = 0;
-            let target_stake = if shreds.len() > 0 {
-                (piece_idx as u64 * self.config.total_stake) / shreds.len() as u64
-            } else {
-                0
-            };
+            let position = (piece_idx + rotation) % shreds.len();
+            let target_stake = (position as u64 * self.config.total_stake) / shreds.len() as u64;
             
             for validator in 0..self.config.validator_count {
                 let validator_id = validator as ValidatorId;
@@ -1557,7 +3313,7 @@ impl AlpenglowModel {
         assignments
     }
     
-    /// Reconstruct block from pieces
+    /// Reconstruct block from any K distinct pieces produced by `erasure_encode`
     fn reconstruct_block(&self, pieces: &BTreeSet<ErasureCodedPiece>) -> AlpenglowResult<Block> {
         if pieces.is_empty() {
             return Err(AlpenglowError::ProtocolViolation(
@@ -1565,19 +3321,89 @@ impl AlpenglowModel {
             ));
         }
         
-        let first_piece = pieces.iter().next().unwrap();
-        Ok(Block {
-            slot: 0, // Will be set from metadata or lookup
-            view: 0, // Will be set from metadata or lookup
-            hash: first_piece.block_id,
-            parent: 0,
-            proposer: 0,
-            transactions: BTreeSet::new(),
-            timestamp: 0,
-            signature: first_piece.signature,
-            data: vec![],
-        })
+        let block_id = pieces.iter().next().unwrap().block_id;
+        let k = self.config.k.max(1) as usize;
+        let mut by_index: BTreeMap<u64, &Vec<u64>> = BTreeMap::new();
+        for piece in pieces.iter().filter(|piece| piece.block_id == block_id) {
+            by_index.entry(piece.index as u64).or_insert(&piece.data);
+        }
+        if by_index.len() < k {
+            return Err(AlpenglowError::ProtocolViolation(format!(
+                "Cannot reconstruct block {} from {} of {} required pieces", block_id, by_index.len(), k
+            )));
+        }
+        
+        let points: Vec<(u64, &Vec<u64>)> = by_index.into_iter().take(k).collect();
+        let width = points[0].1.len();
+        if points.iter().any(|(_, data)| data.len() != width) {
+            return Err(AlpenglowError::ProtocolViolation(format!("Pieces of block {} differ in length", block_id)));
+        }
+        
+        // Take data shreds as they are and interpolate the missing ones
+        let mut words = Vec::with_capacity(width * k);
+        for index in 1..=k as u64 {
+            match points.iter().find(|(x, _)| *x == index) {
+                Some((_, data)) => words.extend(data.iter().copied()),
+                None => words.extend((0..width).map(|position| {
+                    let stripe: Vec<(u64, u64)> = points.iter().map(|(x, data)| (*x, data[position])).collect();
+                    erasure_interpolate(&stripe, index)
+                })),
+            }
+        }
+        
+        let length = words.first().copied().unwrap_or(0) as usize;
+        let bytes: Vec<u8> = words.iter().skip(1)
+            .flat_map(|word| word.to_le_bytes()[..ERASURE_WORD_BYTES].to_vec())
+            .collect();
+        if length > bytes.len() {
+            return Err(AlpenglowError::ProtocolViolation(format!("Pieces of block {} are truncated", block_id)));
+        }
+        let block: Block = bincode::deserialize(&bytes[..length])
+            .map_err(|e| AlpenglowError::SerializationError(e.to_string()))?;
+        if block.hash != block_id {
+            return Err(AlpenglowError::ProtocolViolation(format!(
+                "Pieces of block {} reconstruct block {}", block_id, block.hash
+            )));
+        }
+        Ok(block)
+    }
+}
+
+/// Prime modulus (2^61 - 1) of the field the Rotor erasure code computes in
+const ERASURE_FIELD_PRIME: u64 = (1 << 61) - 1;
+
+/// Block bytes packed into each field element; seven bytes always fit below the modulus
+const ERASURE_WORD_BYTES: usize = 7;
+
+/// Product of two field elements
+fn field_mul(a: u64, b: u64) -> u64 {
+    ((a as u128 * b as u128) % ERASURE_FIELD_PRIME as u128) as u64
+}
+
+/// Multiplicative inverse of a nonzero field element, by Fermat's little theorem
+fn field_inverse(a: u64) -> u64 {
+    let (mut base, mut exponent, mut result) = (a, ERASURE_FIELD_PRIME - 2, 1);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = field_mul(result, base);
+        }
+        base = field_mul(base, base);
+        exponent >>= 1;
     }
+    result
+}
+
+/// Value at `x` of the polynomial of degree below `points.len()` through `points` (Lagrange form)
+fn erasure_interpolate(points: &[(u64, u64)], x: u64) -> u64 {
+    let p = ERASURE_FIELD_PRIME;
+    points.iter().enumerate().fold(0, |sum, (i, &(xi, yi))| {
+        let (numerator, denominator) = points.iter().enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold((1, 1), |(numerator, denominator), (_, &(xj, _))| {
+                (field_mul(numerator, (x + p - xj) % p), field_mul(denominator, (xi + p - xj) % p))
+            });
+        (sum + field_mul(yi, field_mul(numerator, field_inverse(denominator)))) % p
+    })
 }
 
 impl AlpenglowState {
@@ -1617,7 +3443,12 @@ impl AlpenglowState {
             rotor_received_shreds.insert(validator_id, BTreeSet::new());
             rotor_reconstructed_blocks.insert(validator_id, BTreeSet::new());
             network_message_buffer.insert(validator_id, BTreeSet::new());
-            failure_states.insert(validator_id, ValidatorStatus::Honest);
+            let status = if config.observers.contains(&validator_id) {
+                ValidatorStatus::Observer
+            } else {
+                config.validator_statuses.get(&validator_id).cloned().unwrap_or(ValidatorStatus::Honest)
+            };
+            failure_states.insert(validator_id, status);
             bandwidth_metrics.insert(validator_id, 0);
         }
         
@@ -1630,20 +3461,26 @@ impl AlpenglowState {
         Self {
             clock: 0,
             current_slot: 1,
-            current_rotor: 0, // Initial leader
+            current_rotor: utils::leader_for_view(config, 1), // Initial leader
             votor_view,
             votor_voted_blocks,
             votor_generated_certs: BTreeMap::new(),
             votor_finalized_chain: Vec::new(),
             votor_skip_votes,
             votor_timeout_expiry,
+            validator_clock_offset: BTreeMap::new(),
             votor_received_votes,
+            votor_view_advances: BTreeMap::new(),
+            votor_aggregated_votes: BTreeMap::new(),
             rotor_block_shreds: BTreeMap::new(),
+            rotor_shred_signers: BTreeMap::new(),
             rotor_relay_assignments,
             rotor_reconstruction_state,
             rotor_delivered_blocks,
+            rotor_delivery_times: BTreeMap::new(),
             rotor_repair_requests: BTreeSet::new(),
             rotor_bandwidth_usage,
+            rotor_bandwidth_slot: 1,
             rotor_shred_assignments,
             rotor_received_shreds,
             rotor_reconstructed_blocks,
@@ -1651,11 +3488,17 @@ impl AlpenglowState {
             network_message_buffer,
             network_partitions: BTreeSet::new(),
             network_dropped_messages: 0,
+            network_enqueued_messages: 0,
+            network_delivered_messages: 0,
             network_delivery_time: BTreeMap::new(),
+            network_earliest_delivery: BTreeMap::new(),
             finalized_blocks,
+            finalization_times: BTreeMap::new(),
+            finalization_paths: BTreeMap::new(),
             delivered_blocks: BTreeSet::new(),
             messages: BTreeSet::new(),
             failure_states,
+            byzantine_strategies: BTreeMap::new(),
             block_id: 0,
             collected_pieces: BTreeSet::new(),
             complete: false,
@@ -1666,6 +3509,207 @@ impl AlpenglowState {
     pub fn latest_finalized_view(&self) -> ViewNumber {
         self.votor_finalized_chain.last().map_or(0, |block| block.view)
     }
+    
+    /// Minimal set of signers of the certificate that finalized `block` whose stake still meets
+    /// that certificate's threshold, or `None` if the block was not finalized by a certificate.
+    /// Signers are taken largest stake first, so dropping any member falls below the threshold.
+    pub fn critical_quorum(&self, block: BlockHash, config: &Config) -> Option<BTreeSet<ValidatorId>> {
+        if !self.votor_finalized_chain.iter().any(|b| b.hash == block) {
+            return None;
+        }
+        
+        let certificate = self.votor_generated_certs.values()
+            .flat_map(|certs| certs.iter())
+            .find(|cert| cert.block == block && cert.cert_type != CertificateType::Skip)?;
+        let threshold = match certificate.cert_type {
+            CertificateType::Fast => config.fast_path_threshold,
+            _ => config.slow_path_threshold,
+        };
+        
+        let mut signers: Vec<(StakeAmount, ValidatorId)> = certificate.validators.iter()
+            .map(|v| (config.stake_distribution.get(v).copied().unwrap_or(0), *v))
+            .collect();
+        signers.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        
+        let mut quorum = BTreeSet::new();
+        let mut stake: StakeAmount = 0;
+        for (validator_stake, validator) in signers {
+            if stake >= threshold {
+                break;
+            }
+            stake += validator_stake;
+            quorum.insert(validator);
+        }
+        
+        (stake >= threshold).then_some(quorum)
+    }
+    
+    /// Place `message` on the network queue, counting it for message accounting
+    pub fn enqueue_message(&mut self, message: NetworkMessage) {
+        if self.network_message_queue.insert(message) {
+            self.network_enqueued_messages += 1;
+        }
+    }
+    
+    /// JSON Schema describing the serialized state
+    #[cfg(feature = "json-schema")]
+    pub fn json_schema() -> serde_json::Value {
+        serde_json::to_value(schemars::schema_for!(AlpenglowState)).expect("schema serializes to JSON")
+    }
+    
+    /// Most advanced view of any validator; the relay leader is chosen for this view
+    pub fn current_view(&self) -> ViewNumber {
+        self.votor_view.values().copied().max().unwrap_or(1)
+    }
+    
+    /// Clock as seen by `validator`: the global clock shifted by its offset
+    pub fn local_clock(&self, validator: ValidatorId) -> TimeValue {
+        let offset = self.validator_clock_offset.get(&validator).copied().unwrap_or(0);
+        self.clock.saturating_add_signed(offset)
+    }
+    
+    /// Phase of `validator` in its current view, derived from its view, timeout, votes and leadership
+    pub fn validator_phase(&self, validator: ValidatorId, config: &Config) -> ValidatorPhase {
+        let view = self.votor_view.get(&validator).copied().unwrap_or(1);
+        let certified = self.votor_generated_certs.get(&view).is_some_and(|certs| !certs.is_empty());
+        let timeout_expiry = self.votor_timeout_expiry.get(&validator).copied().unwrap_or(0);
+        let voted = self.votor_received_votes.get(&validator)
+            .and_then(|views| views.get(&view))
+            .is_some_and(|votes| votes.iter().any(|vote| vote.voter == validator));
+        
+        if certified {
+            ValidatorPhase::Finalizing
+        } else if self.local_clock(validator) >= timeout_expiry {
+            ValidatorPhase::TimedOut
+        } else if voted {
+            ValidatorPhase::AwaitingVotes
+        } else if utils::leader_for_view(config, view) == validator {
+            ValidatorPhase::Proposing
+        } else {
+            ValidatorPhase::Voting
+        }
+    }
+    
+    /// Hash of every state variable, used to deduplicate states during exploration. Fields are
+    /// fed in declaration order through `utils::StableHasher` and all collections are ordered,
+    /// so the fingerprint is the same across runs, processes, platforms and Rust versions.
+    pub fn fingerprint(&self) -> u64 {
+        use std::hash::Hasher;
+        
+        let mut hasher = utils::StableHasher::new(0);
+        self.clock.hash(&mut hasher);
+        self.current_slot.hash(&mut hasher);
+        self.current_rotor.hash(&mut hasher);
+        
+        self.votor_view.hash(&mut hasher);
+        self.votor_voted_blocks.hash(&mut hasher);
+        self.votor_generated_certs.hash(&mut hasher);
+        self.votor_finalized_chain.hash(&mut hasher);
+        self.votor_skip_votes.hash(&mut hasher);
+        self.votor_timeout_expiry.hash(&mut hasher);
+        self.validator_clock_offset.hash(&mut hasher);
+        self.votor_received_votes.hash(&mut hasher);
+        self.votor_view_advances.hash(&mut hasher);
+        self.votor_aggregated_votes.hash(&mut hasher);
+        
+        self.rotor_block_shreds.hash(&mut hasher);
+        self.rotor_shred_signers.hash(&mut hasher);
+        self.rotor_relay_assignments.hash(&mut hasher);
+        self.rotor_reconstruction_state.hash(&mut hasher);
+        self.rotor_delivered_blocks.hash(&mut hasher);
+        self.rotor_delivery_times.hash(&mut hasher);
+        self.rotor_repair_requests.hash(&mut hasher);
+        self.rotor_bandwidth_usage.hash(&mut hasher);
+        self.rotor_bandwidth_slot.hash(&mut hasher);
+        self.rotor_shred_assignments.hash(&mut hasher);
+        self.rotor_received_shreds.hash(&mut hasher);
+        self.rotor_reconstructed_blocks.hash(&mut hasher);
+        
+        self.network_message_queue.hash(&mut hasher);
+        self.network_message_buffer.hash(&mut hasher);
+        self.network_partitions.hash(&mut hasher);
+        self.network_dropped_messages.hash(&mut hasher);
+        self.network_enqueued_messages.hash(&mut hasher);
+        self.network_delivered_messages.hash(&mut hasher);
+        self.network_delivery_time.hash(&mut hasher);
+        self.network_earliest_delivery.hash(&mut hasher);
+        
+        self.finalized_blocks.hash(&mut hasher);
+        self.finalization_times.hash(&mut hasher);
+        self.finalization_paths.hash(&mut hasher);
+        self.delivered_blocks.hash(&mut hasher);
+        self.messages.hash(&mut hasher);
+        self.failure_states.hash(&mut hasher);
+        self.byzantine_strategies.hash(&mut hasher);
+        self.block_id.hash(&mut hasher);
+        self.collected_pieces.hash(&mut hasher);
+        self.complete.hash(&mut hasher);
+        hasher.finish()
+    }
+    
+    /// Every value that differs between `self` and `other`. Maps are compared key by key so a
+    /// difference is reported at the map entry; lists and sets are reported as a whole.
+    pub fn detailed_diff(&self, other: &Self) -> Vec<FieldDiff> {
+        fn diff_values(path: String, old: Option<&serde_json::Value>, new: Option<&serde_json::Value>, diffs: &mut Vec<FieldDiff>) {
+            match (old, new) {
+                (Some(serde_json::Value::Object(old)), Some(serde_json::Value::Object(new))) => {
+                    let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+                    for key in keys {
+                        let path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                        diff_values(path, old.get(key), new.get(key), diffs);
+                    }
+                }
+                (old, new) if old != new => diffs.push(FieldDiff {
+                    path,
+                    old: old.map(|value| value.to_string()),
+                    new: new.map(|value| value.to_string()),
+                }),
+                _ => {}
+            }
+        }
+        
+        let mut diffs = Vec::new();
+        let old = serde_json::to_value(self).unwrap_or(serde_json::Value::Null);
+        let new = serde_json::to_value(other).unwrap_or(serde_json::Value::Null);
+        diff_values(String::new(), Some(&old), Some(&new), &mut diffs);
+        diffs
+    }
+    
+    /// Registered stake of the distinct validators whose votes for `view` any validator has received
+    pub fn participating_stake(&self, config: &Config, view: ViewNumber) -> StakeAmount {
+        self.votor_received_votes.values()
+            .filter_map(|views| views.get(&view))
+            .flat_map(|votes| votes.iter().map(|vote| vote.voter))
+            .collect::<BTreeSet<_>>()
+            .iter()
+            .map(|voter| config.stake_distribution.get(voter).copied().unwrap_or(0))
+            .sum()
+    }
+    
+    /// Participating stake in `view` as a fraction of total stake
+    pub fn participation_fraction(&self, config: &Config, view: ViewNumber) -> f64 {
+        if config.total_stake == 0 {
+            return 0.0;
+        }
+        self.participating_stake(config, view) as f64 / config.total_stake as f64
+    }
+    
+    /// Whether `piece` carries the signature of the leader that distributed its block
+    pub fn shred_authentic(&self, config: &Config, piece: &ErasureCodedPiece) -> bool {
+        self.rotor_shred_signers.get(&piece.block_id)
+            .is_some_and(|signer| piece.signature == utils::shred_signature(config, *signer, piece))
+    }
+    
+    /// Record that `validator` is leaving its current view; call before the view and timeout change
+    fn record_view_advance(&mut self, validator: ValidatorId, skip_certified: bool) {
+        let advance = ViewAdvance {
+            from_view: self.votor_view.get(&validator).copied().unwrap_or(1),
+            clock: self.local_clock(validator),
+            timeout_expiry: self.votor_timeout_expiry.get(&validator).copied().unwrap_or(0),
+            skip_certified,
+        };
+        self.votor_view_advances.entry(validator).or_default().push(advance);
+    }
 }
 
 impl TryFrom<serde_json::Value> for Config {
@@ -1715,9 +3759,26 @@ impl Config {
             vrf_enabled: true,
             network_delay: 50,
             timeout_ms: 1000,
+            seed: 0,
+            prefer_fast_path: true,
+            memory_budget_bytes: default_memory_budget_bytes(),
+            fast_path_aware_timeouts: false,
+            max_clock_skew: default_max_clock_skew(),
+            vote_gossip_delay: 0,
+            observers: BTreeSet::new(),
+            max_message_loss_rate: default_max_message_loss_rate(),
+            aggregation_batching: None,
+            validator_statuses: BTreeMap::new(),
+            check_byzantine_stake: false,
         }
     }
     
+    /// JSON Schema describing the serialized configuration
+    #[cfg(feature = "json-schema")]
+    pub fn json_schema() -> serde_json::Value {
+        serde_json::to_value(schemars::schema_for!(Config)).expect("schema serializes to JSON")
+    }
+    
     /// Generate TLA+ constants file for cross-validation
     pub fn to_tla_constants(&self) -> AlpenglowResult<serde_json::Value> {
         let constants = serde_json::json!({
@@ -1726,7 +3787,8 @@ impl Config {
             "TimeoutDelta": self.timeout_delta,
             "SlotDuration": 1000, // Default slot duration
             "GST": self.gst,
-            "Delta": self.max_network_delay,
+            "Delta": self.delta,
+            "MaxNetworkDelay": self.max_network_delay,
             "MaxSlot": self.max_slot,
             "MaxView": self.max_view,
             "BandwidthLimit": self.bandwidth_limit,
@@ -1735,6 +3797,10 @@ impl Config {
             "FastPathThreshold": self.fast_path_threshold,
             "SlowPathThreshold": self.slow_path_threshold,
             "ByzantineThreshold": self.byzantine_threshold,
+            "ErasureCodingRate": self.erasure_coding_rate,
+            "LeaderWindowSize": self.leader_window_size,
+            "AdaptiveTimeouts": self.adaptive_timeouts,
+            "VRFEnabled": self.vrf_enabled,
             "StakeDistribution": self.stake_distribution.iter().map(|(k, v)| (k.to_string(), v)).collect::<BTreeMap<String, &StakeAmount>>()
         });
         
@@ -1764,12 +3830,23 @@ impl Config {
             self.stake_distribution.insert(i as ValidatorId, stake_per_validator);
         }
         
-        // Update Byzantine threshold
-        self.byzantine_threshold = count / 3;
+        // Update Byzantine threshold to the largest f with f < n/3
+        self.byzantine_threshold = count.saturating_sub(1) / 3;
         
         self
     }
     
+    /// Like `with_validators`, but spreads the remainder of the split over the first validators
+    /// so the stakes sum to `total_stake` exactly
+    pub fn with_validators_exact_stake(self, count: usize) -> Self {
+        let remainder = if count > 0 { self.total_stake % count as u64 } else { 0 };
+        let mut config = self.with_validators(count);
+        for (_, stake) in config.stake_distribution.iter_mut().take(remainder as usize) {
+            *stake += 1;
+        }
+        config
+    }
+    
     /// Set Byzantine threshold
     pub fn with_byzantine_threshold(mut self, threshold: usize) -> Self {
         self.byzantine_threshold = threshold;
@@ -1831,6 +3908,107 @@ impl Config {
         self
     }
     
+    /// Set the seed driving every deterministic choice in the model
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+    
+    /// Set whether finalization prefers an available fast certificate
+    pub fn with_fast_path_preference(mut self, enabled: bool) -> Self {
+        self.prefer_fast_path = enabled;
+        self
+    }
+    
+    /// Enable timeouts that scale with fast-path quorum availability
+    pub fn with_fast_path_aware_timeouts(mut self, enabled: bool) -> Self {
+        self.fast_path_aware_timeouts = enabled;
+        self
+    }
+    
+    /// Set the largest tolerated clock drift between validators and the global clock
+    pub fn with_max_clock_skew(mut self, skew: TimeValue) -> Self {
+        self.max_clock_skew = skew;
+        self
+    }
+    
+    /// Set the largest tolerated fraction of dropped messages
+    pub fn with_max_message_loss_rate(mut self, rate: f64) -> Self {
+        self.max_message_loss_rate = rate;
+        self
+    }
+    
+    /// Attempt certificate aggregation only at batch boundaries
+    pub fn with_aggregation_batching(mut self, batching: AggregationBatching) -> Self {
+        self.aggregation_batching = Some(batching);
+        self
+    }
+    
+    /// Add a zero-stake observer as the next validator id; stake totals and thresholds are unchanged
+    pub fn with_observer(mut self) -> Self {
+        let observer = self.validator_count as ValidatorId;
+        self.validator_count += 1;
+        self.stake_distribution.insert(observer, 0);
+        self.observers.insert(observer);
+        self
+    }
+    
+    /// Gossip votes through the network, delivering each one `delay` after it was cast
+    pub fn with_vote_gossip_delay(mut self, delay: TimeValue) -> Self {
+        self.vote_gossip_delay = delay;
+        self
+    }
+    
+    /// Validate the worst-case Byzantine stake in addition to the Byzantine validator count
+    pub fn with_byzantine_stake_check(mut self, enabled: bool) -> Self {
+        self.check_byzantine_stake = enabled;
+        self
+    }
+    
+    /// Largest stake `byzantine_threshold` validators can hold together
+    pub fn worst_case_byzantine_stake(&self) -> StakeAmount {
+        let mut stakes: Vec<StakeAmount> = self.stake_distribution.values().copied().collect();
+        stakes.sort_unstable_by(|a, b| b.cmp(a));
+        stakes.iter().take(self.byzantine_threshold).sum()
+    }
+    
+    /// Start the given validators with the given statuses instead of honest
+    pub fn with_validator_statuses(mut self, statuses: BTreeMap<ValidatorId, ValidatorStatus>) -> Self {
+        self.validator_statuses = statuses;
+        self
+    }
+    
+    /// Set the memory budget for verification
+    pub fn with_memory_budget(mut self, bytes: usize) -> Self {
+        self.memory_budget_bytes = bytes;
+        self
+    }
+    
+    /// Rough number of states explored to `exploration_depth`: at every level each
+    /// validator can take about one action per other validator's message
+    pub fn estimated_state_count(&self) -> usize {
+        self.exploration_depth.saturating_mul(self.validator_count.saturating_mul(self.validator_count))
+    }
+    
+    /// Approximate bytes needed to verify this configuration: estimated state count times
+    /// the serialized size of the initial state, doubled for in-memory representation overhead
+    pub fn estimate_memory_for_verification(&self) -> usize {
+        let state_bytes = bincode::serialized_size(&AlpenglowState::init(self)).unwrap_or(0) as usize;
+        self.estimated_state_count().saturating_mul(state_bytes).saturating_mul(2)
+    }
+    
+    /// Warning message if the memory estimate exceeds `memory_budget_bytes`
+    pub fn memory_budget_warning(&self) -> Option<String> {
+        let estimate = self.estimate_memory_for_verification();
+        (estimate > self.memory_budget_bytes).then(|| format!(
+            "Verification of {} validators to depth {} needs about {} MB, over the {} MB budget",
+            self.validator_count,
+            self.exploration_depth,
+            estimate / (1024 * 1024),
+            self.memory_budget_bytes / (1024 * 1024)
+        ))
+    }
+    
     /// Set stake distribution
     pub fn with_stake_distribution(mut self, stakes: BTreeMap<ValidatorId, StakeAmount>) -> Self {
         self.total_stake = stakes.values().sum();
@@ -1846,7 +4024,7 @@ impl Config {
             return Err(AlpenglowError::InvalidConfig("Validator count must be positive".to_string()));
         }
         
-        if self.byzantine_threshold >= self.validator_count / 3 {
+        if self.byzantine_threshold * 3 >= self.validator_count {
             return Err(AlpenglowError::InvalidConfig("Too many Byzantine validators".to_string()));
         }
         
@@ -1858,9 +4036,42 @@ impl Config {
             return Err(AlpenglowError::InvalidConfig("Total stake must be positive".to_string()));
         }
         
-        Ok(())
-    }
-}
+        if self.vote_gossip_delay > self.delta {
+            return Err(AlpenglowError::InvalidConfig("Vote gossip delay exceeds Delta".to_string()));
+        }
+        
+        if !(0.0..=1.0).contains(&self.max_message_loss_rate) {
+            return Err(AlpenglowError::InvalidConfig("Message loss rate must be between 0 and 1".to_string()));
+        }
+        
+        if matches!(self.aggregation_batching, Some(AggregationBatching::Interval(0) | AggregationBatching::VoteCount(0))) {
+            return Err(AlpenglowError::InvalidConfig("Aggregation batch size must be positive".to_string()));
+        }
+        
+        if self.check_byzantine_stake && self.worst_case_byzantine_stake() * 3 >= self.total_stake {
+            return Err(AlpenglowError::InvalidConfig("Worst-case Byzantine stake is not below one third".to_string()));
+        }
+        
+        if self.validator_statuses.keys().any(|validator| *validator as usize >= self.validator_count) {
+            return Err(AlpenglowError::InvalidConfig("Validator status assigned to an unknown validator".to_string()));
+        }
+        
+        // Honest online stake must still reach the slow-path threshold, and Byzantine stake stay below one third
+        let stake_with_status = |wanted: ValidatorStatus| -> StakeAmount {
+            self.validator_statuses.iter()
+                .filter(|(_, status)| **status == wanted)
+                .map(|(validator, _)| self.stake_distribution.get(validator).copied().unwrap_or(0))
+                .sum()
+        };
+        let byzantine_stake = stake_with_status(ValidatorStatus::Byzantine);
+        let faulty_stake = byzantine_stake + stake_with_status(ValidatorStatus::Offline);
+        if faulty_stake > self.total_stake.saturating_sub(self.slow_path_threshold) || byzantine_stake * 3 >= self.total_stake {
+            return Err(AlpenglowError::InvalidConfig("Byzantine and offline stake exceeds the safe bound".to_string()));
+        }
+        
+        Ok(())
+    }
+}
 
 // Duplicate trait definition removed
 
@@ -1874,10 +4085,126 @@ impl TlaCompatible for AlpenglowState {
     }
     
     fn export_tla_state(&self) -> String {
-        self.to_tla_string()
+        serde_json::to_string(&self.export_tla_state_json()).unwrap_or_else(|_| "{}".to_string())
     }
     
-    fn import_tla_state(&mut self, _state: &Self) -> AlpenglowResult<()> {
+    /// Every state variable under its TLA+ name, with validator ids, views, slots and block
+    /// hashes as string keys. Functions keyed by messages become lists of (message, time) pairs.
+    fn export_tla_state_json(&self) -> serde_json::Value {
+        fn tla_value<T: Serialize>(value: &T) -> serde_json::Value {
+            serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+        }
+        
+        let variables = [
+            ("clock", tla_value(&self.clock)),
+            ("currentSlot", tla_value(&self.current_slot)),
+            ("currentRotor", tla_value(&self.current_rotor)),
+            ("votorView", tla_value(&self.votor_view)),
+            ("votorVotedBlocks", tla_value(&self.votor_voted_blocks)),
+            ("votorGeneratedCerts", tla_value(&self.votor_generated_certs)),
+            ("votorFinalizedChain", tla_value(&self.votor_finalized_chain)),
+            ("votorSkipVotes", tla_value(&self.votor_skip_votes)),
+            ("votorTimeoutExpiry", tla_value(&self.votor_timeout_expiry)),
+            ("validatorClockOffset", tla_value(&self.validator_clock_offset)),
+            ("votorReceivedVotes", tla_value(&self.votor_received_votes)),
+            ("votorViewAdvances", tla_value(&self.votor_view_advances)),
+            ("votorAggregatedVotes", tla_value(&self.votor_aggregated_votes)),
+            ("rotorBlockShreds", tla_value(&self.rotor_block_shreds)),
+            ("rotorShredSigners", tla_value(&self.rotor_shred_signers)),
+            ("rotorRelayAssignments", tla_value(&self.rotor_relay_assignments)),
+            ("rotorReconstructionState", tla_value(&self.rotor_reconstruction_state)),
+            ("rotorDeliveredBlocks", tla_value(&self.rotor_delivered_blocks)),
+            ("rotorDeliveryTimes", tla_value(&self.rotor_delivery_times)),
+            ("rotorRepairRequests", tla_value(&self.rotor_repair_requests)),
+            ("rotorBandwidthUsage", tla_value(&self.rotor_bandwidth_usage)),
+            ("rotorBandwidthSlot", tla_value(&self.rotor_bandwidth_slot)),
+            ("rotorShredAssignments", tla_value(&self.rotor_shred_assignments)),
+            ("rotorReceivedShreds", tla_value(&self.rotor_received_shreds)),
+            ("rotorReconstructedBlocks", tla_value(&self.rotor_reconstructed_blocks)),
+            ("networkMessageQueue", tla_value(&self.network_message_queue)),
+            ("networkMessageBuffer", tla_value(&self.network_message_buffer)),
+            ("networkPartitions", tla_value(&self.network_partitions)),
+            ("networkDroppedMessages", tla_value(&self.network_dropped_messages)),
+            ("networkEnqueuedMessages", tla_value(&self.network_enqueued_messages)),
+            ("networkDeliveredMessages", tla_value(&self.network_delivered_messages)),
+            ("networkDeliveryTime", tla_value(&self.network_delivery_time.iter().collect::<Vec<_>>())),
+            ("networkEarliestDelivery", tla_value(&self.network_earliest_delivery.iter().collect::<Vec<_>>())),
+            ("finalizedBlocks", tla_value(&self.finalized_blocks)),
+            ("finalizationTimes", tla_value(&self.finalization_times)),
+            ("finalizationPaths", tla_value(&self.finalization_paths)),
+            ("deliveredBlocks", tla_value(&self.delivered_blocks)),
+            ("messages", tla_value(&self.messages)),
+            ("failureStates", tla_value(&self.failure_states)),
+            ("byzantineStrategies", tla_value(&self.byzantine_strategies)),
+            ("blockId", tla_value(&self.block_id)),
+            ("collectedPieces", tla_value(&self.collected_pieces)),
+            ("complete", tla_value(&self.complete)),
+        ];
+        serde_json::Value::Object(variables.into_iter().map(|(name, value)| (name.to_string(), value)).collect())
+    }
+    
+    fn import_tla_state(&mut self, state: &Self) -> AlpenglowResult<()> {
+        *self = state.clone();
+        Ok(())
+    }
+    
+    /// Reconstruct the state from `export_tla_state_json` output; every variable is required
+    /// and the state is left unchanged if any is missing or malformed
+    fn import_tla_state_from_json(&mut self, state: serde_json::Value) -> AlpenglowResult<()> {
+        fn tla_field<T: serde::de::DeserializeOwned>(state: &serde_json::Value, name: &str) -> AlpenglowResult<T> {
+            let value = state.get(name)
+                .ok_or_else(|| AlpenglowError::SerializationError(format!("Missing '{}' in TLA+ state", name)))?;
+            serde_json::from_value(value.clone())
+                .map_err(|e| AlpenglowError::SerializationError(format!("Invalid '{}' in TLA+ state: {}", name, e)))
+        }
+        
+        let network_delivery_time: Vec<(NetworkMessage, TimeValue)> = tla_field(&state, "networkDeliveryTime")?;
+        let network_earliest_delivery: Vec<(NetworkMessage, TimeValue)> = tla_field(&state, "networkEarliestDelivery")?;
+        *self = AlpenglowState {
+            clock: tla_field(&state, "clock")?,
+            current_slot: tla_field(&state, "currentSlot")?,
+            current_rotor: tla_field(&state, "currentRotor")?,
+            votor_view: tla_field(&state, "votorView")?,
+            votor_voted_blocks: tla_field(&state, "votorVotedBlocks")?,
+            votor_generated_certs: tla_field(&state, "votorGeneratedCerts")?,
+            votor_finalized_chain: tla_field(&state, "votorFinalizedChain")?,
+            votor_skip_votes: tla_field(&state, "votorSkipVotes")?,
+            votor_timeout_expiry: tla_field(&state, "votorTimeoutExpiry")?,
+            validator_clock_offset: tla_field(&state, "validatorClockOffset")?,
+            votor_received_votes: tla_field(&state, "votorReceivedVotes")?,
+            votor_view_advances: tla_field(&state, "votorViewAdvances")?,
+            votor_aggregated_votes: tla_field(&state, "votorAggregatedVotes")?,
+            rotor_block_shreds: tla_field(&state, "rotorBlockShreds")?,
+            rotor_shred_signers: tla_field(&state, "rotorShredSigners")?,
+            rotor_relay_assignments: tla_field(&state, "rotorRelayAssignments")?,
+            rotor_reconstruction_state: tla_field(&state, "rotorReconstructionState")?,
+            rotor_delivered_blocks: tla_field(&state, "rotorDeliveredBlocks")?,
+            rotor_delivery_times: tla_field(&state, "rotorDeliveryTimes")?,
+            rotor_repair_requests: tla_field(&state, "rotorRepairRequests")?,
+            rotor_bandwidth_usage: tla_field(&state, "rotorBandwidthUsage")?,
+            rotor_bandwidth_slot: tla_field(&state, "rotorBandwidthSlot")?,
+            rotor_shred_assignments: tla_field(&state, "rotorShredAssignments")?,
+            rotor_received_shreds: tla_field(&state, "rotorReceivedShreds")?,
+            rotor_reconstructed_blocks: tla_field(&state, "rotorReconstructedBlocks")?,
+            network_message_queue: tla_field(&state, "networkMessageQueue")?,
+            network_message_buffer: tla_field(&state, "networkMessageBuffer")?,
+            network_partitions: tla_field(&state, "networkPartitions")?,
+            network_dropped_messages: tla_field(&state, "networkDroppedMessages")?,
+            network_enqueued_messages: tla_field(&state, "networkEnqueuedMessages")?,
+            network_delivered_messages: tla_field(&state, "networkDeliveredMessages")?,
+            network_delivery_time: network_delivery_time.into_iter().collect(),
+            network_earliest_delivery: network_earliest_delivery.into_iter().collect(),
+            finalized_blocks: tla_field(&state, "finalizedBlocks")?,
+            finalization_times: tla_field(&state, "finalizationTimes")?,
+            finalization_paths: tla_field(&state, "finalizationPaths")?,
+            delivered_blocks: tla_field(&state, "deliveredBlocks")?,
+            messages: tla_field(&state, "messages")?,
+            failure_states: tla_field(&state, "failureStates")?,
+            byzantine_strategies: tla_field(&state, "byzantineStrategies")?,
+            block_id: tla_field(&state, "blockId")?,
+            collected_pieces: tla_field(&state, "collectedPieces")?,
+            complete: tla_field(&state, "complete")?,
+        };
         Ok(())
     }
 }
@@ -1902,6 +4229,23 @@ pub struct VerificationMetrics {
     pub property_results: Vec<PropertyMetric>,
 }
 
+impl VerificationMetrics {
+    /// Stable hash of the verification outcome, excluding wall-clock timings
+    pub fn fingerprint(&self) -> u64 {
+        let outcome: Vec<String> = self.property_results.iter()
+            .map(|result| format!(
+                "{}|{}|{}|{:?}|{:?}",
+                result.name, result.passed, result.states_explored, result.error, result.counterexample_length
+            ))
+            .collect();
+        let summary = format!(
+            "{}|{}|{}|{}",
+            self.states_explored, self.properties_checked, self.violations, outcome.join(";")
+        );
+        utils::stable_hash(0, summary.as_bytes())
+    }
+}
+
 /// Per-property metric record
 #[derive(Debug, Clone)]
 pub struct PropertyMetric {
@@ -1911,6 +4255,188 @@ pub struct PropertyMetric {
     pub duration_ms: u64,
     pub error: Option<String>,
     pub counterexample_length: Option<usize>,
+    /// Actions leading from the explored root to the first state violating the property
+    pub counterexample: Vec<AlpenglowAction>,
+}
+
+/// Category a registered property belongs to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum PropertyCategory {
+    Safety,
+    Liveness,
+    Performance,
+    Byzantine,
+}
+
+impl PropertyCategory {
+    /// Lowercase category name used in reports
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PropertyCategory::Safety => "safety",
+            PropertyCategory::Liveness => "liveness",
+            PropertyCategory::Performance => "performance",
+            PropertyCategory::Byzantine => "byzantine",
+        }
+    }
+}
+
+/// How severe a violation of a property is
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PropertyCriticality {
+    Critical,
+    High,
+    Medium,
+    Low,
+}
+
+/// A field of `AlpenglowState`, used to declare which parts of the state a property reads
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum StateField {
+    Clock,
+    CurrentSlot,
+    CurrentRotor,
+    VotorView,
+    VotorVotedBlocks,
+    VotorGeneratedCerts,
+    VotorFinalizedChain,
+    VotorSkipVotes,
+    VotorTimeoutExpiry,
+    ValidatorClockOffset,
+    VotorReceivedVotes,
+    VotorViewAdvances,
+    VotorAggregatedVotes,
+    RotorBlockShreds,
+    RotorShredSigners,
+    RotorRelayAssignments,
+    RotorReconstructionState,
+    RotorDeliveredBlocks,
+    RotorDeliveryTimes,
+    RotorRepairRequests,
+    RotorBandwidthUsage,
+    RotorBandwidthSlot,
+    RotorShredAssignments,
+    RotorReceivedShreds,
+    RotorReconstructedBlocks,
+    NetworkMessageQueue,
+    NetworkMessageBuffer,
+    NetworkPartitions,
+    NetworkDroppedMessages,
+    NetworkEnqueuedMessages,
+    NetworkDeliveredMessages,
+    NetworkDeliveryTime,
+    NetworkEarliestDelivery,
+    FinalizedBlocks,
+    FinalizationTimes,
+    FinalizationPaths,
+    DeliveredBlocks,
+    Messages,
+    FailureStates,
+    ByzantineStrategies,
+    BlockId,
+    CollectedPieces,
+    Complete,
+}
+
+impl StateField {
+    /// Name of the field in `AlpenglowState`
+    pub fn name(self) -> &'static str {
+        use StateField::*;
+        match self {
+            Clock => "clock",
+            CurrentSlot => "current_slot",
+            CurrentRotor => "current_rotor",
+            VotorView => "votor_view",
+            VotorVotedBlocks => "votor_voted_blocks",
+            VotorGeneratedCerts => "votor_generated_certs",
+            VotorFinalizedChain => "votor_finalized_chain",
+            VotorSkipVotes => "votor_skip_votes",
+            VotorTimeoutExpiry => "votor_timeout_expiry",
+            ValidatorClockOffset => "validator_clock_offset",
+            VotorReceivedVotes => "votor_received_votes",
+            VotorViewAdvances => "votor_view_advances",
+            VotorAggregatedVotes => "votor_aggregated_votes",
+            RotorBlockShreds => "rotor_block_shreds",
+            RotorShredSigners => "rotor_shred_signers",
+            RotorRelayAssignments => "rotor_relay_assignments",
+            RotorReconstructionState => "rotor_reconstruction_state",
+            RotorDeliveredBlocks => "rotor_delivered_blocks",
+            RotorDeliveryTimes => "rotor_delivery_times",
+            RotorRepairRequests => "rotor_repair_requests",
+            RotorBandwidthUsage => "rotor_bandwidth_usage",
+            RotorBandwidthSlot => "rotor_bandwidth_slot",
+            RotorShredAssignments => "rotor_shred_assignments",
+            RotorReceivedShreds => "rotor_received_shreds",
+            RotorReconstructedBlocks => "rotor_reconstructed_blocks",
+            NetworkMessageQueue => "network_message_queue",
+            NetworkMessageBuffer => "network_message_buffer",
+            NetworkPartitions => "network_partitions",
+            NetworkDroppedMessages => "network_dropped_messages",
+            NetworkEnqueuedMessages => "network_enqueued_messages",
+            NetworkDeliveredMessages => "network_delivered_messages",
+            NetworkDeliveryTime => "network_delivery_time",
+            NetworkEarliestDelivery => "network_earliest_delivery",
+            FinalizedBlocks => "finalized_blocks",
+            FinalizationTimes => "finalization_times",
+            FinalizationPaths => "finalization_paths",
+            DeliveredBlocks => "delivered_blocks",
+            Messages => "messages",
+            FailureStates => "failure_states",
+            ByzantineStrategies => "byzantine_strategies",
+            BlockId => "block_id",
+            CollectedPieces => "collected_pieces",
+            Complete => "complete",
+        }
+    }
+}
+
+/// Metadata describing a property the checkers verify
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PropertyInfo {
+    pub name: &'static str,
+    pub category: PropertyCategory,
+    pub description: &'static str,
+    pub criticality: PropertyCriticality,
+    /// State fields the property reads
+    pub reads: &'static [StateField],
+}
+
+impl PropertyInfo {
+    /// State fields the property reads; a state change outside them cannot change its verdict
+    pub fn read_set(&self) -> &'static [StateField] {
+        self.reads
+    }
+}
+
+/// Which properties read each state field
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct DependencyGraph {
+    pub readers: BTreeMap<StateField, BTreeSet<&'static str>>,
+}
+
+impl DependencyGraph {
+    /// Properties that read `field`
+    pub fn properties_reading(&self, field: StateField) -> BTreeSet<&'static str> {
+        self.readers.get(&field).cloned().unwrap_or_default()
+    }
+    
+    /// Field-to-property edges as JSON, keyed by field name
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(&self.readers).unwrap_or(serde_json::Value::Null)
+    }
+    
+    /// Graphviz DOT rendering with an edge from each field to every property reading it
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph property_dependencies {\n    rankdir=LR;\n");
+        for (field, properties) in &self.readers {
+            dot.push_str(&format!("    \"{}\" [shape=box];\n", field.name()));
+            for property in properties {
+                dot.push_str(&format!("    \"{}\" -> \"{}\";\n", field.name(), property));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
 }
 
 /// Detailed result of a property check
@@ -1929,14 +4455,42 @@ pub struct PropertyCheckResult {
     pub counterexample_length: Option<usize>,
 }
 
+/// Rotor bandwidth split into useful block data and erasure-coding redundancy
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandwidthAccounting {
+    /// Bytes sent for all N shreds
+    pub throughput: u64,
+    /// Share of `throughput` carrying block data (K of every N shreds)
+    pub goodput: u64,
+    /// Bytes sent per byte of block data, N/K
+    pub redundancy_overhead: f64,
+}
+
+/// How a generated certificate was resolved
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CertificateResolution {
+    /// The certified block was finalized
+    Finalized,
+    /// A skip certificate, or a commit certificate superseded by a skip certificate for its view
+    Skipped,
+    /// A commit certificate whose view some validator is still in, so it may yet be finalized
+    Pending,
+    /// A commit certificate whose block was neither finalized nor skipped after every validator left its view
+    Orphaned,
+}
+
 /// Lightweight ModelChecker used in unit tests and example flows.
-/// It runs deterministic, single-state checks using the property functions in this file.
+/// It explores the model breadth-first from its current state and checks the property
+/// functions in this file against every reached state.
 pub struct ModelChecker {
     /// Configuration for the model
     pub config: Config,
     
     /// Collected metrics
     pub metrics: VerificationMetrics,
+    
+    /// Maximum number of distinct states to explore
+    pub max_states: usize,
 }
 
 impl ModelChecker {
@@ -1953,10 +4507,92 @@ impl ModelChecker {
                 states_per_second: 0.0,
                 property_results: Vec::new(),
             },
+            max_states: 1000,
+        }
+    }
+    
+    /// Set the maximum number of distinct states to explore
+    pub fn set_max_states(&mut self, max_states: usize) {
+        self.max_states = max_states;
+    }
+    
+    /// List every property the checker knows about, keyed by the names used in `PropertyMetric`
+    pub fn available_properties() -> Vec<PropertyInfo> {
+        use PropertyCategory::*;
+        use PropertyCriticality::*;
+        use StateField::*;
+        
+        let info = |name, category, description, criticality, reads| PropertyInfo { name, category, description, criticality, reads };
+        vec![
+            info("safety_no_conflicting_finalization", Safety, "No two conflicting blocks are finalized in the same slot", Critical, &[FinalizedBlocks]),
+            info("certificate_validity", Safety, "All generated certificates are valid", Critical, &[VotorGeneratedCerts]),
+            info("chain_consistency", Safety, "All honest validators agree on the finalized chain", Critical, &[FinalizedBlocks]),
+            info("bandwidth_safety", Safety, "No validator exceeds its bandwidth limit", High, &[RotorBandwidthUsage]),
+            info("bandwidth_resets_per_round", Safety, "Bandwidth usage is reset at every slot boundary", Medium, &[CurrentSlot, RotorBandwidthSlot, RotorBandwidthUsage]),
+            info("erasure_coding_validity", Safety, "All shreds have valid indices", High, &[RotorBlockShreds]),
+            info("vote_timestamp_monotonic", Safety, "Each validator's vote timestamps never decrease across views", Medium, &[VotorReceivedVotes, VotorSkipVotes]),
+            info("reconstruction_requires_k_distinct", Safety, "Blocks are only reconstructed from K distinct valid shreds", Critical, &[RotorBlockShreds, RotorDeliveredBlocks]),
+            info("relay_stake_weighting", Safety, "Rotor relay assignments follow stake", Medium, &[RotorBlockShreds, RotorRelayAssignments]),
+            info("total_stake_conserved", Safety, "Total stake equals the stake distribution and thresholds match it", Critical, &[]),
+            info("no_premature_view_advance", Safety, "Views only advance after their timeout or with a skip certificate", High, &[VotorViewAdvances]),
+            info("no_skip_and_commit_same_view", Safety, "No validator both skips and commits to a block in the same view", Critical, &[VotorReceivedVotes, VotorSkipVotes, VotorVotedBlocks]),
+            info("certificate_view_in_range", Safety, "Certificates reference a view within 1..=max_view of their own round", High, &[VotorGeneratedCerts]),
+            info("message_accounting_consistent", Safety, "Enqueued messages equal delivered plus dropped plus in-flight messages", Medium, &[NetworkDeliveredMessages, NetworkDroppedMessages, NetworkEnqueuedMessages, NetworkMessageQueue]),
+            info("certificate_references_known_block", Safety, "Every certificate certifies a block that was actually proposed", Critical, &[VotorGeneratedCerts, VotorVotedBlocks]),
+            info("clock_skew_bounded", Safety, "Validator clock offsets stay within max_clock_skew", High, &[ValidatorClockOffset]),
+            info("rotor_leader_correct", Safety, "The current relay leader is the scheduled leader for the current view", Medium, &[CurrentRotor, CurrentSlot, VotorView]),
+            info("stake_integrity", Safety, "Certificate stake is exactly the registered stake of its signers", Critical, &[VotorGeneratedCerts]),
+            info("shred_signature_valid", Safety, "Every shred a validator holds carries its block leader's signature", High, &[RotorBlockShreds, RotorShredSigners]),
+            info("finalized_total_order", Safety, "The finalized chain is a single parent-linked sequence with increasing views", Critical, &[VotorFinalizedChain]),
+            info("certificate_signer_vote_consistency", Safety, "Every signer of a certificate voted for the certified block", Critical, &[VotorGeneratedCerts, VotorReceivedVotes]),
+            info("safety_no_double_voting", Safety, "No honest validator commits to two different blocks in the same view", Critical, &[FailureStates, VotorVotedBlocks]),
+            info("safety_valid_certificates", Safety, "Certificate stake matches its validators, signers match its validators and its type matches the threshold met", Critical, &[VotorGeneratedCerts]),
+            info("view_monotonic_per_validator", Safety, "No validator's view decreases between a state and its successor", High, &[VotorView]),
+            info("liveness", Liveness, "Liveness is feasible: online honest stake reaches the slow-path threshold and Byzantine stake is below one third", High, &[FailureStates]),
+            info("liveness_eventual_progress", Liveness, "Progress is eventually made", High, &[VotorFinalizedChain]),
+            info("view_progression", Liveness, "Views progress in a timely manner", Medium, &[VotorView]),
+            info("block_delivery", Liveness, "Finalized blocks reach a majority of honest validators", High, &[FailureStates, RotorDeliveredBlocks, VotorFinalizedChain]),
+            info("rotor_delivery_before_finalization", Liveness, "Rotor delivers blocks to honest validators before they finalize", Medium, &[FailureStates, FinalizationTimes, RotorDeliveryTimes]),
+            info("leader_coverage", Liveness, "Every staked validator is selected as leader in proportion to its stake", Medium, &[VotorView]),
+            info("repair_requests_resolvable", Liveness, "Outstanding repair requests can be answered before they time out", Medium, &[Clock, RotorBlockShreds, RotorRepairRequests]),
+            info("certificate_resolution", Liveness, "Every commit certificate of a view all validators have left led to finalization or was superseded by a skip certificate", Medium, &[FinalizedBlocks, VotorFinalizedChain, VotorGeneratedCerts]),
+            info("leader_has_active_timeout", Liveness, "The leader of every uncertified active view has a timeout scheduled so a silent leader can be skipped", High, &[VotorGeneratedCerts, VotorTimeoutExpiry, VotorView]),
+            info("fast_path_liveness", Liveness, "Past GST with fast-path responsive honest stake the current view has a fast certificate", High, &[Clock, FailureStates, VotorGeneratedCerts, VotorView]),
+            info("slow_path_liveness", Liveness, "Past GST with only slow-path responsive honest stake the current view has a slow certificate", High, &[Clock, FailureStates, VotorGeneratedCerts, VotorView]),
+            info("delta_bounded_delivery", Performance, "Messages are delivered by their partial synchrony deadline", Medium, &[NetworkDeliveryTime]),
+            info("throughput_optimization", Performance, "The system maintains adequate throughput", Low, &[RotorBandwidthUsage]),
+            info("congestion_control", Performance, "Network congestion is properly managed", Low, &[NetworkMessageBuffer, NetworkMessageQueue]),
+            info("prefers_fast_path", Performance, "Blocks with a fast certificate are finalized through the fast path", Medium, &[FinalizationPaths, VotorGeneratedCerts]),
+            info("drop_rate_bounded", Performance, "The fraction of enqueued messages dropped stays within max_message_loss_rate", Medium, &[NetworkDroppedMessages, NetworkEnqueuedMessages]),
+            info("byzantine_resilience", Byzantine, "The protocol remains safe under Byzantine faults", Critical, &[FailureStates]),
+            info("byzantine_stake_resilience", Byzantine, "Byzantine validators hold less than a third of the total stake", Critical, &[FailureStates]),
+        ]
+    }
+    
+    /// Map every state field to the registered properties that read it
+    pub fn property_dependency_graph() -> DependencyGraph {
+        let mut graph = DependencyGraph::default();
+        for info in Self::available_properties() {
+            for field in info.read_set() {
+                graph.readers.entry(*field).or_default().insert(info.name);
+            }
         }
+        graph
     }
     
-    /// Run verification and collect metrics
+    /// Look up the metadata of a registered property by name
+    pub fn property_info(name: &str) -> Option<PropertyInfo> {
+        Self::available_properties().into_iter().find(|info| info.name == name)
+    }
+    
+    /// Run verification and collect metrics.
+    ///
+    /// States are explored breadth-first from `model.state`, deduplicated by fingerprint, up to
+    /// `config.exploration_depth` actions deep and `max_states` distinct states. Safety and
+    /// Byzantine properties are checked in every reached state; liveness properties only hold
+    /// eventually, so they are checked in the root state (see `RichModelChecker::check_eventually`
+    /// for a search over fair executions). Each property keeps the action path to the first
+    /// state that violated it.
     pub fn verify_model(&mut self, model: &AlpenglowModel) -> AlpenglowResult<VerificationMetrics> {
         let start_time = Instant::now();
         
@@ -1971,10 +4607,71 @@ impl ModelChecker {
             property_results: Vec::new(),
         };
         
-        // Run property checks
-        self.check_safety_properties(model)?;
-        self.check_liveness_properties(model)?;
-        self.check_byzantine_resilience(model)?;
+        let mut results: Vec<PropertyMetric> = Vec::new();
+        let mut seen: HashSet<u64> = HashSet::new();
+        let mut queue: VecDeque<(AlpenglowState, Vec<AlpenglowAction>)> = VecDeque::new();
+        seen.insert(model.state.fingerprint());
+        queue.push_back((model.state.clone(), Vec::new()));
+        let mut explored = 0;
+        
+        while let Some((state, path)) = queue.pop_front() {
+            explored += 1;
+            let mut snapshot = model.clone();
+            snapshot.state = state;
+            
+            // Run property checks
+            self.check_safety_properties(&snapshot)?;
+            if path.is_empty() {
+                self.check_liveness_properties(&snapshot)?;
+            }
+            self.check_byzantine_resilience(&snapshot)?;
+            
+            // Merge this state's results into the per-property records
+            for mut result in self.metrics.property_results.drain(..) {
+                match results.iter_mut().find(|existing| existing.name == result.name) {
+                    Some(existing) => {
+                        existing.states_explored += result.states_explored;
+                        existing.duration_ms += result.duration_ms;
+                        if existing.passed && !result.passed {
+                            existing.passed = false;
+                            existing.error = result.error;
+                            existing.counterexample_length = Some(path.len() + 1);
+                            existing.counterexample = path.clone();
+                        }
+                    }
+                    None => {
+                        if !result.passed {
+                            result.counterexample_length = Some(path.len() + 1);
+                            result.counterexample = path.clone();
+                        }
+                        results.push(result);
+                    }
+                }
+            }
+            
+            if path.len() >= self.config.exploration_depth {
+                continue;
+            }
+            let mut actions = Vec::new();
+            model.actions(&snapshot.state, &mut actions);
+            for action in actions {
+                if seen.len() >= self.max_states {
+                    break;
+                }
+                if let Some(next) = model.next_state(&snapshot.state, action.clone()) {
+                    if seen.insert(next.fingerprint()) {
+                        let mut next_path = path.clone();
+                        next_path.push(action);
+                        queue.push_back((next, next_path));
+                    }
+                }
+            }
+        }
+        
+        self.metrics.states_explored = explored;
+        self.metrics.properties_checked = results.len();
+        self.metrics.violations = results.iter().filter(|result| !result.passed).count();
+        self.metrics.property_results = results;
         
         // Finalize metrics
         let duration = start_time.elapsed();
@@ -2008,10 +4705,82 @@ impl ModelChecker {
         let result = properties::bandwidth_safety_detailed(&model.state, &model.config);
         self.add_property_result("bandwidth_safety", result, start_time.elapsed());
         
+        // Check bandwidth usage is reset every slot
+        let result = properties::bandwidth_resets_per_round_detailed(&model.state, &model.config);
+        self.add_property_result("bandwidth_resets_per_round", result, start_time.elapsed());
+        
         // Check erasure coding validity
         let result = properties::erasure_coding_validity_detailed(&model.state, &model.config);
         self.add_property_result("erasure_coding_validity", result, start_time.elapsed());
         
+        // Check vote timestamp monotonicity
+        let result = properties::vote_timestamp_monotonic_detailed(&model.state, &model.config);
+        self.add_property_result("vote_timestamp_monotonic", result, start_time.elapsed());
+        
+        // Check reconstruction is backed by K distinct shreds
+        let result = properties::reconstruction_requires_k_distinct_detailed(&model.state, &model.config);
+        self.add_property_result("reconstruction_requires_k_distinct", result, start_time.elapsed());
+        
+        // Check relay assignments follow stake
+        let result = properties::relay_stake_weighting_detailed(&model.state, &model.config);
+        self.add_property_result("relay_stake_weighting", result, start_time.elapsed());
+        
+        // Check total stake matches the stake distribution
+        let result = properties::total_stake_conserved_detailed(&model.state, &model.config);
+        self.add_property_result("total_stake_conserved", result, start_time.elapsed());
+        
+        // Check views only advance after their timeout
+        let result = properties::no_premature_view_advance_detailed(&model.state, &model.config);
+        self.add_property_result("no_premature_view_advance", result, start_time.elapsed());
+        
+        // Check no validator both skips and commits in a view
+        let result = properties::no_skip_and_commit_same_view_detailed(&model.state, &model.config);
+        self.add_property_result("no_skip_and_commit_same_view", result, start_time.elapsed());
+        
+        // Check certificates reference valid views
+        let result = properties::certificate_view_in_range_detailed(&model.state, &model.config);
+        self.add_property_result("certificate_view_in_range", result, start_time.elapsed());
+        
+        // Check every enqueued message is delivered, dropped or in flight
+        let result = properties::message_accounting_consistent_detailed(&model.state, &model.config);
+        self.add_property_result("message_accounting_consistent", result, start_time.elapsed());
+        
+        // Check certificates only reference proposed blocks
+        let result = properties::certificate_references_known_block_detailed(&model.state, &model.config);
+        self.add_property_result("certificate_references_known_block", result, start_time.elapsed());
+        
+        // Check validator clocks drift no further than tolerated
+        let result = properties::clock_skew_bounded_detailed(&model.state, &model.config);
+        self.add_property_result("clock_skew_bounded", result, start_time.elapsed());
+        
+        // Check the relay leader follows the leader schedule
+        let result = properties::rotor_leader_correct_detailed(&model.state, &model.config);
+        self.add_property_result("rotor_leader_correct", result, start_time.elapsed());
+        
+        // Check certificates count only registered stake
+        let result = properties::stake_integrity_detailed(&model.state, &model.config);
+        self.add_property_result("stake_integrity", result, start_time.elapsed());
+        
+        // Check shred signature validity
+        let result = properties::shred_signature_valid_detailed(&model.state, &model.config);
+        self.add_property_result("shred_signature_valid", result, start_time.elapsed());
+        
+        // Check the finalized chain is totally ordered
+        let result = properties::finalized_total_order_detailed(&model.state, &model.config);
+        self.add_property_result("finalized_total_order", result, start_time.elapsed());
+        
+        // Check every certificate signer voted for the certified block
+        let result = properties::certificate_signer_vote_consistency_detailed(&model.state, &model.config);
+        self.add_property_result("certificate_signer_vote_consistency", result, start_time.elapsed());
+        
+        // Check no honest validator votes for two blocks in one view
+        let result = properties::safety_no_double_voting_detailed(&model.state, &model.config);
+        self.add_property_result("safety_no_double_voting", result, start_time.elapsed());
+        
+        // Check certificate stake, signers and type agree with each other
+        let result = properties::safety_valid_certificates_detailed(&model.state, &model.config);
+        self.add_property_result("safety_valid_certificates", result, start_time.elapsed());
+        
         Ok(())
     }
     
@@ -2019,6 +4788,20 @@ impl ModelChecker {
     fn check_liveness_properties(&mut self, model: &AlpenglowModel) -> AlpenglowResult<()> {
         let start_time = Instant::now();
         
+        // Report infeasible liveness as not applicable instead of as a violation
+        if let Err(reason) = model.liveness_feasible() {
+            self.metrics.property_results.push(PropertyMetric {
+                name: "liveness".to_string(),
+                passed: true,
+                states_explored: 0,
+                duration_ms: start_time.elapsed().as_millis() as u64,
+                error: Some(format!("liveness not applicable: {}", reason)),
+                counterexample_length: None,
+                counterexample: Vec::new(),
+            });
+            return Ok(());
+        }
+        
         // Check eventual progress
         let result = properties::liveness_eventual_progress_detailed(&model.state, &model.config);
         self.add_property_result("liveness_eventual_progress", result, start_time.elapsed());
@@ -2031,6 +4814,36 @@ impl ModelChecker {
         let result = properties::block_delivery_detailed(&model.state, &model.config);
         self.add_property_result("block_delivery", result, start_time.elapsed());
         
+        // Rotor delivery precedes finalization
+        let result = properties::rotor_delivery_before_finalization_detailed(&model.state, &model.config);
+        self.add_property_result("rotor_delivery_before_finalization", result, start_time.elapsed());
+        
+        // Leader rotation covers every staked validator
+        let result = properties::leader_coverage_detailed(&model.state, &model.config);
+        self.add_property_result("leader_coverage", result, start_time.elapsed());
+        
+        // Outstanding repair requests can still be answered
+        let result = properties::repair_requests_resolvable_detailed(&model.state, &model.config);
+        self.add_property_result("repair_requests_resolvable", result, start_time.elapsed());
+        
+        // Commit certificates are finalized or superseded by a skip
+        let result = properties::certificate_resolution_detailed(&model.state, &model.config);
+        self.add_property_result("certificate_resolution", result, start_time.elapsed());
+        
+        // Leaders of uncertified views have a timeout pending
+        let result = properties::leader_has_active_timeout_detailed(&model.state, &model.config);
+        self.add_property_result("leader_has_active_timeout", result, start_time.elapsed());
+        
+        // Past GST with enough responsive stake the fast path certifies the current view
+        let start_time = Instant::now();
+        let result = properties::fast_path_liveness_detailed(&model.state, &model.config);
+        self.add_property_result("fast_path_liveness", result, start_time.elapsed());
+        
+        // With only slow-path responsive stake the current view still certifies through the slow path
+        let start_time = Instant::now();
+        let result = properties::slow_path_liveness_detailed(&model.state, &model.config);
+        self.add_property_result("slow_path_liveness", result, start_time.elapsed());
+        
         Ok(())
     }
     
@@ -2042,6 +4855,10 @@ impl ModelChecker {
         let result = properties::byzantine_resilience_detailed(&model.state, &model.config);
         self.add_property_result("byzantine_resilience", result, start_time.elapsed());
         
+        let start_time = Instant::now();
+        let result = properties::byzantine_stake_resilience_detailed(&model.state, &model.config);
+        self.add_property_result("byzantine_stake_resilience", result, start_time.elapsed());
+        
         Ok(())
     }
     
@@ -2054,6 +4871,7 @@ impl ModelChecker {
             duration_ms: duration.as_millis() as u64,
             error: result.error.clone(),
             counterexample_length: result.counterexample_length,
+            counterexample: Vec::new(),
         };
         
         self.metrics.property_results.push(property_result);
@@ -2071,6 +4889,103 @@ impl ModelChecker {
     }
 }
 
+/// Strongly connected components of the subgraph induced by `nodes`
+fn strongly_connected_components(nodes: &BTreeSet<usize>, edges: &BTreeMap<usize, Vec<(usize, ActionClass)>>) -> Vec<BTreeSet<usize>> {
+    let successors = |node: usize| -> Vec<usize> {
+        edges.get(&node)
+            .map(|out| out.iter().map(|(target, _)| *target).filter(|target| nodes.contains(target)).collect())
+            .unwrap_or_default()
+    };
+    
+    // Kosaraju: order nodes by DFS finish time, then collect components on the reversed graph
+    let mut visited = BTreeSet::new();
+    let mut finished = Vec::new();
+    for &root in nodes {
+        if !visited.insert(root) {
+            continue;
+        }
+        let mut stack = vec![(root, successors(root), 0)];
+        while let Some((node, out, position)) = stack.last_mut() {
+            if let Some(&target) = out.get(*position) {
+                *position += 1;
+                if visited.insert(target) {
+                    stack.push((target, successors(target), 0));
+                }
+            } else {
+                finished.push(*node);
+                stack.pop();
+            }
+        }
+    }
+    
+    let mut predecessors: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for &node in nodes {
+        for target in successors(node) {
+            predecessors.entry(target).or_default().push(node);
+        }
+    }
+    
+    let mut assigned = BTreeSet::new();
+    let mut components = Vec::new();
+    for &root in finished.iter().rev() {
+        if !assigned.insert(root) {
+            continue;
+        }
+        let mut component = BTreeSet::from([root]);
+        let mut pending = vec![root];
+        while let Some(node) = pending.pop() {
+            for &source in predecessors.get(&node).into_iter().flatten() {
+                if assigned.insert(source) {
+                    component.insert(source);
+                    pending.push(source);
+                }
+            }
+        }
+        components.push(component);
+    }
+    components
+}
+
+/// Find a set of states within `nodes` supporting a cycle that satisfies every fairness constraint.
+/// Every state can stutter, so each component contains a cycle; stuttering takes no action.
+fn fair_cycle(
+    nodes: &BTreeSet<usize>,
+    edges: &BTreeMap<usize, Vec<(usize, ActionClass)>>,
+    enabled: &BTreeMap<usize, BTreeSet<ActionClass>>,
+    constraints: &[(Fairness, ActionClass)],
+) -> Option<BTreeSet<usize>> {
+    'components: for component in strongly_connected_components(nodes, edges) {
+        for &(fairness, class) in constraints {
+            let taken = component.iter().any(|node| {
+                edges[node].iter().any(|(target, taken)| *taken == class && component.contains(target))
+            });
+            if taken {
+                continue;
+            }
+            
+            let disabled: BTreeSet<usize> = component.iter()
+                .copied()
+                .filter(|node| !enabled[node].contains(&class))
+                .collect();
+            match fairness {
+                // A cycle through a state where the class is disabled is weakly fair
+                Fairness::Weak if !disabled.is_empty() => continue,
+                Fairness::Strong if disabled.len() == component.len() => continue,
+                // A strongly fair cycle that never takes the class must avoid every state enabling it
+                Fairness::Strong if !disabled.is_empty() => {
+                    if let Some(cycle) = fair_cycle(&disabled, edges, enabled, constraints) {
+                        return Some(cycle);
+                    }
+                    continue 'components;
+                }
+                _ => continue 'components,
+            }
+        }
+        return Some(component);
+    }
+    None
+}
+
 /// Property checkers for formal verification
 pub mod properties {
     use super::*;
@@ -2130,7 +5045,7 @@ pub mod properties {
             .count();
         
         // Safety should hold as long as Byzantine validators are less than 1/3
-        byzantine_count < config.validator_count / 3
+        byzantine_count * 3 < config.validator_count
     }
     
     /// Detailed version of byzantine_resilience
@@ -2139,10 +5054,42 @@ pub mod properties {
             .filter(|status| matches!(status, ValidatorStatus::Byzantine))
             .count();
         
-        let passed = byzantine_count < config.validator_count / 3;
+        let passed = byzantine_count * 3 < config.validator_count;
+        
+        let error = if !passed {
+            Some(format!("Too many Byzantine validators: {} of {} is not below one third", byzantine_count, config.validator_count))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(1) } else { None },
+        }
+    }
+    
+    /// Total stake of the validators currently marked Byzantine
+    fn byzantine_stake(state: &AlpenglowState, config: &Config) -> StakeAmount {
+        state.failure_states.iter()
+            .filter(|(_, status)| matches!(status, ValidatorStatus::Byzantine))
+            .map(|(validator, _)| config.stake_distribution.get(validator).copied().unwrap_or(0))
+            .sum()
+    }
+    
+    /// Byzantine stake resilience: Byzantine validators hold less than a third of the total stake
+    pub fn byzantine_stake_resilience(state: &AlpenglowState, config: &Config) -> bool {
+        byzantine_stake(state, config) * 3 < config.total_stake
+    }
+    
+    /// Detailed version of byzantine_stake_resilience
+    pub fn byzantine_stake_resilience_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let stake = byzantine_stake(state, config);
+        let passed = stake * 3 < config.total_stake;
         
         let error = if !passed {
-            Some(format!("Too many Byzantine validators: {} >= {}", byzantine_count, config.validator_count / 3))
+            Some(format!("Too much Byzantine stake: {} of {} is not below one third", stake, config.total_stake))
         } else {
             None
         };
@@ -2228,6 +5175,48 @@ pub mod properties {
         }
     }
     
+    /// Validators whose bandwidth usage was carried over from an earlier slot
+    fn stale_bandwidth_usage(state: &AlpenglowState) -> Vec<(ValidatorId, u64)> {
+        if state.rotor_bandwidth_slot == state.current_slot {
+            return Vec::new();
+        }
+        
+        state.rotor_bandwidth_usage.iter()
+            .filter(|(_, usage)| **usage > 0)
+            .map(|(validator, usage)| (*validator, *usage))
+            .collect()
+    }
+    
+    /// Bandwidth resets per round: usage never carries over from an earlier slot
+    pub fn bandwidth_resets_per_round(state: &AlpenglowState) -> bool {
+        stale_bandwidth_usage(state).is_empty()
+    }
+    
+    /// Detailed version of bandwidth_resets_per_round
+    pub fn bandwidth_resets_per_round_detailed(state: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let stale = stale_bandwidth_usage(state);
+        
+        let passed = stale.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = stale.iter()
+                .map(|(validator, usage)| format!("validator {} ({} bytes)", validator, usage))
+                .collect();
+            Some(format!(
+                "Bandwidth usage from slot {} carried into slot {}: {}",
+                state.rotor_bandwidth_slot, state.current_slot, details.join(", ")
+            ))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(stale.len()) } else { None },
+        }
+    }
+    
     /// Chain consistency: All honest validators agree on finalized chain
     pub fn chain_consistency(state: &AlpenglowState) -> bool {
         // For simplicity, check that there's a single finalized chain
@@ -2328,22 +5317,32 @@ pub mod properties {
         }
     }
     
-    /// Delta bounded delivery: Messages delivered within Delta time bound
+    /// Delta bounded delivery: Messages delivered by their partial synchrony deadline, the
+    /// same `PartialSynchronyModel::delivery_deadline` that holds the clock
     pub fn delta_bounded_delivery(state: &AlpenglowState, config: &Config) -> bool {
-        // Check that all messages in delivery_time are within Delta bound
-        state.network_delivery_time.values()
-            .all(|&delivery_time| delivery_time <= config.max_network_delay)
+        state.network_delivery_time.iter()
+            .all(|(message, &delay)| !delivered_late(config, message, delay))
     }
     
     /// Detailed version of delta_bounded_delivery
     pub fn delta_bounded_delivery_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
         let violations: Vec<_> = state.network_delivery_time.iter()
-            .filter(|(_, &delivery_time)| delivery_time > config.max_network_delay)
+            .filter(|(message, &delay)| delivered_late(config, message, delay))
             .collect();
         
         let passed = violations.is_empty();
         let error = if !passed {
-            Some(format!("Found {} messages exceeding Delta bound", violations.len()))
+            let details: Vec<String> = violations.iter()
+                .map(|(message, delay)| format!(
+                    "message {} from validator {} ({} ticks, due at {})",
+                    message.id, message.sender, delay,
+                    PartialSynchronyModel::delivery_deadline(config, message.timestamp)
+                ))
+                .collect();
+            Some(format!(
+                "Found {} messages delivered after their Delta deadline: {}",
+                violations.len(), details.join(", ")
+            ))
         } else {
             None
         };
@@ -2356,6 +5355,22 @@ pub mod properties {
         }
     }
     
+    // Whether `message`, delivered `delay` ticks after it was sent, missed its deadline
+    fn delivered_late(config: &Config, message: &NetworkMessage, delay: TimeValue) -> bool {
+        message.timestamp.saturating_add(delay) > PartialSynchronyModel::delivery_deadline(config, message.timestamp)
+    }
+    
+    /// Split the bandwidth charged for shreds into goodput and erasure-coding redundancy
+    pub fn bandwidth_accounting(state: &AlpenglowState, config: &Config) -> BandwidthAccounting {
+        let throughput: u64 = state.rotor_bandwidth_usage.values().sum();
+        let n = config.n.max(1) as u64;
+        BandwidthAccounting {
+            throughput,
+            goodput: throughput * config.k as u64 / n,
+            redundancy_overhead: n as f64 / config.k.max(1) as f64,
+        }
+    }
+    
     /// Throughput optimization: System maintains adequate throughput
     pub fn throughput_optimization(state: &AlpenglowState, config: &Config) -> bool {
         // Check that bandwidth is being used efficiently
@@ -2383,7 +5398,11 @@ pub mod properties {
             } else {
                 0.0
             };
-            Some(format!("Poor bandwidth utilization: {:.2}%", utilization * 100.0))
+            let accounting = bandwidth_accounting(state, config);
+            Some(format!(
+                "Poor bandwidth utilization: {:.2}% (goodput {} of {} bytes, {:.2}x erasure-coding overhead)",
+                utilization * 100.0, accounting.goodput, accounting.throughput, accounting.redundancy_overhead
+            ))
         } else {
             None
         };
@@ -2431,6 +5450,77 @@ pub mod properties {
         }
     }
     
+    /// Message accounting: every enqueued message is delivered, dropped or still in flight
+    pub fn message_accounting_consistent(state: &AlpenglowState) -> bool {
+        state.network_enqueued_messages
+            == state.network_delivered_messages + state.network_dropped_messages + state.network_message_queue.len() as u64
+    }
+    
+    /// Detailed version of message_accounting_consistent
+    pub fn message_accounting_consistent_detailed(state: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let in_flight = state.network_message_queue.len() as u64;
+        let accounted = state.network_delivered_messages + state.network_dropped_messages + in_flight;
+        
+        let passed = state.network_enqueued_messages == accounted;
+        let error = if !passed {
+            Some(format!(
+                "Message accounting mismatch: {} enqueued but {} delivered + {} dropped + {} in flight = {}",
+                state.network_enqueued_messages,
+                state.network_delivered_messages,
+                state.network_dropped_messages,
+                in_flight,
+                accounted
+            ))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(1) } else { None },
+        }
+    }
+    
+    /// Fraction of enqueued messages the network has dropped so far
+    fn drop_rate(state: &AlpenglowState) -> f64 {
+        if state.network_enqueued_messages == 0 {
+            return 0.0;
+        }
+        state.network_dropped_messages as f64 / state.network_enqueued_messages as f64
+    }
+    
+    /// Drop rate bounded: dropped messages stay within `max_message_loss_rate` of those enqueued
+    pub fn drop_rate_bounded(state: &AlpenglowState, config: &Config) -> bool {
+        drop_rate(state) <= config.max_message_loss_rate
+    }
+    
+    /// Detailed version of drop_rate_bounded
+    pub fn drop_rate_bounded_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let rate = drop_rate(state);
+        
+        let passed = rate <= config.max_message_loss_rate;
+        let error = if !passed {
+            Some(format!(
+                "Dropped {} of {} enqueued messages ({:.1}%), above the tolerated {:.1}%",
+                state.network_dropped_messages,
+                state.network_enqueued_messages,
+                rate * 100.0,
+                config.max_message_loss_rate * 100.0
+            ))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(1) } else { None },
+        }
+    }
+    
     /// View progression: Views progress in a timely manner
     pub fn view_progression(state: &AlpenglowState, _config: &Config) -> bool {
         // Check that views don't get stuck
@@ -2502,459 +5592,4889 @@ pub mod properties {
             counterexample_length: if !passed { Some(1) } else { None },
         }
     }
-}
-
-/// Utilities for cross-validation and testing
-pub mod utils {
-    use super::*;
     
-    /// Generate test configurations for various scenarios
-    pub fn test_configs() -> Vec<Config> {
-        vec![
-            Config::new().with_validators(3),
+    /// Find generated certificates whose view is outside `1..=max_view` or differs from the view
+    /// they are stored under, as (stored view, certificate)
+    fn out_of_range_certificates<'a>(state: &'a AlpenglowState, config: &Config) -> Vec<(ViewNumber, &'a Certificate)> {
+        state.votor_generated_certs.iter()
+            .flat_map(|(view, certs)| certs.iter().map(move |cert| (*view, cert)))
+            .filter(|(view, cert)| cert.view < 1 || cert.view > config.max_view || cert.view != *view)
+            .collect()
+    }
+    
+    /// Certificate view in range: Every generated certificate references a valid view of its own round
+    pub fn certificate_view_in_range(state: &AlpenglowState, config: &Config) -> bool {
+        out_of_range_certificates(state, config).is_empty()
+    }
+    
+    /// Detailed version of certificate_view_in_range
+    pub fn certificate_view_in_range_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let out_of_range = out_of_range_certificates(state, config);
+        
+        let passed = out_of_range.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = out_of_range.iter()
+                .map(|(view, cert)| {
+                    format!("{:?} cert for block {} has view {} (stored under view {})", cert.cert_type, cert.block, cert.view, view)
+                })
+                .collect();
+            Some(format!("Certificates outside valid view range 1..={}: {}", config.max_view, details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(out_of_range.len()) } else { None },
+        }
+    }
+    
+    /// Outstanding repair requests that no other validator can answer, or that have outlived
+    /// the repair timeout (`timeout_delta` ticks) without being answered or expired
+    fn stuck_repair_requests<'a>(state: &'a AlpenglowState, config: &Config) -> Vec<(&'a RepairRequest, &'static str)> {
+        state.rotor_repair_requests.iter()
+            .filter_map(|request| {
+                let answerable = state.rotor_block_shreds.get(&request.block_id).is_some_and(|holders| {
+                    holders.iter()
+                        .filter(|(holder, _)| **holder != request.requester)
+                        .any(|(_, pieces)| pieces.iter().any(|piece| request.missing_indices.contains(&piece.index)))
+                });
+                
+                if !answerable {
+                    Some((request, "no validator holds the missing shreds"))
+                } else if state.clock > request.timestamp.saturating_add(config.timeout_delta) {
+                    Some((request, "outstanding past its timeout"))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+    
+    /// Repair requests resolvable: every outstanding repair request can still be answered in time
+    pub fn repair_requests_resolvable(state: &AlpenglowState, config: &Config) -> bool {
+        stuck_repair_requests(state, config).is_empty()
+    }
+    
+    /// Detailed version of repair_requests_resolvable
+    pub fn repair_requests_resolvable_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let stuck = stuck_repair_requests(state, config);
+        
+        let passed = stuck.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = stuck.iter()
+                .map(|(request, reason)| {
+                    format!(
+                        "validator {} block {} indices {:?} at {}: {}",
+                        request.requester, request.block_id, request.missing_indices, request.timestamp, reason
+                    )
+                })
+                .collect();
+            Some(format!("Unresolvable repair requests: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(stuck.len()) } else { None },
+        }
+    }
+    
+    /// Classify every generated certificate as finalized, skipped, pending or orphaned
+    pub fn certificate_resolutions(state: &AlpenglowState) -> Vec<(&Certificate, CertificateResolution)> {
+        let finalized: BTreeSet<BlockHash> = state.finalized_blocks.values()
+            .flat_map(|blocks| blocks.iter().map(|block| block.hash))
+            .chain(state.votor_finalized_chain.iter().map(|block| block.hash))
+            .collect();
+        let finalized = &finalized;
+        let lowest_view = state.votor_view.values().min().copied().unwrap_or(0);
+        
+        state.votor_generated_certs.values()
+            .flat_map(|certs| {
+                let skipped = certs.iter().any(|cert| cert.cert_type == CertificateType::Skip);
+                certs.iter().map(move |cert| {
+                    let resolution = if cert.cert_type != CertificateType::Skip && finalized.contains(&cert.block) {
+                        CertificateResolution::Finalized
+                    } else if skipped {
+                        CertificateResolution::Skipped
+                    } else if cert.view >= lowest_view {
+                        CertificateResolution::Pending
+                    } else {
+                        CertificateResolution::Orphaned
+                    };
+                    (cert, resolution)
+                }).collect::<Vec<_>>()
+            })
+            .collect()
+    }
+    
+    /// Certificate resolution: every commit certificate of a superseded view was finalized or skipped
+    pub fn certificate_resolution(state: &AlpenglowState, _config: &Config) -> bool {
+        certificate_resolutions(state).iter().all(|(_, resolution)| *resolution != CertificateResolution::Orphaned)
+    }
+    
+    /// Detailed version of certificate_resolution
+    pub fn certificate_resolution_detailed(state: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let resolutions = certificate_resolutions(state);
+        let count = |kind: CertificateResolution| resolutions.iter().filter(|(_, resolution)| *resolution == kind).count();
+        let orphaned: Vec<&Certificate> = resolutions.iter()
+            .filter(|(_, resolution)| *resolution == CertificateResolution::Orphaned)
+            .map(|(cert, _)| *cert)
+            .collect();
+        
+        let passed = orphaned.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = orphaned.iter()
+                .map(|cert| format!("{:?} certificate for block {} in view {} ({} stake)", cert.cert_type, cert.block, cert.view, cert.stake))
+                .collect();
+            Some(format!(
+                "Orphaned certificates ({} finalized, {} skipped, {} pending): {}",
+                count(CertificateResolution::Finalized),
+                count(CertificateResolution::Skipped),
+                count(CertificateResolution::Pending),
+                details.join(", ")
+            ))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(orphaned.len()) } else { None },
+        }
+    }
+    
+    /// Find (view, leader) for each view some voting validator is in that has no certificate yet
+    /// and whose leader has no timeout scheduled; an expired timeout can still fire and skip the view
+    fn leaders_without_active_timeout(state: &AlpenglowState, config: &Config) -> Vec<(ViewNumber, ValidatorId)> {
+        let active_views: BTreeSet<ViewNumber> = state.votor_view.iter()
+            .filter(|(validator, _)| !config.observers.contains(validator))
+            .map(|(_, view)| *view)
+            .collect();
+        
+        active_views.into_iter()
+            .filter(|view| state.votor_generated_certs.get(view).is_none_or(|certs| certs.is_empty()))
+            .map(|view| (view, utils::leader_for_view(config, view)))
+            .filter(|(_, leader)| !state.votor_timeout_expiry.contains_key(leader))
+            .collect()
+    }
+    
+    /// Leader has active timeout: a leader that fails to propose can always be timed out and skipped
+    pub fn leader_has_active_timeout(state: &AlpenglowState, config: &Config) -> bool {
+        leaders_without_active_timeout(state, config).is_empty()
+    }
+    
+    /// Detailed version of leader_has_active_timeout
+    pub fn leader_has_active_timeout_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let hanging = leaders_without_active_timeout(state, config);
+        
+        let passed = hanging.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = hanging.iter()
+                .map(|(view, leader)| format!("view {} leader {} has no timeout", view, leader))
+                .collect();
+            Some(format!("Views may hang on their leader: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(hanging.len()) } else { None },
+        }
+    }
+    
+    /// Stake of the validators that are honest and online
+    fn responsive_honest_stake(state: &AlpenglowState, config: &Config) -> StakeAmount {
+        config.stake_distribution.iter()
+            .filter(|(validator, _)| state.failure_states.get(validator).is_none_or(|status| *status == ValidatorStatus::Honest))
+            .map(|(_, stake)| *stake)
+            .sum()
+    }
+    
+    /// Why fast path liveness does not apply to `state`: before GST, or without enough responsive honest stake
+    fn fast_path_liveness_precondition(state: &AlpenglowState, config: &Config) -> Option<String> {
+        if state.clock < config.gst {
+            return Some(format!("clock {} is before GST {}", state.clock, config.gst));
+        }
+        let responsive_stake = responsive_honest_stake(state, config);
+        (responsive_stake < config.fast_path_threshold).then(|| format!(
+            "responsive honest stake {} is below the fast-path threshold {}",
+            responsive_stake, config.fast_path_threshold
+        ))
+    }
+    
+    /// Whether `view` has a certificate of `cert_type`
+    fn has_certificate(state: &AlpenglowState, view: ViewNumber, cert_type: CertificateType) -> bool {
+        state.votor_generated_certs.get(&view)
+            .is_some_and(|certs| certs.iter().any(|cert| cert.cert_type == cert_type))
+    }
+    
+    /// Fast path liveness: past GST with at least the fast-path threshold of responsive honest stake,
+    /// the current view is finalized through a fast certificate in a single round
+    pub fn fast_path_liveness(state: &AlpenglowState, config: &Config) -> bool {
+        fast_path_liveness_precondition(state, config).is_some() || has_certificate(state, state.current_view(), CertificateType::Fast)
+    }
+    
+    /// Detailed version of fast_path_liveness; explains the unmet precondition when it does not apply
+    pub fn fast_path_liveness_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let view = state.current_view();
+        let (passed, error) = match fast_path_liveness_precondition(state, config) {
+            Some(reason) => (true, Some(format!("Fast path liveness not applicable: {}", reason))),
+            None if has_certificate(state, view, CertificateType::Fast) => (true, None),
+            None => (false, Some(format!(
+                "No fast certificate for view {} at clock {} despite GST {} and responsive honest stake above the fast-path threshold",
+                view, state.clock, config.gst
+            ))),
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(1) } else { None },
+        }
+    }
+    
+    /// Why slow path liveness does not apply to `state`: before GST, or with responsive honest stake
+    /// below the slow-path threshold or enough for the fast path
+    fn slow_path_liveness_precondition(state: &AlpenglowState, config: &Config) -> Option<String> {
+        if state.clock < config.gst {
+            return Some(format!("clock {} is before GST {}", state.clock, config.gst));
+        }
+        let responsive_stake = responsive_honest_stake(state, config);
+        if responsive_stake < config.slow_path_threshold {
+            Some(format!(
+                "responsive honest stake {} is below the slow-path threshold {}",
+                responsive_stake, config.slow_path_threshold
+            ))
+        } else if responsive_stake >= config.fast_path_threshold {
+            Some(format!(
+                "responsive honest stake {} meets the fast-path threshold {}",
+                responsive_stake, config.fast_path_threshold
+            ))
+        } else {
+            None
+        }
+    }
+    
+    /// Slow path liveness: past GST with responsive honest stake between the slow- and fast-path
+    /// thresholds, the current view degrades to a slow certificate rather than stalling
+    pub fn slow_path_liveness(state: &AlpenglowState, config: &Config) -> bool {
+        slow_path_liveness_precondition(state, config).is_some() || has_certificate(state, state.current_view(), CertificateType::Slow)
+    }
+    
+    /// Detailed version of slow_path_liveness; explains the unmet precondition when it does not apply
+    pub fn slow_path_liveness_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let view = state.current_view();
+        let (passed, error) = match slow_path_liveness_precondition(state, config) {
+            Some(reason) => (true, Some(format!("Slow path liveness not applicable: {}", reason))),
+            None if has_certificate(state, view, CertificateType::Slow) => (true, None),
+            None => (false, Some(format!(
+                "No slow certificate for view {} at clock {} with {:.1}% of stake responsive",
+                view, state.clock, 100.0 * responsive_honest_stake(state, config) as f64 / config.total_stake.max(1) as f64
+            ))),
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(1) } else { None },
+        }
+    }
+    
+    /// Non-skip certificates whose block hash was never proposed or voted on by any validator
+    fn phantom_block_certificates(state: &AlpenglowState) -> Vec<&Certificate> {
+        let known_blocks: BTreeSet<BlockHash> = state.votor_voted_blocks.values()
+            .flat_map(|views| views.values())
+            .flat_map(|blocks| blocks.iter().map(|block| block.hash))
+            .collect();
+        
+        state.votor_generated_certs.values()
+            .flat_map(|certs| certs.iter())
+            .filter(|cert| cert.cert_type != CertificateType::Skip && !known_blocks.contains(&cert.block))
+            .collect()
+    }
+    
+    /// Certificates reference known blocks: no certificate certifies a block that was never proposed
+    pub fn certificate_references_known_block(state: &AlpenglowState, _config: &Config) -> bool {
+        phantom_block_certificates(state).is_empty()
+    }
+    
+    /// Detailed version of certificate_references_known_block
+    pub fn certificate_references_known_block_detailed(state: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let phantoms = phantom_block_certificates(state);
+        
+        let passed = phantoms.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = phantoms.iter()
+                .map(|cert| format!("{:?} cert in view {} for block {}", cert.cert_type, cert.view, cert.block))
+                .collect();
+            Some(format!("Certificates for unknown blocks: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(phantoms.len()) } else { None },
+        }
+    }
+    
+    /// Find validators whose clock offset exceeds `max_clock_skew` in either direction
+    fn excessive_clock_skews(state: &AlpenglowState, config: &Config) -> Vec<(ValidatorId, i64)> {
+        state.validator_clock_offset.iter()
+            .filter(|(_, offset)| offset.unsigned_abs() > config.max_clock_skew)
+            .map(|(validator, offset)| (*validator, *offset))
+            .collect()
+    }
+    
+    /// Clock skew bounded: every validator's local clock stays within max_clock_skew of the global clock
+    pub fn clock_skew_bounded(state: &AlpenglowState, config: &Config) -> bool {
+        excessive_clock_skews(state, config).is_empty()
+    }
+    
+    /// Detailed version of clock_skew_bounded
+    pub fn clock_skew_bounded_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let skews = excessive_clock_skews(state, config);
+        
+        let passed = skews.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = skews.iter()
+                .map(|(validator, offset)| format!("validator {} (offset {})", validator, offset))
+                .collect();
+            Some(format!("Clock skew beyond {}: {}", config.max_clock_skew, details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(skews.len()) } else { None },
+        }
+    }
+    
+    /// Stake registered in `stake_distribution` for a certificate's validators
+    fn registered_stake(cert: &Certificate, config: &Config) -> StakeAmount {
+        cert.validators.iter()
+            .map(|validator| config.stake_distribution.get(validator).copied().unwrap_or(0))
+            .sum()
+    }
+    
+    /// Find (certificate, registered stake) where the certificate's stake differs from the
+    /// stake registered in `stake_distribution` for its signers
+    fn misweighted_certificates<'a>(state: &'a AlpenglowState, config: &Config) -> Vec<(&'a Certificate, StakeAmount)> {
+        state.votor_generated_certs.values()
+            .flat_map(|certs| certs.iter())
+            .filter_map(|cert| {
+                let registered = registered_stake(cert, config);
+                (cert.stake != registered).then_some((cert, registered))
+            })
+            .collect()
+    }
+    
+    /// Stake integrity: every vote counted in a certificate contributes exactly its voter's registered stake
+    pub fn stake_integrity(state: &AlpenglowState, config: &Config) -> bool {
+        misweighted_certificates(state, config).is_empty()
+    }
+    
+    /// Detailed version of stake_integrity
+    pub fn stake_integrity_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let misweighted = misweighted_certificates(state, config);
+        
+        let passed = misweighted.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = misweighted.iter()
+                .map(|(cert, registered)| format!(
+                    "{:?} cert in view {} for block {} counts {} stake, signers registered {}",
+                    cert.cert_type, cert.view, cert.block, cert.stake, registered
+                ))
+                .collect();
+            Some(format!("Certificate stake does not match registered stake: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(misweighted.len()) } else { None },
+        }
+    }
+    
+    /// Certificates whose stake, signers or type disagree with their validator set, with the reason
+    fn inconsistent_certificates<'a>(state: &'a AlpenglowState, config: &Config) -> Vec<(&'a Certificate, String)> {
+        let mut inconsistent: Vec<_> = misweighted_certificates(state, config).into_iter()
+            .map(|(cert, registered)| (cert, format!("claims {} stake, validators hold {}", cert.stake, registered)))
+            .collect();
+        
+        for cert in state.votor_generated_certs.values().flatten() {
+            let registered = registered_stake(cert, config);
+            let threshold_met = match cert.cert_type {
+                CertificateType::Fast => registered >= config.fast_path_threshold,
+                CertificateType::Slow => registered >= config.slow_path_threshold && registered < config.fast_path_threshold,
+                CertificateType::Skip => registered >= config.slow_path_threshold,
+            };
+            
+            if cert.signatures.signers != cert.validators {
+                inconsistent.push((cert, format!("signers {:?} differ from validators {:?}", cert.signatures.signers, cert.validators)));
+            }
+            if !threshold_met {
+                inconsistent.push((cert, format!("validators hold {} stake, which does not match a {:?} certificate", registered, cert.cert_type)));
+            }
+        }
+        
+        inconsistent
+    }
+    
+    /// Valid certificates: every certificate's stake is the registered stake of its validators,
+    /// its signers are its validators, and its type is the one the stake qualifies for
+    pub fn safety_valid_certificates(state: &AlpenglowState, config: &Config) -> bool {
+        inconsistent_certificates(state, config).is_empty()
+    }
+    
+    /// Detailed version of safety_valid_certificates
+    pub fn safety_valid_certificates_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let inconsistent = inconsistent_certificates(state, config);
+        
+        let passed = inconsistent.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = inconsistent.iter()
+                .map(|(cert, reason)| format!("{:?} cert in view {} for block {} {}", cert.cert_type, cert.view, cert.block, reason))
+                .collect();
+            Some(format!("Inconsistent certificates: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(inconsistent.len()) } else { None },
+        }
+    }
+    
+    /// Rotor leader correct: `current_rotor` is the scheduled leader for the current view
+    pub fn rotor_leader_correct(state: &AlpenglowState, config: &Config) -> bool {
+        state.current_rotor == utils::leader_for_view(config, state.current_view())
+    }
+    
+    /// Detailed version of rotor_leader_correct
+    pub fn rotor_leader_correct_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let view = state.current_view();
+        let expected = utils::leader_for_view(config, view);
+        
+        let passed = state.current_rotor == expected;
+        let error = if !passed {
+            Some(format!(
+                "Relay leader in slot {} view {} is validator {}, expected validator {}",
+                state.current_slot, view, state.current_rotor, expected
+            ))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(1) } else { None },
+        }
+    }
+    
+    /// Find (validator, block, shred index) for every held shred not signed by its block's leader
+    fn forged_shreds(state: &AlpenglowState, config: &Config) -> Vec<(ValidatorId, BlockHash, u32)> {
+        state.rotor_block_shreds.iter()
+            .flat_map(|(block_id, holders)| holders.iter().map(move |(validator, pieces)| (block_id, validator, pieces)))
+            .flat_map(|(block_id, validator, pieces)| {
+                pieces.iter()
+                    .filter(|piece| !state.shred_authentic(config, piece))
+                    .map(move |piece| (*validator, *block_id, piece.index))
+            })
+            .collect()
+    }
+    
+    /// Shred signature valid: every shred a validator holds verifies against its block's leader
+    pub fn shred_signature_valid(state: &AlpenglowState, config: &Config) -> bool {
+        forged_shreds(state, config).is_empty()
+    }
+    
+    /// Detailed version of shred_signature_valid
+    pub fn shred_signature_valid_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let forged = forged_shreds(state, config);
+        
+        let passed = forged.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = forged.iter()
+                .map(|(validator, block_id, index)| format!("validator {} block {} shred {}", validator, block_id, index))
+                .collect();
+            Some(format!("Found {} shreds with invalid signatures: {}", forged.len(), details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(forged.len()) } else { None },
+        }
+    }
+    
+    /// Find (validator, view before, view after) where a validator's view went backward
+    fn view_regressions(previous: &AlpenglowState, next: &AlpenglowState) -> Vec<(ValidatorId, ViewNumber, ViewNumber)> {
+        previous.votor_view.iter()
+            .filter_map(|(validator, before)| {
+                let after = next.votor_view.get(validator).copied().unwrap_or(1);
+                (after < *before).then_some((*validator, *before, after))
+            })
+            .collect()
+    }
+    
+    /// View monotonic per validator: no validator's `votor_view` decreases from `previous` to `next`.
+    /// Unlike the other properties this relates a state to its successor, so it is checked per transition.
+    pub fn view_monotonic_per_validator(previous: &AlpenglowState, next: &AlpenglowState) -> bool {
+        view_regressions(previous, next).is_empty()
+    }
+    
+    /// Detailed version of view_monotonic_per_validator
+    pub fn view_monotonic_per_validator_detailed(previous: &AlpenglowState, next: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let regressions = view_regressions(previous, next);
+        
+        let passed = regressions.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = regressions.iter()
+                .map(|(validator, before, after)| format!("validator {} from view {} to {}", validator, before, after))
+                .collect();
+            Some(format!("View regressed: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 2,
+            error,
+            counterexample_length: if !passed { Some(regressions.len()) } else { None },
+        }
+    }
+    
+    /// Find (height, previous, block) where a finalized block does not extend its predecessor
+    /// in the finalized chain or does not advance its view
+    fn total_order_breaks(state: &AlpenglowState) -> Vec<(usize, &Block, &Block)> {
+        state.votor_finalized_chain.windows(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[1].parent != pair[0].hash || pair[1].view <= pair[0].view)
+            .map(|(height, pair)| (height + 1, &pair[0], &pair[1]))
+            .collect()
+    }
+    
+    /// Finalized total order: `votor_finalized_chain` is a strictly increasing, parent-linked sequence
+    pub fn finalized_total_order(state: &AlpenglowState, _config: &Config) -> bool {
+        total_order_breaks(state).is_empty()
+    }
+    
+    /// Detailed version of finalized_total_order
+    pub fn finalized_total_order_detailed(state: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let breaks = total_order_breaks(state);
+        
+        let passed = breaks.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = breaks.iter()
+                .map(|(height, previous, block)| format!(
+                    "height {} block {} (view {}, parent {}) does not extend block {} (view {})",
+                    height, block.hash, block.view, block.parent, previous.hash, previous.view
+                ))
+                .collect();
+            Some(format!("Finalized chain is not totally ordered: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(breaks.len()) } else { None },
+        }
+    }
+    
+    /// Find (certificate, signer, blocks the signer voted for in the certificate's view) where a
+    /// signer of a non-skip certificate has no received vote for the certified block
+    fn inconsistent_certificate_signers(state: &AlpenglowState) -> Vec<(&Certificate, ValidatorId, BTreeSet<BlockHash>)> {
+        let mut voted: BTreeMap<(ValidatorId, ViewNumber), BTreeSet<BlockHash>> = BTreeMap::new();
+        for views in state.votor_received_votes.values() {
+            for (view, votes) in views {
+                for vote in votes {
+                    voted.entry((vote.voter, *view)).or_default().insert(vote.block);
+                }
+            }
+        }
+        
+        state.votor_generated_certs.values()
+            .flat_map(|certs| certs.iter())
+            .filter(|cert| cert.cert_type != CertificateType::Skip)
+            .flat_map(|cert| {
+                let voted = &voted;
+                cert.validators.iter().filter_map(move |signer| {
+                    let blocks = voted.get(&(*signer, cert.view)).cloned().unwrap_or_default();
+                    (!blocks.contains(&cert.block)).then_some((cert, *signer, blocks))
+                })
+            })
+            .collect()
+    }
+    
+    /// Certificate signer vote consistency: every signer of a certificate voted for its block
+    pub fn certificate_signer_vote_consistency(state: &AlpenglowState, _config: &Config) -> bool {
+        inconsistent_certificate_signers(state).is_empty()
+    }
+    
+    /// Detailed version of certificate_signer_vote_consistency
+    pub fn certificate_signer_vote_consistency_detailed(state: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let inconsistent = inconsistent_certificate_signers(state);
+        
+        let passed = inconsistent.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = inconsistent.iter()
+                .map(|(cert, signer, blocks)| format!(
+                    "signer {} of the view {} certificate for block {} voted for {:?}",
+                    signer, cert.view, cert.block, blocks
+                ))
+                .collect();
+            Some(format!("Certificate signers did not vote for the certified block: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(inconsistent.len()) } else { None },
+        }
+    }
+    
+    /// Find (validator, view, committed blocks) where the validator also submitted a skip vote.
+    /// A leader's own proposal only counts as a commit if it also cast a commit vote for it.
+    fn skip_and_commit_conflicts(state: &AlpenglowState) -> Vec<(ValidatorId, ViewNumber, BTreeSet<BlockHash>)> {
+        let mut commits: BTreeMap<(ValidatorId, ViewNumber), BTreeSet<BlockHash>> = BTreeMap::new();
+        
+        for (validator, views) in &state.votor_voted_blocks {
+            for (view, blocks) in views {
+                for block in blocks.iter().filter(|block| block.proposer != *validator) {
+                    commits.entry((*validator, *view)).or_default().insert(block.hash);
+                }
+            }
+        }
+        for views in state.votor_received_votes.values() {
+            for (view, votes) in views {
+                for vote in votes.iter().filter(|vote| vote.vote_type == VoteType::Commit) {
+                    commits.entry((vote.voter, *view)).or_default().insert(vote.block);
+                }
+            }
+        }
+        
+        let mut conflicts = Vec::new();
+        for (validator, views) in &state.votor_skip_votes {
+            for (view, skip_votes) in views {
+                let skipped = skip_votes.iter().any(|vote| vote.voter == *validator);
+                if let Some(blocks) = commits.get(&(*validator, *view)).filter(|_| skipped) {
+                    conflicts.push((*validator, *view, blocks.clone()));
+                }
+            }
+        }
+        
+        conflicts
+    }
+    
+    /// No skip and commit in the same view: A validator never both skips and commits to a block in one view
+    pub fn no_skip_and_commit_same_view(state: &AlpenglowState, _config: &Config) -> bool {
+        skip_and_commit_conflicts(state).is_empty()
+    }
+    
+    /// Detailed version of no_skip_and_commit_same_view
+    pub fn no_skip_and_commit_same_view_detailed(state: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let conflicts = skip_and_commit_conflicts(state);
+        
+        let passed = conflicts.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = conflicts.iter()
+                .map(|(validator, view, blocks)| {
+                    format!("validator {} view {} skipped and committed to {:?}", validator, view, blocks)
+                })
+                .collect();
+            Some(format!("Protocol violation: skip and commit votes in the same view: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(conflicts.len()) } else { None },
+        }
+    }
+    
+    /// Honest validators that committed to more than one block hash in a view
+    fn double_votes(state: &AlpenglowState) -> Vec<(ValidatorId, ViewNumber, BTreeSet<BlockHash>)> {
+        let mut double_votes = Vec::new();
+        
+        for (validator, views) in &state.votor_voted_blocks {
+            if state.failure_states.get(validator) == Some(&ValidatorStatus::Byzantine) {
+                continue;
+            }
+            for (view, blocks) in views {
+                let hashes: BTreeSet<BlockHash> = blocks.iter().map(|block| block.hash).collect();
+                if hashes.len() > 1 {
+                    double_votes.push((*validator, *view, hashes));
+                }
+            }
+        }
+        
+        double_votes
+    }
+    
+    /// No double voting: No honest validator casts commit votes for two distinct blocks in one view
+    pub fn safety_no_double_voting(state: &AlpenglowState, _config: &Config) -> bool {
+        double_votes(state).is_empty()
+    }
+    
+    /// Detailed version of safety_no_double_voting
+    pub fn safety_no_double_voting_detailed(state: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let double_votes = double_votes(state);
+        
+        let passed = double_votes.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = double_votes.iter()
+                .map(|(validator, view, hashes)| format!("validator {} view {} voted for {:?}", validator, view, hashes))
+                .collect();
+            Some(format!("Honest validators voted for conflicting blocks: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        // Each pair of distinct blocks voted for in one view is a separate conflict
+        let conflicting_pairs: usize = double_votes.iter()
+            .map(|(_, _, hashes)| hashes.len() * (hashes.len() - 1) / 2)
+            .sum();
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(conflicting_pairs) } else { None },
+        }
+    }
+    
+    /// Find view advances taken before the timeout expired, or repeated at the same clock value,
+    /// without a skip certificate justifying them
+    fn premature_view_advances(state: &AlpenglowState) -> Vec<(ValidatorId, ViewAdvance, &'static str)> {
+        let mut premature = Vec::new();
+        
+        for (validator, advances) in &state.votor_view_advances {
+            let mut previous_clock = None;
+            for advance in advances {
+                if !advance.skip_certified {
+                    if advance.clock < advance.timeout_expiry {
+                        premature.push((*validator, advance.clone(), "before timeout expiry"));
+                    } else if previous_clock == Some(advance.clock) {
+                        premature.push((*validator, advance.clone(), "twice at the same clock"));
+                    }
+                }
+                previous_clock = Some(advance.clock);
+            }
+        }
+        
+        premature
+    }
+    
+    /// No premature view advance: Validators only leave a view after its timeout expires or with a skip certificate
+    pub fn no_premature_view_advance(state: &AlpenglowState, _config: &Config) -> bool {
+        premature_view_advances(state).is_empty()
+    }
+    
+    /// Detailed version of no_premature_view_advance
+    pub fn no_premature_view_advance_detailed(state: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let premature = premature_view_advances(state);
+        
+        let passed = premature.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = premature.iter()
+                .map(|(validator, advance, reason)| {
+                    format!(
+                        "validator {} left view {} at clock {} (expiry {}) {}",
+                        validator, advance.from_view, advance.clock, advance.timeout_expiry, reason
+                    )
+                })
+                .collect();
+            Some(format!("Premature view advances: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(premature.len()) } else { None },
+        }
+    }
+    
+    /// Find finalized blocks that a majority of honest validators had not received via Rotor
+    /// by the time they were finalized, with the number delivered in time and the honest count
+    fn late_rotor_deliveries(state: &AlpenglowState) -> Vec<(BlockHash, TimeValue, usize, usize)> {
+        let honest: Vec<ValidatorId> = state.failure_states.iter()
+            .filter(|(_, status)| matches!(status, ValidatorStatus::Honest))
+            .map(|(validator, _)| *validator)
+            .collect();
+        if honest.is_empty() {
+            return Vec::new();
+        }
+        
+        state.finalization_times.iter()
+            .filter_map(|(block, finalized_at)| {
+                let delivered_in_time = honest.iter()
+                    .filter(|validator| {
+                        state.rotor_delivery_times.get(validator)
+                            .and_then(|times| times.get(block))
+                            .is_some_and(|delivered_at| delivered_at <= finalized_at)
+                    })
+                    .count();
+                
+                if delivered_in_time * 2 > honest.len() {
+                    None
+                } else {
+                    Some((*block, *finalized_at, delivered_in_time, honest.len()))
+                }
+            })
+            .collect()
+    }
+    
+    /// Rotor delivery before finalization: A majority of honest validators received each finalized block by its finalization time
+    pub fn rotor_delivery_before_finalization(state: &AlpenglowState, _config: &Config) -> bool {
+        late_rotor_deliveries(state).is_empty()
+    }
+    
+    /// Detailed version of rotor_delivery_before_finalization
+    pub fn rotor_delivery_before_finalization_detailed(state: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let late = late_rotor_deliveries(state);
+        
+        let passed = late.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = late.iter()
+                .map(|(block, finalized_at, delivered, honest)| {
+                    format!("block {} finalized at {} with {} of {} honest deliveries", block, finalized_at, delivered, honest)
+                })
+                .collect();
+            Some(format!("Blocks finalized before Rotor delivery: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(late.len()) } else { None },
+        }
+    }
+    
+    /// Count distinct shred indices in `pieces` that are valid erasure coded pieces of `block_id`
+    pub fn distinct_valid_shred_count(pieces: &BTreeSet<ErasureCodedPiece>, block_id: BlockHash, config: &Config) -> usize {
+        pieces.iter()
+            .filter(|piece| {
+                piece.block_id == block_id &&
+                piece.index >= 1 && piece.index <= config.n &&
+                piece.is_parity == (piece.index > config.k)
+            })
+            .map(|piece| piece.index)
+            .collect::<BTreeSet<_>>()
+            .len()
+    }
+    
+    /// Find (validator, block) pairs delivered without K distinct valid shreds
+    fn under_supported_reconstructions(state: &AlpenglowState, config: &Config) -> Vec<(ValidatorId, BlockHash, usize)> {
+        let empty = BTreeSet::new();
+        let mut violations = Vec::new();
+        
+        for (validator, delivered) in &state.rotor_delivered_blocks {
+            for block_id in delivered {
+                let pieces = state.rotor_block_shreds.get(block_id)
+                    .and_then(|shreds| shreds.get(validator))
+                    .unwrap_or(&empty);
+                let distinct = distinct_valid_shred_count(pieces, *block_id, config);
+                if distinct < config.k as usize {
+                    violations.push((*validator, *block_id, distinct));
+                }
+            }
+        }
+        
+        violations
+    }
+    
+    /// Reconstruction soundness: Every reconstructed block was backed by K distinct valid shreds
+    pub fn reconstruction_requires_k_distinct(state: &AlpenglowState, config: &Config) -> bool {
+        under_supported_reconstructions(state, config).is_empty()
+    }
+    
+    /// Detailed version of reconstruction_requires_k_distinct
+    pub fn reconstruction_requires_k_distinct_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let violations = under_supported_reconstructions(state, config);
+        
+        let passed = violations.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = violations.iter()
+                .map(|(validator, block_id, distinct)| {
+                    format!("validator {} block {} ({} of {} shreds)", validator, block_id, distinct, config.k)
+                })
+                .collect();
+            Some(format!("Found {} reconstructions without K distinct shreds: {}", violations.len(), details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(violations.len()) } else { None },
+        }
+    }
+    
+    /// Describe how the configured total stake and thresholds disagree with the stake distribution
+    fn stake_conservation_violations(config: &Config) -> Vec<String> {
+        let mut violations = Vec::new();
+        let distributed: StakeAmount = config.stake_distribution.values().sum();
+        
+        if config.total_stake != distributed {
+            violations.push(format!("total_stake {} != distributed stake {}", config.total_stake, distributed));
+        }
+        if config.fast_path_threshold > config.total_stake {
+            violations.push(format!("fast_path_threshold {} exceeds total_stake {}", config.fast_path_threshold, config.total_stake));
+        }
+        if config.slow_path_threshold > config.fast_path_threshold {
+            violations.push(format!("slow_path_threshold {} exceeds fast_path_threshold {}", config.slow_path_threshold, config.fast_path_threshold));
+        }
+        if config.slow_path_threshold * 2 <= config.total_stake {
+            violations.push(format!("slow_path_threshold {} is not a majority of total_stake {}", config.slow_path_threshold, config.total_stake));
+        }
+        
+        violations
+    }
+    
+    /// Total stake conservation: total_stake equals the sum of stakes and thresholds stay consistent with it
+    pub fn total_stake_conserved(_state: &AlpenglowState, config: &Config) -> bool {
+        stake_conservation_violations(config).is_empty()
+    }
+    
+    /// Detailed version of total_stake_conserved
+    pub fn total_stake_conserved_detailed(_state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let violations = stake_conservation_violations(config);
+        
+        let passed = violations.is_empty();
+        let error = if !passed {
+            Some(format!("Total stake not conserved: {}", violations.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(violations.len()) } else { None },
+        }
+    }
+    
+    /// Shreds of the most recently distributed block assigned to `validator` to relay
+    pub fn relay_shreds_assigned(state: &AlpenglowState, validator: ValidatorId) -> usize {
+        state.rotor_relay_assignments.get(&validator).map_or(0, Vec::len)
+    }
+    
+    /// Pearson correlation between validator stake and number of relay-assigned shreds
+    pub fn relay_stake_correlation(state: &AlpenglowState, config: &Config) -> Option<f64> {
+        let samples: Vec<(f64, f64)> = config.stake_distribution.iter()
+            .map(|(validator, stake)| {
+                let assigned = relay_shreds_assigned(state, *validator);
+                (*stake as f64, assigned as f64)
+            })
+            .collect();
+        
+        let count = samples.len() as f64;
+        if count < 2.0 {
+            return None;
+        }
+        
+        let mean_stake = samples.iter().map(|(stake, _)| stake).sum::<f64>() / count;
+        let mean_assigned = samples.iter().map(|(_, assigned)| assigned).sum::<f64>() / count;
+        let covariance: f64 = samples.iter()
+            .map(|(stake, assigned)| (stake - mean_stake) * (assigned - mean_assigned))
+            .sum();
+        let stake_variance: f64 = samples.iter().map(|(stake, _)| (stake - mean_stake).powi(2)).sum();
+        let assigned_variance: f64 = samples.iter().map(|(_, assigned)| (assigned - mean_assigned).powi(2)).sum();
+        
+        if stake_variance == 0.0 || assigned_variance == 0.0 {
+            return None;
+        }
+        
+        Some(covariance / (stake_variance.sqrt() * assigned_variance.sqrt()))
+    }
+    
+    /// Find (higher-stake, lower-stake) validator pairs where the higher-stake one was assigned fewer shreds.
+    /// Stake-weighted rounding can cost a validator one shred, so a deficit of one is tolerated.
+    fn relay_stake_inversions(state: &AlpenglowState, config: &Config) -> Vec<(ValidatorId, ValidatorId, usize, usize)> {
+        let tolerance = 1;
+        let assigned = |validator: &ValidatorId| relay_shreds_assigned(state, *validator);
+        
+        let mut inversions = Vec::new();
+        for (high, high_stake) in &config.stake_distribution {
+            for (low, low_stake) in &config.stake_distribution {
+                if high_stake > low_stake && assigned(high) + tolerance < assigned(low) {
+                    inversions.push((*high, *low, assigned(high), assigned(low)));
+                }
+            }
+        }
+        
+        inversions
+    }
+    
+    /// Validators with nonzero stake whose leader count over views 1..=current view strays from
+    /// their stake share by more than three binomial standard deviations (plus one window) of
+    /// the number of leader windows they lead, scaled to views
+    fn leader_coverage_deviations(state: &AlpenglowState, config: &Config) -> Vec<(ValidatorId, usize, f64)> {
+        let views = state.votor_view.values().copied().max().unwrap_or(1);
+        if config.total_stake == 0 {
+            return Vec::new();
+        }
+        
+        let mut led: BTreeMap<ValidatorId, usize> = BTreeMap::new();
+        for view in 1..=views {
+            *led.entry(utils::leader_for_view(config, view)).or_default() += 1;
+        }
+        
+        let window = config.leader_window_size.max(1) as f64;
+        let windows = (views as f64 / window).ceil();
+        config.stake_distribution.iter()
+            .filter(|(_, stake)| **stake > 0)
+            .filter_map(|(validator, stake)| {
+                let share = *stake as f64 / config.total_stake as f64;
+                let expected = views as f64 * share;
+                let tolerance = window * (3.0 * (windows * share * (1.0 - share)).sqrt() + 1.0);
+                let observed = led.get(validator).copied().unwrap_or(0);
+                ((observed as f64 - expected).abs() > tolerance).then_some((*validator, observed, expected))
+            })
+            .collect()
+    }
+    
+    /// Leader coverage: every staked validator leads views in proportion to its stake
+    pub fn leader_coverage(state: &AlpenglowState, config: &Config) -> bool {
+        leader_coverage_deviations(state, config).is_empty()
+    }
+    
+    /// Detailed version of leader_coverage
+    pub fn leader_coverage_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let deviations = leader_coverage_deviations(state, config);
+        
+        let passed = deviations.is_empty();
+        let error = if !passed {
+            let views = state.votor_view.values().copied().max().unwrap_or(1);
+            let details: Vec<String> = deviations.iter()
+                .map(|(validator, observed, expected)| {
+                    format!("validator {} led {} views (expected {:.1})", validator, observed, expected)
+                })
+                .collect();
+            Some(format!("Leader selection not proportional to stake over views 1..={}: {}", views, details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(deviations.len()) } else { None },
+        }
+    }
+    
+    /// Fast certificate generated for the same block, slot and view as `certificate`
+    pub fn matching_fast_certificate<'a>(state: &'a AlpenglowState, certificate: &Certificate) -> Option<&'a Certificate> {
+        state.votor_generated_certs.get(&certificate.view)?
+            .iter()
+            .find(|cert| {
+                cert.cert_type == CertificateType::Fast &&
+                cert.block == certificate.block &&
+                cert.slot == certificate.slot &&
+                cert.view == certificate.view
+            })
+    }
+    
+    /// Blocks finalized through the slow path although a slow certificate for them has a
+    /// matching fast certificate, as (block, slot, view) of that fast certificate
+    fn slow_path_finalizations_with_fast_cert(state: &AlpenglowState, config: &Config) -> Vec<(BlockHash, SlotNumber, ViewNumber)> {
+        if !config.prefer_fast_path {
+            return Vec::new();
+        }
+        
+        state.finalization_paths.iter()
+            .filter(|(_, path)| **path == CertificateType::Slow)
+            .filter_map(|(block, _)| {
+                state.votor_generated_certs.values()
+                    .flat_map(|certs| certs.iter())
+                    .filter(|cert| cert.block == *block && cert.cert_type == CertificateType::Slow)
+                    .find_map(|slow| matching_fast_certificate(state, slow))
+                    .map(|fast| (fast.block, fast.slot, fast.view))
+            })
+            .collect()
+    }
+    
+    /// Fast path preference: no block is finalized via the slow path when its slow certificate
+    /// has a fast certificate for the same block, slot and view
+    pub fn prefers_fast_path(state: &AlpenglowState, config: &Config) -> bool {
+        slow_path_finalizations_with_fast_cert(state, config).is_empty()
+    }
+    
+    /// Detailed version of prefers_fast_path
+    pub fn prefers_fast_path_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let blocks = slow_path_finalizations_with_fast_cert(state, config);
+        
+        let passed = blocks.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = blocks.iter()
+                .map(|(block, slot, view)| format!("block {} (slot {}, view {})", block, slot, view))
+                .collect();
+            Some(format!("Blocks finalized via slow path despite a matching fast certificate: {}", details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(blocks.len()) } else { None },
+        }
+    }
+    
+    /// Relay stake weighting: Higher-stake validators are not assigned fewer shreds than lower-stake ones
+    pub fn relay_stake_weighting(state: &AlpenglowState, config: &Config) -> bool {
+        relay_stake_inversions(state, config).is_empty()
+    }
+    
+    /// Detailed version of relay_stake_weighting
+    pub fn relay_stake_weighting_detailed(state: &AlpenglowState, config: &Config) -> PropertyCheckResult {
+        let inversions = relay_stake_inversions(state, config);
+        
+        let passed = inversions.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = inversions.iter()
+                .map(|(high, low, high_count, low_count)| {
+                    format!("validator {} ({} shreds) < validator {} ({} shreds)", high, high_count, low, low_count)
+                })
+                .collect();
+            let correlation = relay_stake_correlation(state, config)
+                .map_or("n/a".to_string(), |c| format!("{:.2}", c));
+            Some(format!("Relay assignments not stake-weighted (correlation {}): {}", correlation, details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(inversions.len()) } else { None },
+        }
+    }
+    
+    /// Collect every vote observed in the state, grouped by voter
+    fn votes_by_voter(state: &AlpenglowState) -> BTreeMap<ValidatorId, BTreeSet<Vote>> {
+        let mut votes_by_voter: BTreeMap<ValidatorId, BTreeSet<Vote>> = BTreeMap::new();
+        
+        let received = state.votor_received_votes.values()
+            .flat_map(|view_votes| view_votes.values())
+            .flat_map(|votes| votes.iter());
+        let skipped = state.votor_skip_votes.values()
+            .flat_map(|view_votes| view_votes.values())
+            .flat_map(|votes| votes.iter());
+        
+        for vote in received.chain(skipped) {
+            votes_by_voter.entry(vote.voter).or_default().insert(vote.clone());
+        }
+        
+        votes_by_voter
+    }
+    
+    /// Find validators whose votes, ordered by view, have decreasing timestamps
+    fn vote_timestamp_violations(state: &AlpenglowState) -> Vec<(ValidatorId, ViewNumber, TimeValue, TimeValue)> {
+        let mut violations = Vec::new();
+        
+        for (voter, votes) in votes_by_voter(state) {
+            let mut ordered: Vec<_> = votes.into_iter().collect();
+            ordered.sort_by_key(|vote| (vote.view, vote.timestamp));
+            
+            for pair in ordered.windows(2) {
+                if pair[1].view > pair[0].view && pair[1].timestamp < pair[0].timestamp {
+                    violations.push((voter, pair[1].view, pair[0].timestamp, pair[1].timestamp));
+                }
+            }
+        }
+        
+        violations
+    }
+    
+    /// Vote timestamp monotonicity: No validator votes in a later view with an earlier timestamp
+    pub fn vote_timestamp_monotonic(state: &AlpenglowState) -> bool {
+        vote_timestamp_violations(state).is_empty()
+    }
+    
+    /// Detailed version of vote_timestamp_monotonic
+    pub fn vote_timestamp_monotonic_detailed(state: &AlpenglowState, _config: &Config) -> PropertyCheckResult {
+        let violations = vote_timestamp_violations(state);
+        
+        let passed = violations.is_empty();
+        let error = if !passed {
+            let details: Vec<String> = violations.iter()
+                .map(|(voter, view, previous, current)| {
+                    format!("validator {} view {} timestamp {} < {}", voter, view, current, previous)
+                })
+                .collect();
+            Some(format!("Found {} non-monotonic vote timestamps: {}", violations.len(), details.join(", ")))
+        } else {
+            None
+        };
+        
+        PropertyCheckResult {
+            passed,
+            states_explored: 1,
+            error,
+            counterexample_length: if !passed { Some(violations.len()) } else { None },
+        }
+    }
+}
+
+/// Utilities for cross-validation and testing
+pub mod utils {
+    use super::*;
+    
+    /// Mix `value` with `seed` (SplitMix64 finalizer). Unlike `DefaultHasher` the output is
+    /// fixed across Rust versions and platforms, which keeps seeded runs reproducible.
+    pub fn seeded_hash(seed: u64, value: u64) -> u64 {
+        let mut z = (seed ^ value.wrapping_mul(0x9E37_79B9_7F4A_7C15)).wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    
+    /// Stake-weighted leader of `view`; every view of a leader window has the same leader
+    pub fn leader_for_view(config: &Config, view: ViewNumber) -> ValidatorId {
+        leader_window(config, view).0
+    }
+    
+    /// Leader of the window containing `view` and the views of that window. Views 1 through
+    /// `leader_window_size` form the first window, the next `leader_window_size` views the
+    /// second, and so on. With `vrf_enabled` the leader is the winner of `vrf_leader_election`
+    /// over the window's first view, otherwise a seeded deterministic hash of the window index.
+    pub fn leader_window(config: &Config, view: ViewNumber) -> (ValidatorId, std::ops::Range<ViewNumber>) {
+        let size = config.leader_window_size.max(1) as ViewNumber;
+        let window = view.saturating_sub(1) / size;
+        let views = window * size + 1..(window + 1) * size + 1;
+        
+        if config.vrf_enabled {
+            return (vrf_leader_election(config, views.start).validator, views);
+        }
+        
+        let total_stake = config.total_stake;
+        if total_stake == 0 {
+            return (0, views);
+        }
+        
+        let target = seeded_hash(config.seed, window) % total_stake;
+        
+        let mut cumulative_stake = 0;
+        
+        for validator in 0..config.validator_count {
+            let validator_id = validator as ValidatorId;
+            let stake = config.stake_distribution.get(&validator_id).copied().unwrap_or(0);
+            cumulative_stake += stake;
+            if cumulative_stake > target {
+                return (validator_id, views);
+            }
+        }
+        
+        (0, views) // Fallback
+    }
+    
+    /// Leader of `view` with that leader's VRF proof over the first view of the leader window.
+    /// The proof is marked invalid when `vrf_enabled` is off, since the hash-selected leader
+    /// does not follow from it.
+    pub fn leader_with_proof(config: &Config, view: ViewNumber) -> (ValidatorId, VRFProof) {
+        let (leader, views) = leader_window(config, view);
+        let mut proof = votor::vrf_prove(&vrf_key_pair(config, leader), views.start);
+        proof.valid = config.vrf_enabled;
+        (leader, proof)
+    }
+    
+    /// Whether `proof` shows its validator to be the VRF-selected leader of `view`: the proof
+    /// verifies under that validator's public key, is over the window's first view, and wins
+    /// the election for that window
+    pub fn verify_leader_proof(config: &Config, view: ViewNumber, proof: &VRFProof) -> bool {
+        let (_, views) = leader_window(config, view);
+        config.vrf_enabled
+            && proof.input == views.start
+            && votor::vrf_verify(&vrf_key_pair(config, proof.validator), proof)
+            && vrf_leader_election(config, views.start) == *proof
+    }
+    
+    /// Proof of the validator winning the VRF election over `input`: every staked validator
+    /// evaluates the VRF and the lowest `votor::vrf_weighted_value` wins, so each validator
+    /// wins with probability proportional to its stake
+    fn vrf_leader_election(config: &Config, input: u64) -> VRFProof {
+        let mut winner: Option<(f64, VRFProof)> = None;
+        for validator in 0..config.validator_count as ValidatorId {
+            let stake = config.stake_distribution.get(&validator).copied().unwrap_or(0);
+            if stake == 0 {
+                continue;
+            }
+            let proof = votor::vrf_prove(&vrf_key_pair(config, validator), input);
+            let rank = votor::vrf_weighted_value(proof.output, stake);
+            if winner.as_ref().is_none_or(|(best, _)| rank < *best) {
+                winner = Some((rank, proof));
+            }
+        }
+        winner.map(|(_, proof)| proof).unwrap_or_else(|| votor::vrf_prove(&vrf_key_pair(config, 0), input))
+    }
+    
+    /// VRF key pair of `validator` under the config seed
+    pub fn vrf_key_pair(config: &Config, validator: ValidatorId) -> VRFKeyPair {
+        votor::vrf_key_pair(validator, config.seed)
+    }
+    
+    /// Number of most recently sent delivered messages `observed_mean_delay` averages over
+    pub const DELAY_SAMPLE_WINDOW: usize = 16;
+    
+    /// Moving average of the delivery delays of the last `DELAY_SAMPLE_WINDOW` delivered
+    /// messages, in send order; 0 when nothing has been delivered
+    pub fn observed_mean_delay(state: &AlpenglowState) -> TimeValue {
+        let mut delays: Vec<(u64, TimeValue)> = state.network_delivery_time.iter()
+            .map(|(message, delay)| (message.id, *delay))
+            .collect();
+        delays.sort_unstable();
+        let recent = &delays[delays.len().saturating_sub(DELAY_SAMPLE_WINDOW)..];
+        if recent.is_empty() {
+            return 0;
+        }
+        recent.iter().map(|(_, delay)| delay).sum::<TimeValue>() / recent.len() as TimeValue
+    }
+    
+    /// Stable FNV-1a hash of `bytes`, seeded with `seed`
+    pub fn stable_hash(seed: u64, bytes: &[u8]) -> u64 {
+        fnv1a(0xcbf2_9ce4_8422_2325 ^ seed, bytes)
+    }
+    
+    fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+        bytes.iter().fold(hash, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3))
+    }
+    
+    /// `Hasher` that feeds everything written, integers as little-endian bytes, through
+    /// `stable_hash` and mixes the result with `seeded_hash`. Unlike `DefaultHasher` its output
+    /// does not depend on the Rust version or platform, so hashes can be persisted.
+    #[derive(Debug, Clone)]
+    pub struct StableHasher(u64);
+    
+    impl StableHasher {
+        pub fn new(seed: u64) -> Self {
+            Self(stable_hash(seed, &[]))
+        }
+    }
+    
+    impl std::hash::Hasher for StableHasher {
+        fn finish(&self) -> u64 {
+            seeded_hash(0, self.0)
+        }
+        
+        fn write(&mut self, bytes: &[u8]) {
+            self.0 = fnv1a(self.0, bytes);
+        }
+        
+        fn write_u16(&mut self, value: u16) {
+            self.write(&value.to_le_bytes());
+        }
+        
+        fn write_u32(&mut self, value: u32) {
+            self.write(&value.to_le_bytes());
+        }
+        
+        fn write_u64(&mut self, value: u64) {
+            self.write(&value.to_le_bytes());
+        }
+        
+        fn write_u128(&mut self, value: u128) {
+            self.write(&value.to_le_bytes());
+        }
+        
+        fn write_usize(&mut self, value: usize) {
+            self.write_u64(value as u64);
+        }
+    }
+    
+    /// Bytes `pieces` occupy on the wire: one full word per field element of their data
+    pub fn shred_bytes<'a>(pieces: impl IntoIterator<Item = &'a ErasureCodedPiece>) -> u64 {
+        pieces.into_iter().map(|piece| (piece.data.len() * std::mem::size_of::<u64>()) as u64).sum()
+    }
+    
+    /// Signature `signer` places on `piece`, covering every field except the signature itself
+    pub fn shred_signature(config: &Config, signer: ValidatorId, piece: &ErasureCodedPiece) -> Signature {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&piece.block_id.to_le_bytes());
+        bytes.extend_from_slice(&piece.index.to_le_bytes());
+        bytes.extend_from_slice(&piece.total_pieces.to_le_bytes());
+        bytes.push(piece.is_parity as u8);
+        for word in &piece.data {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        stable_hash(seeded_hash(config.seed, signer as u64), &bytes)
+    }
+    
+    /// Generate test configurations for various scenarios
+    pub fn test_configs() -> Vec<Config> {
+        vec![
+            Config::new().with_validators(3),
             Config::new().with_validators(4),
             Config::new().with_validators(7),
             Config::new().with_validators(10),
         ]
     }
     
-    /// Create a configuration with Byzantine validators
-    pub fn byzantine_config(total_validators: usize, byzantine_count: usize) -> Config {
-        Config::new()
-            .with_validators(total_validators)
-            .with_byzantine_threshold(byzantine_count)
+    /// Create a configuration with Byzantine validators
+    pub fn byzantine_config(total_validators: usize, byzantine_count: usize) -> Config {
+        Config::new()
+            .with_validators(total_validators)
+            .with_byzantine_threshold(byzantine_count)
+    }
+    
+    /// Create a configuration with unequal stake distribution
+    pub fn unequal_stake_config() -> Config {
+        let mut stakes = BTreeMap::new();
+        stakes.insert(0, 4000); // 40% stake
+        stakes.insert(1, 3000); // 30% stake
+        stakes.insert(2, 2000); // 20% stake
+        stakes.insert(3, 1000); // 10% stake
+        
+        Config::new()
+            .with_validators(4)
+            .with_stake_distribution(stakes)
+    }
+    
+    /// Create test scenario with Byzantine validators
+    pub fn create_byzantine_scenario(
+        config: &Config,
+        byzantine_validators: &[ValidatorId],
+    ) -> AlpenglowResult<AlpenglowModel> {
+        let mut model = AlpenglowModel::new(config.clone());
+        
+        // Mark specified validators as Byzantine
+        for &validator in byzantine_validators {
+            if validator < config.validator_count as ValidatorId {
+                model.state.failure_states.insert(validator, ValidatorStatus::Byzantine);
+            }
+        }
+        
+        Ok(model)
+    }
+    
+    /// Create test scenario with network partitions
+    pub fn create_network_partition_scenario(
+        config: &Config,
+        partitions: Vec<BTreeSet<ValidatorId>>,
+    ) -> AlpenglowResult<AlpenglowModel> {
+        let mut model = AlpenglowModel::new(config.clone());
+        
+        // Add network partitions
+        for partition in partitions {
+            model.state.network_partitions.insert(partition);
+        }
+        
+        Ok(model)
+    }
+    
+    /// Create test scenario with offline validators
+    pub fn create_offline_scenario(
+        config: &Config,
+        offline_validators: &[ValidatorId],
+    ) -> AlpenglowResult<AlpenglowModel> {
+        let mut model = AlpenglowModel::new(config.clone());
+        
+        // Mark specified validators as offline
+        for &validator in offline_validators {
+            if validator < config.validator_count as ValidatorId {
+                model.state.failure_states.insert(validator, ValidatorStatus::Offline);
+            }
+        }
+        
+        Ok(model)
+    }
+    
+    /// Create stress test scenario with high network activity
+    pub fn create_stress_test_scenario(config: &Config) -> AlpenglowResult<AlpenglowModel> {
+        let mut model = AlpenglowModel::new(config.clone());
+        
+        // Add multiple concurrent proposals
+        for validator in 0..config.validator_count {
+            let validator_id = validator as ValidatorId;
+            let current_view = model.state.votor_view.get(&validator_id).copied().unwrap_or(1);
+            
+            // Create test blocks for stress testing
+            let test_block = Block {
+                slot: model.state.current_slot,
+                view: current_view,
+                hash: (validator_id as u64) * 1000 + current_view,
+                parent: 0,
+                proposer: validator_id,
+                transactions: BTreeSet::new(),
+                timestamp: model.state.clock,
+                signature: validator_id as u64,
+                data: vec![],
+            };
+            
+            model.state.votor_voted_blocks
+                .entry(validator_id)
+                .or_default()
+                .entry(current_view)
+                .or_default()
+                .insert(test_block);
+        }
+        
+        Ok(model)
+    }
+    
+    /// Create adversarial scenario combining multiple attack vectors
+    pub fn create_adversarial_scenario(
+        config: &Config,
+        byzantine_validators: &[ValidatorId],
+        offline_validators: &[ValidatorId],
+        network_partitions: Vec<BTreeSet<ValidatorId>>,
+    ) -> AlpenglowResult<AlpenglowModel> {
+        let mut model = AlpenglowModel::new(config.clone());
+        
+        // Mark Byzantine validators
+        for &validator in byzantine_validators {
+            if validator < config.validator_count as ValidatorId {
+                model.state.failure_states.insert(validator, ValidatorStatus::Byzantine);
+            }
+        }
+        
+        // Mark offline validators
+        for &validator in offline_validators {
+            if validator < config.validator_count as ValidatorId {
+                model.state.failure_states.insert(validator, ValidatorStatus::Offline);
+            }
+        }
+        
+        // Add network partitions
+        for partition in network_partitions {
+            model.state.network_partitions.insert(partition);
+        }
+        
+        Ok(model)
+    }
+    
+    /// Create scenario for testing economic incentives
+    pub fn create_economic_test_scenario(config: &Config) -> AlpenglowResult<AlpenglowModel> {
+        let mut model = AlpenglowModel::new(config.clone());
+        
+        // Create certificates with different stake amounts for testing thresholds
+        let test_cert_fast = Certificate {
+            slot: 1,
+            view: 1,
+            block: 123,
+            cert_type: CertificateType::Fast,
+            validators: (0..config.validator_count as ValidatorId).collect(),
+            stake: config.fast_path_threshold,
+            signatures: AggregatedSignature {
+                signers: (0..config.validator_count as ValidatorId).collect(),
+                message: 123,
+                signatures: (0..config.validator_count as ValidatorId).map(|v| v as u64).collect(),
+                valid: true,
+            },
+        };
+        
+        let test_cert_slow = Certificate {
+            slot: 2,
+            view: 2,
+            block: 456,
+            cert_type: CertificateType::Slow,
+            validators: (0..((config.validator_count * 2) / 3) as ValidatorId).collect(),
+            stake: config.slow_path_threshold,
+            signatures: AggregatedSignature {
+                signers: (0..((config.validator_count * 2) / 3) as ValidatorId).collect(),
+                message: 456,
+                signatures: (0..((config.validator_count * 2) / 3) as ValidatorId).map(|v| v as u64).collect(),
+                valid: true,
+            },
+        };
+        
+        model.state.votor_generated_certs.entry(1).or_default().insert(test_cert_fast);
+        model.state.votor_generated_certs.entry(2).or_default().insert(test_cert_slow);
+        
+        Ok(model)
+    }
+    
+    /// Create scenario for testing VRF leader selection
+    pub fn create_vrf_test_scenario(config: &Config) -> AlpenglowResult<AlpenglowModel> {
+        let mut model = AlpenglowModel::new(config.clone());
+        
+        // Test leader selection across multiple views
+        for view in 1..=10 {
+            let leader = model.compute_leader_for_view(view);
+            
+            // Create a test block from the selected leader
+            let test_block = Block {
+                slot: view,
+                view,
+                hash: view * 1000 + leader as u64,
+                parent: if view > 1 { (view - 1) * 1000 } else { 0 },
+                proposer: leader,
+                transactions: BTreeSet::new(),
+                timestamp: model.state.clock + view,
+                signature: leader as u64,
+                data: vec![],
+            };
+            
+            model.state.votor_voted_blocks
+                .entry(leader)
+                .or_default()
+                .entry(view)
+                .or_default()
+                .insert(test_block);
+        }
+        
+        Ok(model)
+    }
+    
+    /// Create scenario for testing adaptive timeouts
+    pub fn create_adaptive_timeout_scenario(config: &Config) -> AlpenglowResult<AlpenglowModel> {
+        let mut model = AlpenglowModel::new(config.clone());
+        
+        // Set up different timeout states for validators
+        for validator in 0..config.validator_count {
+            let validator_id = validator as ValidatorId;
+            let view = (validator + 1) as ViewNumber;
+            
+            // Set different views and timeout expiries
+            model.state.votor_view.insert(validator_id, view);
+            let timeout = model.calculate_timeout(model.state.clock, view);
+            model.state.votor_timeout_expiry.insert(validator_id, timeout);
+        }
+        
+        Ok(model)
+    }
+    
+    /// Render a trace as a Mermaid `sequenceDiagram` with one participant per validator.
+    /// Broadcasts become one arrow per recipient; Byzantine actions are highlighted in red.
+    pub fn trace_to_mermaid(trace: &[AlpenglowAction], config: &Config) -> String {
+        let validators: Vec<ValidatorId> = (0..config.validator_count as ValidatorId).collect();
+        let everyone = match (validators.first(), validators.last()) {
+            (Some(first), Some(last)) if first != last => format!("V{},V{}", first, last),
+            (Some(first), _) => format!("V{}", first),
+            _ => String::new(),
+        };
+        
+        let mut lines = vec!["sequenceDiagram".to_string()];
+        for validator in &validators {
+            lines.push(format!("    participant V{} as Validator {}", validator, validator));
+        }
+        
+        let broadcast = |lines: &mut Vec<String>, from: ValidatorId, arrow: &str, label: String| {
+            for to in validators.iter().filter(|to| **to != from) {
+                lines.push(format!("    V{}{}V{}: {}", from, arrow, to, label));
+            }
+        };
+        let note = |lines: &mut Vec<String>, over: String, label: String| {
+            lines.push(format!("    Note over {}: {}", over, label));
+        };
+        
+        for action in trace {
+            match action {
+                // Clock ticks carry no messages
+                AlpenglowAction::AdvanceClock => {},
+                AlpenglowAction::AdvanceSlot => note(&mut lines, everyone.clone(), "advance slot".to_string()),
+                AlpenglowAction::AdvanceView { validator } => {
+                    note(&mut lines, format!("V{}", validator), "advance view".to_string());
+                },
+                AlpenglowAction::SkewClock { validator, offset } => {
+                    note(&mut lines, format!("V{}", validator), format!("clock offset {}", offset));
+                },
+                AlpenglowAction::Votor(votor_action) => match votor_action {
+                    VotorAction::ProposeBlock { validator, view } => {
+                        broadcast(&mut lines, *validator, "->>", format!("propose view {}", view));
+                    },
+                    VotorAction::CastVote { validator, block, view } => {
+                        broadcast(&mut lines, *validator, "->>", format!("vote block {} view {}", block.hash, view));
+                    },
+                    VotorAction::CollectVotes { validator, view } => {
+                        note(&mut lines, format!("V{}", validator), format!("collect votes view {}", view));
+                    },
+                    VotorAction::FinalizeBlock { validator, certificate } => {
+                        broadcast(&mut lines, *validator, "->>", format!(
+                            "{:?} cert slot {} block {}", certificate.cert_type, certificate.slot, certificate.block
+                        ));
+                    },
+                    VotorAction::SubmitSkipVote { validator, view } => {
+                        broadcast(&mut lines, *validator, "->>", format!("skip vote view {}", view));
+                    },
+                    VotorAction::CollectSkipVotes { validator, view } => {
+                        note(&mut lines, format!("V{}", validator), format!("collect skip votes view {}", view));
+                    },
+                    VotorAction::Timeout { validator } => {
+                        note(&mut lines, format!("V{}", validator), "timeout".to_string());
+                    },
+                },
+                AlpenglowAction::Rotor(rotor_action) => match rotor_action {
+                    RotorAction::ShredAndDistribute { leader, block } => {
+                        broadcast(&mut lines, *leader, "-)", format!("shreds block {}", block.hash));
+                    },
+                    RotorAction::RelayShreds { validator, block_id } => {
+                        broadcast(&mut lines, *validator, "-)", format!("relay shreds block {}", block_id));
+                    },
+                    RotorAction::AttemptReconstruction { validator, block_id } => {
+                        note(&mut lines, format!("V{}", validator), format!("reconstruct block {}", block_id));
+                    },
+                    RotorAction::RequestRepair { validator, block_id } => {
+                        broadcast(&mut lines, *validator, "-->>", format!("repair request block {}", block_id));
+                    },
+                    RotorAction::RespondToRepair { validator, request } => {
+                        lines.push(format!("    V{}-)V{}: repair block {}", validator, request.requester, request.block_id));
+                    },
+                },
+                AlpenglowAction::Network(network_action) => match network_action {
+                    NetworkAction::DeliverMessage { message } | NetworkAction::DropMessage { message } => {
+                        let arrow = if matches!(network_action, NetworkAction::DropMessage { .. }) { "-x" } else { "->>" };
+                        let label = format!("{:?} #{}", message.msg_type, message.id);
+                        match message.recipient {
+                            MessageRecipient::Validator(to) => {
+                                lines.push(format!("    V{}{}V{}: {}", message.sender, arrow, to, label));
+                            },
+                            MessageRecipient::Broadcast => broadcast(&mut lines, message.sender, arrow, label),
+                        }
+                    },
+                    NetworkAction::DelayMessage { message, delay } => {
+                        note(&mut lines, format!("V{}", message.sender), format!("{:?} #{} delayed {}", message.msg_type, message.id, delay));
+                    },
+                    NetworkAction::PartitionNetwork { partition } => {
+                        let members: Vec<String> = partition.iter().map(|v| v.to_string()).collect();
+                        note(&mut lines, everyone.clone(), format!("partition {{{}}}", members.join(", ")));
+                    },
+                    NetworkAction::HealPartition => note(&mut lines, everyone.clone(), "heal partition".to_string()),
+                },
+                AlpenglowAction::Byzantine(byzantine_action) => {
+                    lines.push("    rect rgb(255, 210, 210)".to_string());
+                    match byzantine_action {
+                        ByzantineAction::DoubleVote { validator, view } => {
+                            broadcast(&mut lines, *validator, "--x", format!("Byzantine double vote view {}", view));
+                        },
+                        ByzantineAction::InvalidBlock { validator } => {
+                            broadcast(&mut lines, *validator, "--x", "Byzantine invalid block".to_string());
+                        },
+                        ByzantineAction::WithholdShreds { validator } => {
+                            note(&mut lines, format!("V{}", validator), "Byzantine withholds shreds".to_string());
+                        },
+                        ByzantineAction::Equivocate { validator } => {
+                            broadcast(&mut lines, *validator, "--x", "Byzantine equivocation".to_string());
+                        },
+                        ByzantineAction::DelayMessages { validator, delay } => {
+                            note(&mut lines, format!("V{}", validator), format!("Byzantine delays messages by {}", delay));
+                        },
+                    }
+                    lines.push("    end".to_string());
+                },
+            }
+        }
+        
+        lines.join("\n")
+    }
+    
+    /// A trace step that could not be applied under one or both configs
+    #[derive(Debug, Clone)]
+    pub struct TraceDivergence {
+        pub step: usize,
+        pub action: AlpenglowAction,
+        pub enabled_in_a: bool,
+        pub enabled_in_b: bool,
+    }
+    
+    /// Certificate types used to finalize a block under each config
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CertificatePathDifference {
+        pub block: BlockHash,
+        pub path_a: Option<CertificateType>,
+        pub path_b: Option<CertificateType>,
+    }
+    
+    /// A property whose result differs between the two configs
+    #[derive(Debug, Clone)]
+    pub struct PropertyDifference {
+        pub property: String,
+        pub status_a: PropertyStatus,
+        pub status_b: PropertyStatus,
+    }
+    
+    /// Outcome of replaying one trace under two configs
+    #[derive(Debug, Clone)]
+    pub struct ConfigComparison {
+        /// Steps skipped in a config where they were not enabled
+        pub divergence_points: Vec<TraceDivergence>,
+        pub finalized_chain_a: Vec<BlockHash>,
+        pub finalized_chain_b: Vec<BlockHash>,
+        pub certificate_path_differences: Vec<CertificatePathDifference>,
+        pub property_differences: Vec<PropertyDifference>,
+    }
+    
+    impl ConfigComparison {
+        /// Whether both configs finalized the same chain
+        pub fn chains_match(&self) -> bool {
+            self.finalized_chain_a == self.finalized_chain_b
+        }
+        
+        /// Whether the configs behaved identically on the trace
+        pub fn is_equivalent(&self) -> bool {
+            self.divergence_points.is_empty()
+                && self.chains_match()
+                && self.certificate_path_differences.is_empty()
+                && self.property_differences.is_empty()
+        }
+    }
+    
+    /// Replay `trace` under both configs and compare finalized chains, certificate paths
+    /// and property results. Steps not enabled under a config are skipped there and
+    /// reported as divergence points.
+    pub fn compare_configs(config_a: Config, config_b: Config, trace: &[AlpenglowAction]) -> ConfigComparison {
+        let mut model_a = AlpenglowModel::new(config_a.clone());
+        let mut model_b = AlpenglowModel::new(config_b.clone());
+        let mut divergence_points = Vec::new();
+        
+        for (step, action) in trace.iter().enumerate() {
+            let apply = |model: &mut AlpenglowModel| {
+                match model.execute_action(action.clone()) {
+                    Ok(state) => {
+                        model.state = state;
+                        true
+                    },
+                    Err(_) => false,
+                }
+            };
+            let enabled_in_a = apply(&mut model_a);
+            let enabled_in_b = apply(&mut model_b);
+            
+            if !(enabled_in_a && enabled_in_b) {
+                divergence_points.push(TraceDivergence {
+                    step,
+                    action: action.clone(),
+                    enabled_in_a,
+                    enabled_in_b,
+                });
+            }
+        }
+        
+        // The path each block was finalized through, as recorded by the model
+        let paths_a = &model_a.state.finalization_paths;
+        let paths_b = &model_b.state.finalization_paths;
+        let blocks: BTreeSet<BlockHash> = paths_a.keys().chain(paths_b.keys()).copied().collect();
+        let certificate_path_differences = blocks.into_iter()
+            .map(|block| CertificatePathDifference {
+                block,
+                path_a: paths_a.get(&block).cloned(),
+                path_b: paths_b.get(&block).cloned(),
+            })
+            .filter(|difference| difference.path_a != difference.path_b)
+            .collect();
+        
+        let property_results = |config: Config, state: &AlpenglowState| {
+            let checker = RichModelChecker::new(config);
+            let mut results = checker.check_all_safety_properties(state).0;
+            results.extend(checker.check_all_liveness_properties(state).0);
+            results
+        };
+        let results_a = property_results(config_a, &model_a.state);
+        let results_b = property_results(config_b, &model_b.state);
+        let mut property_differences: Vec<PropertyDifference> = results_a.iter()
+            .filter_map(|(name, result_a)| {
+                let result_b = results_b.get(name)?;
+                (result_a.status != result_b.status).then(|| PropertyDifference {
+                    property: name.clone(),
+                    status_a: result_a.status.clone(),
+                    status_b: result_b.status.clone(),
+                })
+            })
+            .collect();
+        property_differences.sort_by(|a, b| a.property.cmp(&b.property));
+        
+        ConfigComparison {
+            divergence_points,
+            finalized_chain_a: model_a.state.votor_finalized_chain.iter().map(|b| b.hash).collect(),
+            finalized_chain_b: model_b.state.votor_finalized_chain.iter().map(|b| b.hash).collect(),
+            certificate_path_differences,
+            property_differences,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_config_creation() {
+        let config = Config::new().with_validators(4);
+        assert_eq!(config.validator_count, 4);
+        assert_eq!(config.byzantine_threshold, 1);
+        assert!(config.validate().is_ok());
+    }
+    
+    #[test]
+    fn test_config_validation() {
+        let invalid_config = Config {
+            validator_count: 0,
+            ..Default::default()
+        };
+        assert!(invalid_config.validate().is_err());
+    }
+    
+    #[test]
+    fn test_validator_statuses() {
+        // Five validators of 200 stake each: 20% Byzantine plus 20% offline is the most tolerated
+        let statuses = BTreeMap::from([(1, ValidatorStatus::Byzantine), (3, ValidatorStatus::Offline)]);
+        let config = Config::new().with_validators(5).with_byzantine_threshold(0).with_validator_statuses(statuses);
+        assert!(config.validate().is_ok());
+        
+        let state = AlpenglowState::init(&config);
+        assert_eq!(state.failure_states[&1], ValidatorStatus::Byzantine);
+        assert_eq!(state.failure_states[&3], ValidatorStatus::Offline);
+        for validator in [0, 2, 4] {
+            assert_eq!(state.failure_states[&validator], ValidatorStatus::Honest);
+        }
+        
+        let mut over = config.validator_statuses.clone();
+        over.insert(4, ValidatorStatus::Offline);
+        assert!(config.clone().with_validator_statuses(over).validate().is_err());
+        let two_byzantine = BTreeMap::from([(0, ValidatorStatus::Byzantine), (1, ValidatorStatus::Byzantine)]);
+        assert!(config.clone().with_validator_statuses(two_byzantine).validate().is_err());
+        assert!(config.with_validator_statuses(BTreeMap::from([(5, ValidatorStatus::Offline)])).validate().is_err());
+    }
+    
+    #[test]
+    fn test_stake_thresholds() {
+        let config = Config::new().with_validators(4);
+        assert!(config.fast_path_threshold > config.slow_path_threshold);
+        assert!(config.slow_path_threshold > config.total_stake / 2);
+    }
+    
+    #[test]
+    fn test_model_creation() {
+        let config = Config::new().with_validators(3);
+        let model = create_model(config);
+        assert!(model.is_ok());
+    }
+    
+    #[test]
+    fn test_alpenglow_state_init() {
+        let config = Config::new().with_validators(3);
+        let state = AlpenglowState::init(&config);
+        
+        assert_eq!(state.clock, 0);
+        assert_eq!(state.current_slot, 1);
+        assert_eq!(state.votor_view.len(), 3);
+        assert!(state.votor_finalized_chain.is_empty());
+    }
+    
+    #[test]
+    fn test_leader_selection() {
+        let config = Config::new().with_validators(4);
+        let model = AlpenglowModel::new(config);
+        
+        // Test deterministic leader selection
+        let leader1 = model.compute_leader_for_view(1);
+        let leader2 = model.compute_leader_for_view(1);
+        assert_eq!(leader1, leader2);
+        
+        // Different views may have different leaders
+        let leader_view2 = model.compute_leader_for_view(2);
+        // Leaders can be the same or different, but selection should be deterministic
+        assert_eq!(model.compute_leader_for_view(2), leader_view2);
+    }
+    
+    #[test]
+    fn test_leader_windows() {
+        let config = Config::new().with_validators(4).with_leader_window_size(4);
+        let model = AlpenglowModel::new(config.clone());
+        
+        for window in 0..50u64 {
+            let first = window * 4 + 1;
+            let (leader, views) = model.compute_leader_window(first);
+            assert_eq!(views, first..first + 4);
+            for view in views.clone() {
+                assert_eq!(model.compute_leader_window(view), (leader, views.clone()));
+                assert_eq!(model.compute_leader_for_view(view), leader);
+                assert!(model.is_leader_for_view(leader, view));
+            }
+        }
+        
+        // Windows are drawn independently, so the leader changes at some window boundary
+        assert!((1..50u64).any(|window| model.compute_leader_for_view(window * 4) != model.compute_leader_for_view(window * 4 + 1)));
+        
+        // A window of one view is per-view selection
+        let model = AlpenglowModel::new(config.with_leader_window_size(1));
+        assert_eq!(model.compute_leader_window(7), (model.compute_leader_for_view(7), 7..8));
+    }
+    
+    #[test]
+    fn test_vrf_leader_proof() {
+        let config = Config::new().with_validators(4).with_vrf_enabled(true);
+        let model = AlpenglowModel::new(config.clone());
+        
+        for view in 1..=40 {
+            let (leader, proof) = model.compute_leader_with_proof(view);
+            assert_eq!(leader, model.compute_leader_for_view(view));
+            assert_eq!(proof.validator, leader);
+            assert!(votor::vrf_verify(&utils::vrf_key_pair(&config, leader), &proof));
+            assert!(utils::verify_leader_proof(&config, view, &proof));
+        }
+        
+        // Tampering with any part of the proof is detected
+        let (leader, proof) = model.compute_leader_with_proof(1);
+        let tampered = [
+            VRFProof { output: proof.output ^ 1, ..proof.clone() },
+            VRFProof { proof: proof.proof ^ 1, ..proof.clone() },
+            VRFProof { input: proof.input + 1, ..proof.clone() },
+            VRFProof { public_key: proof.public_key ^ 1, ..proof.clone() },
+        ];
+        for proof in &tampered {
+            assert!(!utils::verify_leader_proof(&config, 1, proof), "accepted {:?}", proof);
+        }
+        
+        // A valid proof by a validator that lost the election does not make it leader
+        let other = (leader + 1) % 4;
+        let key_pair = utils::vrf_key_pair(&config, other);
+        let loser = votor::vrf_prove(&key_pair, proof.input);
+        assert!(votor::vrf_verify(&key_pair, &loser));
+        assert!(!utils::verify_leader_proof(&config, 1, &loser));
+        
+        // Selection follows stake and the seed
+        let led = (1..=400).step_by(config.leader_window_size)
+            .filter(|view| model.compute_leader_for_view(*view) == 0)
+            .count();
+        assert!((10..=40).contains(&led), "validator 0 led {} of 100 windows", led);
+        let reseeded = AlpenglowModel::new(config.clone().with_seed(config.seed + 1));
+        assert!((1..=40).any(|view| reseeded.compute_leader_for_view(view) != model.compute_leader_for_view(view)));
+        
+        // Without VRF the hash-selected leader's proof does not justify the selection
+        let model = AlpenglowModel::new(config.with_vrf_enabled(false));
+        let (_, proof) = model.compute_leader_with_proof(1);
+        assert!(!proof.valid);
+        assert!(!utils::verify_leader_proof(&model.config, 1, &proof));
+    }
+    
+    #[test]
+    fn test_erasure_encoding() {
+        let config = Config::new().with_validators(4).with_erasure_coding(2, 4);
+        let model = AlpenglowModel::new(config);
+        
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 123,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 456,
+            data: vec![],
+        };
+        
+        let shreds = model.erasure_encode(&block);
+        assert_eq!(shreds.len(), 4);
+        
+        // Check data shreds
+        let data_shreds: Vec<_> = shreds.iter().filter(|s| !s.is_parity).collect();
+        assert_eq!(data_shreds.len(), 2);
+        assert!(data_shreds.iter().all(|s| s.index <= 2));
+        
+        // Check parity shreds
+        let parity_shreds: Vec<_> = shreds.iter().filter(|s| s.is_parity).collect();
+        assert_eq!(parity_shreds.len(), 2);
+        assert!(parity_shreds.iter().all(|s| s.index > 2));
+    }
+    
+    #[test]
+    fn test_erasure_coding_round_trip() {
+        let config = Config::new().with_validators(4).with_erasure_coding(3, 6);
+        let model = AlpenglowModel::new(config);
+        let block = Block {
+            slot: 2,
+            view: 3,
+            hash: 77,
+            parent: 41,
+            proposer: 1,
+            transactions: (0..5).map(|id| Transaction { id, sender: 2, data: vec![id * 1000, u64::MAX - id], signature: id + 9 }).collect(),
+            timestamp: 12,
+            signature: 99,
+            data: vec![5, 6, 7],
+        };
+        let shreds = model.erasure_encode(&block);
+        assert_eq!(shreds.len(), 6);
+        
+        // Any three of the six shreds reconstruct the original block
+        for mask in 0u32..(1 << 6) {
+            if mask.count_ones() != 3 {
+                continue;
+            }
+            let pieces: BTreeSet<ErasureCodedPiece> = shreds.iter()
+                .filter(|shred| mask & (1 << (shred.index - 1)) != 0)
+                .cloned()
+                .collect();
+            assert_eq!(model.reconstruct_block(&pieces).unwrap(), block, "shreds {:06b}", mask);
+        }
+        
+        // Fewer than K distinct pieces are rejected
+        let pieces: BTreeSet<ErasureCodedPiece> = shreds.iter().take(2).cloned().collect();
+        assert!(model.reconstruct_block(&pieces).is_err());
+    }
+    
+    #[test]
+    fn test_action_execution() {
+        let config = Config::new().with_validators(3);
+        let model = AlpenglowModel::new(config);
+        
+        // Test clock advancement
+        let new_state = model.execute_action(AlpenglowAction::AdvanceClock).unwrap();
+        assert_eq!(new_state.clock, 1);
+        
+        // Test view advancement
+        let validator = 0;
+        let new_state = model.execute_action(AlpenglowAction::AdvanceView { validator }).unwrap();
+        assert_eq!(new_state.votor_view.get(&validator).copied().unwrap_or(1), 2);
+    }
+    
+    #[test]
+    fn test_safety_properties() {
+        let config = Config::new().with_validators(3);
+        let state = AlpenglowState::init(&config);
+        
+        assert!(properties::safety_no_conflicting_finalization(&state));
+        assert!(properties::chain_consistency(&state));
+        assert!(properties::bandwidth_safety(&state, &config));
+        assert!(properties::erasure_coding_validity(&state, &config));
+    }
+    
+    #[test]
+    fn test_model_checker() {
+        let config = Config::new().with_validators(3);
+        let model = AlpenglowModel::new(config.clone());
+        let mut checker = ModelChecker::new(config);
+        
+        let metrics = checker.verify_model(&model).unwrap();
+        assert!(metrics.properties_checked > 0);
+        assert_eq!(metrics.violations, 0);
+    }
+    
+    #[test]
+    fn test_model_checker_explores_reachable_states() {
+        let config = Config::new().with_validators(4).with_byzantine_threshold(0).with_exploration_depth(4);
+        let mut checker = ModelChecker::new(config.clone());
+        let metrics = checker.verify_model(&AlpenglowModel::new(config)).unwrap();
+        assert!(metrics.states_explored > 1);
+        assert!(metrics.states_explored <= checker.max_states);
+        // Nothing reached breaks a safety or Byzantine property; liveness is only checked at the root
+        assert!(metrics.property_results.iter()
+            .filter(|r| ModelChecker::property_info(&r.name).is_some_and(|info| info.category != PropertyCategory::Liveness))
+            .all(|r| r.passed && r.states_explored == metrics.states_explored));
+        
+        // Zero timeouts let validator 0 leave two views at the same clock, a premature advance
+        let mut config = Config::new().with_validators(4).with_byzantine_threshold(0).with_exploration_depth(4);
+        config.timeout_delta = 0;
+        let mut checker = ModelChecker::new(config.clone());
+        let metrics = checker.verify_model(&AlpenglowModel::new(config)).unwrap();
+        let premature = metrics.property_results.iter().find(|r| r.name == "no_premature_view_advance").unwrap();
+        assert!(!premature.passed);
+        let advance = AlpenglowAction::AdvanceView { validator: 0 };
+        assert_eq!(premature.counterexample, vec![advance.clone(), advance]);
+        assert_eq!(premature.counterexample_length, Some(3));
+        
+        // The state bound is respected
+        checker.set_max_states(5);
+        let metrics = checker.verify_model(&AlpenglowModel::new(checker.config.clone())).unwrap();
+        assert_eq!(metrics.states_explored, 5);
+    }
+    
+    #[test]
+    fn test_property_detailed_results() {
+        let config = Config::new().with_validators(3);
+        let state = AlpenglowState::init(&config);
+        
+        let result = properties::safety_no_conflicting_finalization_detailed(&state, &config);
+        assert!(result.passed);
+        assert!(result.error.is_none());
+        
+        let result = properties::liveness_eventual_progress_detailed(&state, &config);
+        assert!(!result.passed); // No blocks finalized yet
+        assert!(result.error.is_some());
+    }
+    
+    #[test]
+    fn test_config_json_conversion() {
+        let config = Config::new().with_validators(4);
+        let json_value = serde_json::to_value(&config).unwrap();
+        let converted_config = Config::try_from(json_value).unwrap();
+        assert_eq!(config, converted_config);
+    }
+    
+    #[test]
+    fn test_vrf_election_follows_unequal_stake() {
+        let mut config = Config::new().with_validators(2).with_leader_window_size(1).with_vrf_enabled(true);
+        config.stake_distribution = BTreeMap::from([(0, 1), (1, 3)]);
+        config.total_stake = 4;
+        
+        // The validator with a quarter of the stake leads about a quarter of the views
+        let led = (1..=4000).filter(|view| utils::leader_for_view(&config, *view) == 0).count();
+        assert!((900..=1100).contains(&led), "validator 0 led {} of 4000 views", led);
+        
+        // A validator without stake never leads
+        config.stake_distribution.insert(0, 0);
+        assert!((1..=200).all(|view| utils::leader_for_view(&config, view) == 1));
+    }
+    
+    #[test]
+    fn test_tla_constants_cover_rotor_and_votor_parameters() {
+        let mut config = Config::new().with_validators(4);
+        config.delta = 50;
+        config.max_network_delay = 200;
+        config.leader_window_size = 8;
+        config.adaptive_timeouts = false;
+        config.vrf_enabled = true;
+        let constants = config.to_tla_constants().unwrap();
+        
+        assert_eq!(constants["Delta"], serde_json::json!(50));
+        assert_eq!(constants["MaxNetworkDelay"], serde_json::json!(200));
+        assert_eq!(constants["ErasureCodingRate"], serde_json::json!(config.erasure_coding_rate));
+        assert_eq!(constants["LeaderWindowSize"], serde_json::json!(8));
+        assert_eq!(constants["AdaptiveTimeouts"], serde_json::json!(false));
+        assert_eq!(constants["VRFEnabled"], serde_json::json!(true));
+    }
+    
+    #[test]
+    fn test_byzantine_scenario_creation() {
+        let config = Config::new().with_validators(4);
+        let byzantine_validators = vec![0, 1];
+        let model = utils::create_byzantine_scenario(&config, &byzantine_validators).unwrap();
+        
+        assert_eq!(
+            model.state.failure_states.get(&0),
+            Some(&ValidatorStatus::Byzantine)
+        );
+        assert_eq!(
+            model.state.failure_states.get(&1),
+            Some(&ValidatorStatus::Byzantine)
+        );
+    }
+    
+    #[test]
+    fn test_network_partition_scenario() {
+        let config = Config::new().with_validators(4);
+        let partition1: BTreeSet<ValidatorId> = [0, 1].iter().cloned().collect();
+        let partition2: BTreeSet<ValidatorId> = [2, 3].iter().cloned().collect();
+        let partitions = vec![partition1.clone(), partition2];
+        
+        let model = utils::create_network_partition_scenario(&config, partitions).unwrap();
+        assert!(model.state.network_partitions.contains(&partition1));
+    }
+    
+    #[test]
+    fn test_config_builder_methods() {
+        let config = Config::new()
+            .with_validators(5)
+            .with_exploration_depth(2000)
+            .with_timeout(60000)
+            .with_test_mode(true)
+            .with_leader_window_size(8)
+            .with_adaptive_timeouts(false)
+            .with_vrf_enabled(false);
+        
+        assert_eq!(config.validator_count, 5);
+        assert_eq!(config.exploration_depth, 2000);
+        assert_eq!(config.verification_timeout_ms, 60000);
+        assert!(config.test_mode);
+        assert_eq!(config.leader_window_size, 8);
+        assert!(!config.adaptive_timeouts);
+        assert!(!config.vrf_enabled);
+    }
+    
+    #[test]
+    fn test_byzantine_resilience() {
+        let config = Config::new().with_validators(4).with_byzantine_threshold(1);
+        let mut state = AlpenglowState::init(&config);
+        
+        // Mark one validator as Byzantine
+        state.failure_states.insert(0, ValidatorStatus::Byzantine);
+        
+        assert!(properties::byzantine_resilience(&state, &config));
+        
+        // Mark too many validators as Byzantine
+        state.failure_states.insert(1, ValidatorStatus::Byzantine);
+        assert!(!properties::byzantine_resilience(&state, &config));
+    }
+    
+    #[test]
+    fn test_byzantine_stake_resilience() {
+        let config = utils::unequal_stake_config();
+        let mut state = AlpenglowState::init(&config);
+        assert!(properties::byzantine_stake_resilience(&state, &config));
+        
+        // One of four validators is under a third by count, but its 40% stake is not
+        state.failure_states.insert(0, ValidatorStatus::Byzantine);
+        assert!(properties::byzantine_resilience(&state, &config));
+        assert!(!properties::byzantine_stake_resilience(&state, &config));
+        let result = properties::byzantine_stake_resilience_detailed(&state, &config);
+        assert!(result.error.unwrap().contains("4000 of 10000"));
+        
+        // Tolerating one Byzantine validator is only safe by stake if the largest holds under a third
+        let stakes = (0..7).map(|v| (v, if v == 0 { 4000 } else { 1000 })).collect();
+        let config = Config::new().with_validators(7).with_stake_distribution(stakes).with_byzantine_threshold(1);
+        assert_eq!(config.worst_case_byzantine_stake(), 4000);
+        assert!(config.validate().is_ok());
+        assert!(config.with_byzantine_stake_check(true).validate().is_err());
+    }
+    
+    #[test]
+    fn test_vote_timestamp_monotonic() {
+        let config = Config::new().with_validators(3);
+        let mut state = AlpenglowState::init(&config);
+        
+        let vote = |view: ViewNumber, timestamp: TimeValue| Vote {
+            voter: 1,
+            slot: view,
+            view,
+            block: view,
+            vote_type: VoteType::Commit,
+            signature: 1,
+            timestamp,
+        };
+        
+        state.votor_received_votes.entry(0).or_default().entry(1).or_default().insert(vote(1, 10));
+        state.votor_received_votes.entry(0).or_default().entry(2).or_default().insert(vote(2, 20));
+        assert!(properties::vote_timestamp_monotonic_detailed(&state, &config).passed);
+        
+        // Validator 1 votes in view 3 with a timestamp earlier than its view 2 vote
+        state.votor_received_votes.entry(2).or_default().entry(3).or_default().insert(vote(3, 5));
+        let result = properties::vote_timestamp_monotonic_detailed(&state, &config);
+        assert!(!result.passed);
+        assert_eq!(result.counterexample_length, Some(1));
+        assert!(result.error.unwrap().contains("validator 1 view 3"));
+    }
+    
+    #[test]
+    fn test_reconstruction_requires_k_distinct_shreds() {
+        let config = Config::new().with_validators(4).with_erasure_coding(2, 4);
+        let mut model = AlpenglowModel::new(config.clone());
+        let block_id = 42;
+        let validator = 1;
+        
+        let shred = |index: u32, data: u64| {
+            let mut piece = ErasureCodedPiece {
+                block_id,
+                index,
+                total_pieces: config.n,
+                data: vec![block_id, data],
+                is_parity: index > config.k,
+                signature: 0,
+            };
+            piece.signature = utils::shred_signature(&config, 0, &piece);
+            piece
+        };
+        model.state.rotor_shred_signers.insert(block_id, 0);
+        
+        // K copies of the same index cannot be decoded
+        let copies: BTreeSet<_> = (0..config.k as u64).map(|data| shred(1, data)).collect();
+        assert_eq!(copies.len(), config.k as usize);
+        model.state.rotor_block_shreds.entry(block_id).or_default().insert(validator, copies);
+        let reconstruct = AlpenglowAction::Rotor(RotorAction::AttemptReconstruction { validator, block_id });
+        assert!(!model.action_enabled(&reconstruct));
+        
+        // A delivery recorded without distinct shreds violates the invariant
+        let mut forged = model.state.clone();
+        forged.rotor_delivered_blocks.entry(validator).or_default().insert(block_id);
+        let result = properties::reconstruction_requires_k_distinct_detailed(&forged, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("validator 1 block 42 (1 of 2 shreds)"));
+        
+        // K distinct indices of the encoded block allow reconstruction and satisfy the invariant
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: block_id,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        let encoded = model.erasure_encode(&block);
+        let distinct: BTreeSet<_> = encoded.into_iter().filter(|piece| piece.index == 1 || piece.index == 3).collect();
+        model.state.rotor_block_shreds.get_mut(&block_id).unwrap().insert(validator, distinct);
+        assert!(model.action_enabled(&reconstruct));
+        let new_state = model.execute_action(reconstruct).unwrap();
+        assert!(new_state.rotor_delivered_blocks[&validator].contains(&block_id));
+        assert!(new_state.rotor_reconstructed_blocks[&validator].contains(&block));
+        assert!(properties::reconstruction_requires_k_distinct_detailed(&new_state, &config).passed);
+    }
+    
+    #[test]
+    fn test_relay_stake_weighting() {
+        // Ten shreds split exactly along the 40/30/20/10 stakes
+        let config = utils::unequal_stake_config().with_erasure_coding(2, 10);
+        let mut model = AlpenglowModel::new(config.clone());
+        let blocks = 20;
+        
+        for hash in 1..=blocks {
+            let block = Block {
+                slot: hash,
+                view: hash,
+                hash,
+                parent: hash - 1,
+                proposer: 0,
+                transactions: BTreeSet::new(),
+                timestamp: 0,
+                signature: 0,
+                data: vec![],
+            };
+            let action = AlpenglowAction::Rotor(RotorAction::ShredAndDistribute { leader: 0, block });
+            model.state = model.execute_action(action).unwrap();
+        }
+        
+        // Each distribution replaces the previous block's assignments
+        let assigned = |v: ValidatorId| properties::relay_shreds_assigned(&model.state, v);
+        let total: usize = (0..4).map(assigned).sum();
+        assert_eq!(total, config.n as usize);
+        
+        // The 40%-stake validator carries at least its share and more than any lower-stake validator
+        assert!(assigned(0) as f64 / total as f64 >= 0.4);
+        assert!(assigned(0) > assigned(1));
+        assert!(assigned(1) >= assigned(2));
+        assert!(assigned(2) >= assigned(3));
+        assert!(properties::relay_stake_correlation(&model.state, &config).unwrap() > 0.8);
+        assert!(properties::relay_stake_weighting_detailed(&model.state, &config).passed);
+        
+        // Inverting the assignments so the 10% validator relays the most is detected
+        let mut inverted = model.state.clone();
+        let top = inverted.rotor_relay_assignments.remove(&0).unwrap();
+        let bottom = inverted.rotor_relay_assignments.remove(&3).unwrap();
+        inverted.rotor_relay_assignments.insert(0, bottom);
+        inverted.rotor_relay_assignments.insert(3, top);
+        let result = properties::relay_stake_weighting_detailed(&inverted, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("validator 0"));
+    }
+    
+    #[test]
+    fn test_seeded_relay_assignment() {
+        let distribute = |seed: u64| {
+            let config = utils::unequal_stake_config().with_erasure_coding(2, 10).with_seed(seed);
+            let mut model = AlpenglowModel::new(config);
+            for hash in 1..=10 {
+                let block = Block {
+                    slot: hash,
+                    view: hash,
+                    hash,
+                    parent: hash - 1,
+                    proposer: 0,
+                    transactions: BTreeSet::new(),
+                    timestamp: 0,
+                    signature: 0,
+                    data: vec![],
+                };
+                let action = AlpenglowAction::Rotor(RotorAction::ShredAndDistribute { leader: 0, block });
+                model.state = model.execute_action(action).unwrap();
+            }
+            model.state
+        };
+        let counts = |state: &AlpenglowState| -> Vec<usize> {
+            (0..4).map(|v| properties::relay_shreds_assigned(state, v)).collect()
+        };
+        
+        // The seed picks which shreds each relay gets, never how many
+        let seeded = distribute(7);
+        assert_eq!(seeded.rotor_relay_assignments, distribute(7).rotor_relay_assignments);
+        assert_ne!(seeded.rotor_relay_assignments, distribute(8).rotor_relay_assignments);
+        assert_eq!(counts(&seeded), counts(&distribute(0)));
+        
+        // Moving a seeded selection of the top validators' shreds to the 10% validator is detected
+        let config = utils::unequal_stake_config().with_erasure_coding(2, 10).with_seed(7);
+        let mut faulty = seeded.clone();
+        let assignments = &mut faulty.rotor_relay_assignments;
+        for validator in 0..2 {
+            let (moved, kept): (Vec<u32>, Vec<u32>) = assignments[&validator].iter()
+                .partition(|&&index| utils::seeded_hash(config.seed, index as u64) % 4 != 0);
+            assignments.insert(validator, kept);
+            assignments.get_mut(&3).unwrap().extend(moved);
+        }
+        assert!(properties::relay_stake_weighting_detailed(&seeded, &config).passed);
+        let result = properties::relay_stake_weighting_detailed(&faulty, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("validator 0"));
+    }
+    
+    #[test]
+    fn test_total_stake_conserved() {
+        let state = AlpenglowState::init(&Config::new());
+        assert!(properties::total_stake_conserved_detailed(&state, &Config::new()).passed);
+        assert!(properties::total_stake_conserved(&state, &utils::unequal_stake_config()));
+        
+        // Uneven splits add up to the configured total when the remainder is spread
+        let config = Config::new().with_validators_exact_stake(3);
+        assert_eq!(config.stake_distribution.values().sum::<StakeAmount>(), config.total_stake);
+        assert!(properties::total_stake_conserved(&state, &config));
+        assert_eq!(config.stake_distribution.values().copied().collect::<Vec<_>>(), vec![334, 333, 333]);
+        assert!(Config::new().with_validators(3).stake_distribution.values().all(|stake| *stake == 333));
+        
+        // Changing a stake without updating the total breaks conservation
+        let mut config = Config::new();
+        config.stake_distribution.insert(0, 500);
+        let result = properties::total_stake_conserved_detailed(&state, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("total_stake 1000 != distributed stake 1250"));
+        
+        // Thresholds left stale after the total shrinks are reported
+        let mut config = Config::new();
+        config.stake_distribution.remove(&3);
+        config.total_stake = 750;
+        let result = properties::total_stake_conserved_detailed(&state, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("fast_path_threshold 800 exceeds total_stake 750"));
+    }
+    
+    #[test]
+    fn test_trace_to_mermaid() {
+        let config = Config::new();
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 1,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        let certificate = Certificate {
+            slot: 1,
+            view: 1,
+            block: 1,
+            cert_type: CertificateType::Fast,
+            validators: (0..4).collect(),
+            stake: config.total_stake,
+            signatures: AggregatedSignature {
+                signers: (0..4).collect(),
+                message: 1,
+                signatures: (0..4).map(|v| v as u64).collect(),
+                valid: true,
+            },
+        };
+        let trace = vec![
+            AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: 0, view: 1 }),
+            AlpenglowAction::Rotor(RotorAction::ShredAndDistribute { leader: 0, block: block.clone() }),
+            AlpenglowAction::Votor(VotorAction::CastVote { validator: 1, block, view: 1 }),
+            AlpenglowAction::AdvanceClock,
+            AlpenglowAction::Votor(VotorAction::CollectVotes { validator: 0, view: 1 }),
+            AlpenglowAction::Votor(VotorAction::FinalizeBlock { validator: 0, certificate }),
+            AlpenglowAction::Byzantine(ByzantineAction::Equivocate { validator: 3 }),
+        ];
+        
+        let diagram = utils::trace_to_mermaid(&trace, &config);
+        
+        assert!(diagram.starts_with("sequenceDiagram"));
+        for validator in 0..4 {
+            assert!(diagram.contains(&format!("participant V{} as Validator {}", validator, validator)));
+        }
+        assert!(diagram.contains("V0->>V1: propose view 1"));
+        assert!(diagram.contains("V0-)V3: shreds block 1"));
+        assert!(diagram.contains("V1->>V0: vote block 1 view 1"));
+        assert!(diagram.contains("V1->>V2: vote block 1 view 1"));
+        assert!(!diagram.contains("V1->>V1"));
+        assert!(diagram.contains("Note over V0: collect votes view 1"));
+        assert!(diagram.contains("V0->>V2: Fast cert slot 1 block 1"));
+        assert!(diagram.contains("rect rgb(255, 210, 210)\n    V3--xV0: Byzantine equivocation"));
+    }
+    
+    #[test]
+    fn test_rotor_delivery_before_finalization() {
+        let config = Config::new();
+        let model = AlpenglowModel::new(config.clone());
+        let result = model.simulate_to_slot(1, 1000, &mut HonestScheduler::new()).unwrap();
+        let state = result.final_state;
+        let block = result.slot_finalizations[&1].block;
+        assert!(properties::rotor_delivery_before_finalization_detailed(&state, &config).passed);
+        
+        // Rotor delivery to three of the four validators lagged behind finalization
+        let mut lagged = state.clone();
+        let finalized_at = lagged.finalization_times[&block];
+        for validator in 1..4 {
+            lagged.rotor_delivery_times.get_mut(&validator).unwrap().insert(block, finalized_at + 5);
+        }
+        let result = properties::rotor_delivery_before_finalization_detailed(&lagged, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains(&format!(
+            "block {} finalized at {} with 1 of 4 honest deliveries", block, finalized_at
+        )));
+        
+        // Validators that never received the block count as late too
+        let mut undelivered = state;
+        undelivered.rotor_delivery_times.clear();
+        assert!(!properties::rotor_delivery_before_finalization(&undelivered, &config));
+    }
+    
+    #[test]
+    fn test_no_premature_view_advance() {
+        let config = Config::new();
+        let mut model = AlpenglowModel::new(config.clone());
+        model.state.clock = model.state.votor_timeout_expiry[&0];
+        
+        // Once the timeout expires a view advances, but not again at the same clock value
+        model.state = model.execute_action(AlpenglowAction::AdvanceView { validator: 0 }).unwrap();
+        assert!(model.execute_action(AlpenglowAction::AdvanceView { validator: 0 }).is_err());
+        assert!(properties::no_premature_view_advance_detailed(&model.state, &config).passed);
+        
+        // A skip certificate justifies leaving view 2 at the same clock value
+        for voter in 0..3 {
+            let skip_vote = Vote {
+                voter,
+                slot: 2,
+                view: 2,
+                block: 0,
+                vote_type: VoteType::Skip,
+                signature: voter as u64,
+                timestamp: model.state.clock,
+            };
+            model.state.votor_skip_votes.get_mut(&0).unwrap().entry(2).or_default().insert(skip_vote);
+        }
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CollectSkipVotes { validator: 0, view: 2 })).unwrap();
+        assert_eq!(model.state.votor_view[&0], 3);
+        assert!(model.state.votor_view_advances[&0][1].skip_certified);
+        assert!(properties::no_premature_view_advance(&model.state, &config));
+        
+        // A timeout that is not pushed forward lets the view inflate at a single clock value
+        let mut broken_config = config.clone();
+        broken_config.timeout_delta = 0;
+        let mut broken = AlpenglowModel::new(broken_config.clone());
+        broken.state.clock = broken.state.votor_timeout_expiry[&1];
+        broken.state = broken.execute_action(AlpenglowAction::AdvanceView { validator: 1 }).unwrap();
+        broken.state = broken.execute_action(AlpenglowAction::AdvanceView { validator: 1 }).unwrap();
+        let result = properties::no_premature_view_advance_detailed(&broken.state, &broken_config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("validator 1 left view 2 at clock 0 (expiry 0) twice at the same clock"));
+        
+        // Leaving a view before its timeout expired is reported
+        let mut early = AlpenglowModel::new(config.clone()).state;
+        early.record_view_advance(2, false);
+        let result = properties::no_premature_view_advance_detailed(&early, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("before timeout expiry"));
+    }
+    
+    #[test]
+    fn test_liveness_feasibility_precondition() {
+        let config = Config::new();
+        assert!(AlpenglowModel::new(config.clone()).liveness_feasible().is_ok());
+        
+        // One Byzantine and two offline validators leave only 25% honest stake online
+        let mut model = utils::create_byzantine_scenario(&config, &[0]).unwrap();
+        model.state.failure_states.insert(1, ValidatorStatus::Offline);
+        model.state.failure_states.insert(2, ValidatorStatus::Offline);
+        let reason = model.liveness_feasible().unwrap_err();
+        assert!(reason.contains("online honest stake 250 of 1000 is below the slow-path threshold 600 (500 offline, 250 Byzantine)"));
+        
+        let byzantine = utils::create_byzantine_scenario(&config, &[0, 1]).unwrap();
+        assert!(byzantine.liveness_feasible().unwrap_err().contains("Byzantine stake 500 is not below one third of total stake 1000"));
+        
+        // The checker reports liveness as not applicable instead of violated
+        let mut checker = ModelChecker::new(config.clone());
+        let metrics = checker.verify_model(&model).unwrap();
+        let liveness = metrics.property_results.iter().find(|r| r.name == "liveness").unwrap();
+        assert!(liveness.passed);
+        assert!(liveness.error.as_ref().unwrap().starts_with("liveness not applicable: Liveness impossible by construction"));
+        assert_eq!(ModelChecker::property_info(&liveness.name).map(|info| info.category), Some(PropertyCategory::Liveness));
+        assert!(!metrics.property_results.iter().any(|r| r.name == "view_progression"));
+    }
+    
+    #[test]
+    fn test_available_properties_registry() {
+        let registry = ModelChecker::available_properties();
+        let category = |name: &str| ModelChecker::property_info(name).map(|info| info.category);
+        
+        assert_eq!(category("safety_no_conflicting_finalization"), Some(PropertyCategory::Safety));
+        assert_eq!(category("certificate_validity"), Some(PropertyCategory::Safety));
+        assert_eq!(category("liveness_eventual_progress"), Some(PropertyCategory::Liveness));
+        assert_eq!(category("block_delivery"), Some(PropertyCategory::Liveness));
+        assert_eq!(category("throughput_optimization"), Some(PropertyCategory::Performance));
+        assert_eq!(category("byzantine_resilience"), Some(PropertyCategory::Byzantine));
+        assert_eq!(category("unknown_property"), None);
+        
+        // Names are unique and every property the checker reports is registered
+        let names: BTreeSet<&str> = registry.iter().map(|info| info.name).collect();
+        assert_eq!(names.len(), registry.len());
+        let mut checker = ModelChecker::new(Config::new());
+        let metrics = checker.verify_model(&AlpenglowModel::new(Config::new())).unwrap();
+        for result in &metrics.property_results {
+            assert!(names.contains(result.name.as_str()), "{} is not registered", result.name);
+        }
+        assert!(registry.iter().all(|info| !info.description.is_empty()));
+    }
+    
+    #[test]
+    fn test_property_dependency_graph() {
+        let bandwidth = ModelChecker::property_info("bandwidth_safety").unwrap();
+        assert!(bandwidth.read_set().contains(&StateField::RotorBandwidthUsage));
+        assert!(!bandwidth.read_set().contains(&StateField::VotorView));
+        
+        let graph = ModelChecker::property_dependency_graph();
+        assert!(graph.properties_reading(StateField::RotorBandwidthUsage).contains("bandwidth_safety"));
+        assert!(!graph.properties_reading(StateField::VotorView).contains("bandwidth_safety"));
+        assert!(graph.to_json()["rotor_bandwidth_usage"].as_array().unwrap().iter().any(|p| p == "bandwidth_safety"));
+        assert!(graph.to_dot().contains("    \"rotor_bandwidth_usage\" -> \"bandwidth_safety\";\n"));
+    }
+    
+    #[test]
+    fn test_no_skip_and_commit_same_view() {
+        let config = Config::new();
+        let mut model = AlpenglowModel::new(config.clone());
+        model.state.votor_view.insert(1, 2);
+        model.state.votor_view.insert(2, 2);
+        model.state.clock = model.state.votor_timeout_expiry[&1];
+        let block = Block {
+            slot: 1,
+            view: 2,
+            hash: 2,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        
+        // Validator 2 only commits and the leader only proposes; neither conflicts with skipping
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CastVote { validator: 2, block: block.clone(), view: 2 })).unwrap();
+        model.state.votor_voted_blocks.get_mut(&0).unwrap().entry(2).or_default().insert(block.clone());
+        assert!(properties::no_skip_and_commit_same_view_detailed(&model.state, &config).passed);
+        
+        // Honest validator 1 commits to the block and then skips view 2
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CastVote { validator: 1, block, view: 2 })).unwrap();
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::SubmitSkipVote { validator: 1, view: 2 })).unwrap();
+        assert_eq!(model.state.failure_states[&1], ValidatorStatus::Honest);
+        
+        let result = properties::no_skip_and_commit_same_view_detailed(&model.state, &config);
+        assert!(!result.passed);
+        let error = result.error.unwrap();
+        assert!(error.starts_with("Protocol violation"));
+        assert!(error.contains("validator 1 view 2 skipped and committed to {2}"));
+        assert!(!error.contains("validator 2"));
+        
+        let mut checker = ModelChecker::new(config);
+        let metrics = checker.verify_model(&model).unwrap();
+        assert!(metrics.property_results.iter().any(|r| r.name == "no_skip_and_commit_same_view" && !r.passed));
+    }
+    
+    #[test]
+    fn test_safety_no_double_voting() {
+        let config = Config::new();
+        let mut state = AlpenglowState::init(&config);
+        let block = |hash: BlockHash| Block {
+            slot: 1,
+            view: 2,
+            hash,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        
+        // Voting for the same hash twice is not a conflict
+        let votes = state.votor_voted_blocks.entry(1).or_default().entry(2).or_default();
+        votes.insert(block(7));
+        votes.insert(Block { timestamp: 1, ..block(7) });
+        assert!(properties::safety_no_double_voting(&state, &config));
+        
+        // Three distinct blocks in one view are three conflicting pairs
+        let votes = state.votor_voted_blocks.get_mut(&1).unwrap().get_mut(&2).unwrap();
+        votes.insert(block(8));
+        votes.insert(block(9));
+        let result = properties::safety_no_double_voting_detailed(&state, &config);
+        assert!(!result.passed);
+        assert_eq!(result.counterexample_length, Some(3));
+        assert!(result.error.unwrap().contains("validator 1 view 2 voted for {7, 8, 9}"));
+        
+        // Byzantine validators are excluded
+        state.failure_states.insert(1, ValidatorStatus::Byzantine);
+        assert!(properties::safety_no_double_voting_detailed(&state, &config).passed);
+    }
+    
+    #[test]
+    fn test_replay_trace() {
+        let config = Config::new().with_validators(4).with_byzantine_threshold(1);
+        let mut model = AlpenglowModel::new(config.clone());
+        model.set_byzantine(3, ByzantineStrategy::Equivocate).unwrap();
+        let block = |hash: BlockHash, proposer: ValidatorId| Block {
+            slot: model.state.current_slot,
+            view: 1,
+            hash,
+            parent: 0,
+            proposer,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: proposer as u64,
+            data: vec![],
+        };
+        
+        // The Byzantine validator's invalid block lets honest validator 1 vote twice in view 1
+        let trace = vec![
+            AlpenglowAction::Votor(VotorAction::CastVote { validator: 1, block: block(1, 0), view: 1 }),
+            AlpenglowAction::Byzantine(ByzantineAction::InvalidBlock { validator: 3 }),
+            AlpenglowAction::Votor(VotorAction::CastVote { validator: 1, block: block(999999, 3), view: 1 }),
+        ];
+        let states = model.replay_trace(&trace).unwrap();
+        assert_eq!(states.len(), trace.len() + 1);
+        assert_eq!(states[0], model.state);
+        assert!(states[..3].iter().all(|state| properties::safety_no_double_voting(state, &config)));
+        let result = properties::safety_no_double_voting_detailed(&states[3], &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("validator 1 view 1 voted for {1, 999999}"));
+        
+        // Each intermediate state is the one the previous action leads to
+        for (step, action) in trace.iter().enumerate() {
+            assert_eq!(model.next_state(&states[step], action.clone()).as_ref(), Some(&states[step + 1]));
+        }
+        
+        // A step that is not enabled is reported by its index
+        let mut invalid = trace.clone();
+        invalid.insert(1, AlpenglowAction::Byzantine(ByzantineAction::InvalidBlock { validator: 2 }));
+        let err = model.replay_trace(&invalid).unwrap_err();
+        assert!(err.to_string().contains("Step 1 of the trace"));
     }
     
-    /// Create a configuration with unequal stake distribution
-    pub fn unequal_stake_config() -> Config {
-        let mut stakes = BTreeMap::new();
-        stakes.insert(0, 4000); // 40% stake
-        stakes.insert(1, 3000); // 30% stake
-        stakes.insert(2, 2000); // 20% stake
-        stakes.insert(3, 1000); // 10% stake
+    #[test]
+    fn test_certificate_view_in_range() {
+        let config = Config::new();
+        let model = utils::create_economic_test_scenario(&config).unwrap();
+        assert!(properties::certificate_view_in_range_detailed(&model.state, &config).passed);
+        
+        // A certificate from beyond max_view is flagged
+        let mut future = model.state.clone();
+        let mut cert = future.votor_generated_certs[&1].iter().next().unwrap().clone();
+        cert.view = config.max_view + 1;
+        future.votor_generated_certs.entry(config.max_view + 1).or_default().insert(cert.clone());
+        let result = properties::certificate_view_in_range_detailed(&future, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains(&format!("has view {} (stored under view {})", config.max_view + 1, config.max_view + 1)));
+        
+        // A stale certificate replayed into a later view is flagged too
+        let mut replayed = model.state.clone();
+        cert.view = 1;
+        replayed.votor_generated_certs.entry(5).or_default().insert(cert);
+        assert!(!properties::certificate_view_in_range(&replayed, &config));
+        
+        // No action moves a validator past max_view, even with its timeout expired
+        let mut last_view = AlpenglowModel::new(config.clone());
+        last_view.state.votor_view.insert(0, config.max_view);
+        last_view.state.votor_timeout_expiry.insert(0, 0);
+        assert!(!last_view.action_enabled(&AlpenglowAction::AdvanceView { validator: 0 }));
+        assert!(!last_view.action_enabled(&AlpenglowAction::Votor(VotorAction::Timeout { validator: 0 })));
+        assert!(!last_view.action_enabled(&AlpenglowAction::Votor(VotorAction::SubmitSkipVote { validator: 0, view: config.max_view })));
+        assert!(!last_view.action_enabled(&AlpenglowAction::Votor(VotorAction::CollectSkipVotes { validator: 0, view: config.max_view })));
+        last_view.state.votor_view.insert(0, config.max_view - 1);
+        assert!(last_view.action_enabled(&AlpenglowAction::Votor(VotorAction::Timeout { validator: 0 })));
+    }
+    
+    #[test]
+    fn test_compare_configs_fast_versus_slow_path() {
+        // Three of four equal-stake validators hold 75%: slow path at 80%, fast path at 67%
+        let config_a = Config::new();
+        let mut config_b = Config::new();
+        config_b.fast_path_threshold = (config_b.total_stake * 67) / 100;
         
-        Config::new()
-            .with_validators(4)
-            .with_stake_distribution(stakes)
+        let leader = AlpenglowModel::new(config_a.clone()).compute_leader_for_view(1);
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 1,
+            parent: 0,
+            proposer: leader,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: leader as u64,
+            data: vec![],
+        };
+        let certificate = |cert_type: CertificateType| Certificate {
+            slot: 1,
+            view: 1,
+            block: 1,
+            cert_type,
+            validators: (0..3).collect(),
+            stake: (config_a.total_stake * 3) / 4,
+            signatures: AggregatedSignature {
+                signers: (0..3).collect(),
+                message: 1,
+                signatures: (0..3).map(|v| v as u64).collect(),
+                valid: true,
+            },
+        };
+        let mut trace = vec![
+            AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: leader, view: 1 }),
+        ];
+        for validator in 0..3 {
+            trace.push(AlpenglowAction::Votor(VotorAction::CastVote { validator, block: block.clone(), view: 1 }));
+        }
+        trace.push(AlpenglowAction::Votor(VotorAction::CollectVotes { validator: 0, view: 1 }));
+        trace.push(AlpenglowAction::Votor(VotorAction::FinalizeBlock { validator: 0, certificate: certificate(CertificateType::Slow) }));
+        trace.push(AlpenglowAction::Votor(VotorAction::FinalizeBlock { validator: 0, certificate: certificate(CertificateType::Fast) }));
+        
+        let comparison = utils::compare_configs(config_a.clone(), config_b, &trace);
+        
+        assert!(comparison.chains_match());
+        assert_eq!(comparison.finalized_chain_a, vec![1]);
+        assert_eq!(comparison.certificate_path_differences, vec![utils::CertificatePathDifference {
+            block: 1,
+            path_a: Some(CertificateType::Slow),
+            path_b: Some(CertificateType::Fast),
+        }]);
+        let divergences: Vec<(usize, bool, bool)> = comparison.divergence_points.iter()
+            .map(|d| (d.step, d.enabled_in_a, d.enabled_in_b))
+            .collect();
+        assert_eq!(divergences, vec![(5, true, false), (6, false, true)]);
+        assert!(!comparison.is_equivalent());
+        
+        // Identical configs replay identically
+        assert!(utils::compare_configs(config_a.clone(), config_a, &trace[..5]).is_equivalent());
     }
     
-    /// Create test scenario with Byzantine validators
-    pub fn create_byzantine_scenario(
-        config: &Config,
-        byzantine_validators: &[ValidatorId],
-    ) -> AlpenglowResult<AlpenglowModel> {
+    #[test]
+    fn test_verify_with_progress_streams_levels() {
+        let mut checker = RichModelChecker::new(Config::new());
+        checker.set_exploration_depth(5);
+        let mut output = Vec::new();
+        
+        let result = checker.verify_with_progress(&mut output).unwrap();
+        
+        let lines: Vec<serde_json::Value> = String::from_utf8(output).unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let (summary, levels) = lines.split_last().unwrap();
+        assert_eq!(levels.len(), 6);
+        let explored: Vec<u64> = levels.iter().map(|line| line["states_explored"].as_u64().unwrap()).collect();
+        assert!(explored.windows(2).all(|pair| pair[0] < pair[1]), "not increasing: {:?}", explored);
+        assert!(levels.iter().all(|line| line["type"] == "level" && line["frontier_size"].is_u64()));
+        assert_eq!(summary["type"], "summary");
+        assert_eq!(summary["states_explored"].as_u64().unwrap() as usize, result.total_states_explored);
+        assert_eq!(summary["passed"], result.violations_found.is_empty());
+        
+        // Depth and branching factor come from the traversal
+        assert_eq!(result.max_depth, levels.len() - 1);
+        assert!(result.avg_branching_factor >= 1.0, "AdvanceClock is always enabled");
+        
+        // verify_model runs the same breadth-first traversal
+        let mut bfs = RichModelChecker::new(Config::new());
+        bfs.set_exploration_depth(5);
+        let traversed = bfs.verify_model().unwrap();
+        assert_eq!(traversed.total_states_explored, result.total_states_explored);
+        assert_eq!(traversed.max_depth, result.max_depth);
+        assert_eq!(traversed.avg_branching_factor, result.avg_branching_factor);
+    }
+    
+    #[test]
+    #[cfg(feature = "checkpoint")]
+    fn test_checkpoint_resume_matches_uninterrupted_run() {
+        let config = Config::new();
+        let mut uninterrupted = RichModelChecker::new(config.clone());
+        uninterrupted.set_max_states(60);
+        let expected = uninterrupted.verify_with_progress(std::io::sink()).unwrap();
+        
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("search.ckpt");
+        let mut first = RichModelChecker::new(config.clone());
+        assert!(first.save_checkpoint(&path).is_err(), "nothing to checkpoint before a run");
+        first.set_max_states(30);
+        let partial = first.verify_with_progress(std::io::sink()).unwrap();
+        assert_eq!(partial.total_states_explored, 30);
+        first.save_checkpoint(&path).unwrap();
+        
+        let mut resumed = RichModelChecker::resume_from_checkpoint(&path, config.clone()).unwrap();
+        assert_eq!(resumed.max_states, 30);
+        resumed.set_max_states(60);
+        let result = resumed.verify_with_progress(std::io::sink()).unwrap();
+        
+        assert_eq!(result.total_states_explored, expected.total_states_explored);
+        assert_eq!(result.property_results, expected.property_results);
+        assert_eq!(result.violations_found, expected.violations_found);
+        assert_eq!(result.max_depth, expected.max_depth);
+        assert_eq!(result.avg_branching_factor, expected.avg_branching_factor);
+        
+        // A checkpoint only resumes the configuration it was taken with
+        let other = config.clone().with_validators(5);
+        assert!(matches!(RichModelChecker::resume_from_checkpoint(&path, other), Err(AlpenglowError::InvalidConfig(_))));
+        
+        // Nor one written in another checkpoint format
+        let mut bytes = fs::read(&path).unwrap();
+        bytes[..4].copy_from_slice(&(CHECKPOINT_FORMAT_VERSION + 1).to_le_bytes());
+        fs::write(&path, bytes).unwrap();
+        assert!(matches!(RichModelChecker::resume_from_checkpoint(&path, config), Err(AlpenglowError::SerializationError(_))));
+    }
+    
+    #[test]
+    fn test_erasure_overhead_in_bandwidth() {
+        let config = Config::new().with_validators(4).with_erasure_coding(2, 4);
+        let model = AlpenglowModel::new(config.clone());
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 1,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        let shreds = model.erasure_encode(&block);
+        let state = model.execute_action(AlpenglowAction::Rotor(RotorAction::ShredAndDistribute { leader: 0, block })).unwrap();
+        
+        // Sending N = 2K shreds costs twice the K data shreds
+        let raw_block = utils::shred_bytes(shreds.iter().filter(|piece| !piece.is_parity));
+        assert!(raw_block > 0);
+        assert_eq!(state.rotor_bandwidth_usage[&0], 2 * raw_block);
+        
+        let accounting = properties::bandwidth_accounting(&state, &config);
+        assert_eq!(accounting.throughput, 2 * raw_block);
+        assert_eq!(accounting.goodput * 2, accounting.throughput);
+        assert!((accounting.redundancy_overhead - 2.0).abs() < f64::EPSILON);
+        assert!(properties::throughput_optimization_detailed(&state, &config).passed);
+    }
+    
+    #[test]
+    fn test_rotor_actions_consume_bandwidth() {
+        let config = Config::new().with_validators(4).with_byzantine_threshold(0);
         let mut model = AlpenglowModel::new(config.clone());
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 1,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![7; 64],
+        };
+        model.state = model.execute_action(AlpenglowAction::Rotor(RotorAction::ShredAndDistribute { leader: 0, block: block.clone() })).unwrap();
+        let shreds = model.erasure_encode(&block);
+        assert_eq!(model.state.rotor_bandwidth_usage[&0], utils::shred_bytes(&shreds));
+        
+        // Relaying sends every held shred to each of the other three validators
+        let held = model.state.rotor_block_shreds[&1][&1].clone();
+        assert!(!held.is_empty());
+        model.state = model.execute_action(AlpenglowAction::Rotor(RotorAction::RelayShreds { validator: 1, block_id: 1 })).unwrap();
+        assert_eq!(model.state.rotor_bandwidth_usage[&1], 3 * utils::shred_bytes(&held));
+        
+        // Answering a repair request costs the responder the pieces it sends
+        let request = RepairRequest {
+            requester: 3,
+            block_id: 1,
+            missing_indices: held.iter().map(|piece| piece.index).collect(),
+            timestamp: 0,
+        };
+        model.state.rotor_repair_requests.insert(request.clone());
+        let before = model.state.rotor_bandwidth_usage[&2];
+        model.state = model.execute_action(AlpenglowAction::Rotor(RotorAction::RespondToRepair { validator: 2, request })).unwrap();
+        assert_eq!(model.state.rotor_bandwidth_usage[&2], before + utils::shred_bytes(&held));
         
-        // Mark specified validators as Byzantine
-        for &validator in byzantine_validators {
-            if validator < config.validator_count as ValidatorId {
-                model.state.failure_states.insert(validator, ValidatorStatus::Byzantine);
-            }
+        // A limit below the relay's usage is tripped, and the slot advance clears it again
+        let tight = Config { bandwidth_limit: model.state.rotor_bandwidth_usage[&1] - 1, ..config };
+        assert!(!properties::bandwidth_safety(&model.state, &tight));
+        model.state.finalized_blocks.entry(1).or_default().insert(block);
+        let state = model.execute_action(AlpenglowAction::AdvanceSlot).unwrap();
+        assert!(state.rotor_bandwidth_usage.values().all(|usage| *usage == 0));
+        assert!(properties::bandwidth_safety(&state, &tight));
+    }
+    
+    #[test]
+    fn test_bandwidth_limit_defers_relays() {
+        let config = Config::new().with_validators(4).with_byzantine_threshold(0);
+        let mut model = AlpenglowModel::new(config.clone());
+        let block = |hash: BlockHash| Block {
+            slot: 1,
+            view: 1,
+            hash,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![7; 64],
+        };
+        for hash in [1, 2] {
+            model.state = model.execute_action(AlpenglowAction::Rotor(RotorAction::ShredAndDistribute { leader: 0, block: block(hash) })).unwrap();
         }
+        let relay = |block_id| AlpenglowAction::Rotor(RotorAction::RelayShreds { validator: 1, block_id });
+        
+        // Validator 1's budget covers relaying its shreds of one block, not of both
+        let cost = 3 * utils::shred_bytes(&model.state.rotor_block_shreds[&1][&1]);
+        model.config.bandwidth_limit = cost + cost / 2;
+        assert_eq!(model.remaining_bandwidth(&model.state, 1), cost + cost / 2);
+        assert!(model.action_enabled(&relay(1)) && model.action_enabled(&relay(2)));
+        
+        model.state = model.execute_action(relay(1)).unwrap();
+        assert_eq!(model.remaining_bandwidth(&model.state, 1), cost / 2);
+        assert!(!model.action_enabled(&relay(2)));
+        assert!(model.execute_action(relay(2)).is_err());
+        let mut actions = Vec::new();
+        model.actions(&model.state, &mut actions);
+        assert!(!actions.contains(&relay(2)));
         
-        Ok(model)
+        // The deferred relay goes out in the next round
+        model.state.finalized_blocks.entry(1).or_default().insert(block(1));
+        model.state = model.execute_action(AlpenglowAction::AdvanceSlot).unwrap();
+        assert_eq!(model.remaining_bandwidth(&model.state, 1), cost + cost / 2);
+        model.state = model.execute_action(relay(2)).unwrap();
+        assert_eq!(model.state.rotor_bandwidth_usage[&1], cost);
+        assert!(properties::bandwidth_safety(&model.state, &model.config));
     }
     
-    /// Create test scenario with network partitions
-    pub fn create_network_partition_scenario(
-        config: &Config,
-        partitions: Vec<BTreeSet<ValidatorId>>,
-    ) -> AlpenglowResult<AlpenglowModel> {
+    #[test]
+    fn test_bandwidth_resets_per_round() {
+        let config = Config::new();
+        let mut model = AlpenglowModel::new(config.clone());
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 1,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        model.state.finalized_blocks.entry(1).or_default().insert(block);
+        
+        // Usage accumulated during slot 1
+        model.state.rotor_bandwidth_usage.insert(0, 600);
+        model.state.rotor_bandwidth_usage.insert(2, 150);
+        assert!(properties::bandwidth_resets_per_round_detailed(&model.state, &config).passed);
+        
+        let state = model.execute_action(AlpenglowAction::AdvanceSlot).unwrap();
+        assert_eq!(state.current_slot, 2);
+        assert!(state.rotor_bandwidth_usage.values().all(|usage| *usage == 0));
+        assert!(properties::bandwidth_resets_per_round_detailed(&state, &config).passed);
+        
+        // Usage left over from slot 1 is stale once slot 2 begins
+        let mut stale = model.state.clone();
+        stale.current_slot = 2;
+        let result = properties::bandwidth_resets_per_round_detailed(&stale, &config);
+        assert!(!result.passed);
+        assert_eq!(result.counterexample_length, Some(2));
+        assert!(result.error.unwrap().contains("from slot 1 carried into slot 2: validator 0 (600 bytes)"));
+    }
+    
+    #[test]
+    fn test_critical_quorum() {
+        let stakes: BTreeMap<ValidatorId, StakeAmount> = [(0, 400), (1, 300), (2, 200), (3, 100)].into_iter().collect();
+        let config = Config::new().with_stake_distribution(stakes.clone());
+        let model = AlpenglowModel::new(config.clone());
+        let result = model.simulate_to_slot(1, 1000, &mut HonestScheduler::new()).unwrap();
+        let state = result.final_state;
+        let block = result.slot_finalizations[&1].block;
+        
+        let quorum = state.critical_quorum(block, &config).unwrap();
+        let certificate = state.votor_generated_certs.values()
+            .flat_map(|certs| certs.iter())
+            .find(|cert| cert.block == block)
+            .unwrap();
+        let threshold = match certificate.cert_type {
+            CertificateType::Fast => config.fast_path_threshold,
+            _ => config.slow_path_threshold,
+        };
+        let stake_of = |members: &BTreeSet<ValidatorId>| members.iter().map(|v| stakes[v]).sum::<StakeAmount>();
+        
+        assert!(quorum.is_subset(&certificate.validators));
+        assert!(stake_of(&quorum) >= threshold);
+        for member in &quorum {
+            let mut reduced = quorum.clone();
+            reduced.remove(member);
+            assert!(stake_of(&reduced) < threshold, "quorum {:?} still meets threshold without {}", quorum, member);
+        }
+        
+        assert_eq!(state.critical_quorum(block + 1000, &config), None);
+    }
+    
+    #[test]
+    fn test_message_accounting_counts_cross_partition_drops() {
+        let config = Config::new();
         let mut model = AlpenglowModel::new(config.clone());
+        let message = |id: u64, recipient: MessageRecipient| NetworkMessage {
+            id,
+            msg_type: MessageType::Vote,
+            sender: 0,
+            recipient,
+            payload: vec![id as u8],
+            timestamp: 0,
+            signature: 0,
+        };
+        let to_isolated = message(1, MessageRecipient::Validator(1));
+        let broadcast = message(2, MessageRecipient::Broadcast);
+        model.state.enqueue_message(to_isolated.clone());
+        model.state.enqueue_message(broadcast.clone());
+        model.state.enqueue_message(message(3, MessageRecipient::Validator(2)));
+        model.state.network_partitions.insert([1].into_iter().collect());
+        
+        for message in [to_isolated, broadcast] {
+            model.state = model.execute_action(AlpenglowAction::Network(NetworkAction::DeliverMessage { message })).unwrap();
+        }
         
-        // Add network partitions
-        for partition in partitions {
-            model.state.network_partitions.insert(partition);
+        let state = &model.state;
+        assert_eq!(state.network_enqueued_messages, 3);
+        assert_eq!(state.network_delivered_messages, 1);
+        assert_eq!(state.network_dropped_messages, 1);
+        assert_eq!(state.network_message_queue.len(), 1);
+        assert!(properties::message_accounting_consistent_detailed(state, &config).passed);
+        
+        // A message that vanishes without being counted breaks the equation
+        let mut lost = state.clone();
+        lost.network_message_queue.clear();
+        let result = properties::message_accounting_consistent_detailed(&lost, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("3 enqueued but 1 delivered + 1 dropped + 0 in flight = 2"));
+    }
+    
+    #[test]
+    fn test_drop_rate_bounded() {
+        let config = Config::new().with_max_message_loss_rate(0.25);
+        let mut model = AlpenglowModel::new(config.clone());
+        for id in 1..=4 {
+            model.state.enqueue_message(NetworkMessage {
+                id,
+                msg_type: MessageType::Vote,
+                sender: 0,
+                recipient: MessageRecipient::Broadcast,
+                payload: vec![],
+                timestamp: 0,
+                signature: 0,
+            });
         }
+        let queued: Vec<_> = model.state.network_message_queue.iter().cloned().collect();
         
-        Ok(model)
+        // One drop in four is within tolerance
+        model.state = model.execute_action(AlpenglowAction::Network(NetworkAction::DropMessage { message: queued[0].clone() })).unwrap();
+        assert!(properties::drop_rate_bounded_detailed(&model.state, &config).passed);
+        
+        // Dropping everything is not a realistic network
+        for message in &queued[1..] {
+            model.state = model.execute_action(AlpenglowAction::Network(NetworkAction::DropMessage { message: message.clone() })).unwrap();
+        }
+        let result = properties::drop_rate_bounded_detailed(&model.state, &config);
+        assert!(!result.passed);
+        assert_eq!(result.error.unwrap(), "Dropped 4 of 4 enqueued messages (100.0%), above the tolerated 25.0%");
+        assert!(config.clone().with_byzantine_threshold(0).with_max_message_loss_rate(1.5).validate().is_err());
     }
     
-    /// Create test scenario with offline validators
-    pub fn create_offline_scenario(
-        config: &Config,
-        offline_validators: &[ValidatorId],
-    ) -> AlpenglowResult<AlpenglowModel> {
+    #[test]
+    fn test_leader_coverage() {
+        let config = Config::new();
+        let mut state = AlpenglowState::init(&config);
+        for view in state.votor_view.values_mut() {
+            *view = 400;
+        }
+        
+        let mut led: BTreeMap<ValidatorId, usize> = BTreeMap::new();
+        for view in 1..=400 {
+            *led.entry(utils::leader_for_view(&config, view)).or_default() += 1;
+        }
+        assert_eq!(led.len(), 4);
+        assert!(led.values().all(|count| (70..=130).contains(count)), "uneven leader counts: {:?}", led);
+        assert!(properties::leader_coverage_detailed(&state, &config).passed);
+        
+        // Selection that skips a staked validator is caught
+        let mut excluding = config.clone();
+        excluding.validator_count = 3;
+        let result = properties::leader_coverage_detailed(&state, &excluding);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("validator 3 led 0 views (expected 100.0)"));
+    }
+    
+    #[test]
+    fn test_finalization_prefers_fast_path() {
+        let config = Config::new();
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 1,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        let certificate = |cert_type: CertificateType, signers: ValidatorId| Certificate {
+            slot: 1,
+            view: 1,
+            block: 1,
+            cert_type,
+            validators: (0..signers).collect(),
+            stake: signers as StakeAmount * 250,
+            signatures: AggregatedSignature {
+                signers: (0..signers).collect(),
+                message: 1,
+                signatures: (0..signers).map(|v| v as u64).collect(),
+                valid: true,
+            },
+        };
+        let slow = certificate(CertificateType::Slow, 3);
+        let mut model = AlpenglowModel::new(config.clone());
+        model.state.votor_voted_blocks.entry(0).or_default().entry(1).or_default().insert(block);
+        let certs = model.state.votor_generated_certs.entry(1).or_default();
+        certs.insert(slow.clone());
+        certs.insert(certificate(CertificateType::Fast, 4));
+        
+        // Finalizing with the slow cert still records the fast path
+        let finalize = AlpenglowAction::Votor(VotorAction::FinalizeBlock { validator: 0, certificate: slow.clone() });
+        let state = model.execute_action(finalize.clone()).unwrap();
+        assert_eq!(state.finalization_paths[&1], CertificateType::Fast);
+        assert!(properties::prefers_fast_path_detailed(&state, &config).passed);
+        
+        let mut slow_path = state.clone();
+        slow_path.finalization_paths.insert(1, CertificateType::Slow);
+        let result = properties::prefers_fast_path_detailed(&slow_path, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("despite a matching fast certificate: block 1 (slot 1, view 1)"));
+        
+        // A fast certificate from another view does not match the slow one
+        let mut other_view = AlpenglowModel::new(config.clone());
+        other_view.state = model.state.clone();
+        let certs = other_view.state.votor_generated_certs.remove(&1).unwrap();
+        let (fast, slow_only): (BTreeSet<_>, BTreeSet<_>) = certs.into_iter().partition(|c| c.cert_type == CertificateType::Fast);
+        other_view.state.votor_generated_certs.insert(1, slow_only);
+        other_view.state.votor_generated_certs.insert(2, fast.into_iter().map(|cert| Certificate { view: 2, ..cert }).collect());
+        let state = other_view.execute_action(finalize.clone()).unwrap();
+        assert_eq!(state.finalization_paths[&1], CertificateType::Slow);
+        assert!(properties::prefers_fast_path_detailed(&state, &config).passed);
+        
+        // Without the preference the given certificate decides the path
+        model.config = config.with_fast_path_preference(false);
+        let state = model.execute_action(finalize).unwrap();
+        assert_eq!(state.finalization_paths[&1], CertificateType::Slow);
+        assert!(properties::prefers_fast_path_detailed(&state, &model.config).passed);
+    }
+    
+    #[test]
+    fn test_estimate_memory_for_verification() {
+        let small = Config::new().with_validators(3);
+        let large = Config::new().with_validators(10);
+        
+        let small_estimate = small.estimate_memory_for_verification();
+        let large_estimate = large.estimate_memory_for_verification();
+        
+        assert!(small_estimate > 0);
+        assert!(large_estimate > 10 * small_estimate, "{} vs {}", large_estimate, small_estimate);
+        
+        assert!(small.memory_budget_warning().is_none());
+        let over_budget = large.with_memory_budget(small_estimate);
+        let warning = over_budget.memory_budget_warning().unwrap();
+        assert!(warning.starts_with("Verification of 10 validators to depth 1000 needs about"));
+        
+        // Verification reports the warning in its result instead of printing it
+        let mut checker = RichModelChecker::new(over_budget);
+        checker.set_max_states(1);
+        assert_eq!(checker.verify_with_progress(std::io::sink()).unwrap().warnings, vec![warning]);
+        assert!(RichModelChecker::new(small).verify_model().unwrap().warnings.is_empty());
+    }
+    
+    #[test]
+    fn test_adaptive_timeouts() {
+        let config = Config::new().with_validators(4).with_adaptive_timeouts(true);
+        let mut model = AlpenglowModel::new(config.clone());
+        let mut fixed = AlpenglowModel::new(config.with_adaptive_timeouts(false));
+        
+        // Nothing delivered yet, so there is nothing to adapt to
+        assert_eq!(utils::observed_mean_delay(&model.state), 0);
+        assert_eq!(model.calculate_timeout(0, 1), fixed.calculate_timeout(0, 1));
+        
+        let message = |id: u64| NetworkMessage {
+            id,
+            msg_type: MessageType::Vote,
+            sender: 0,
+            recipient: MessageRecipient::Broadcast,
+            payload: vec![],
+            timestamp: 0,
+            signature: 0,
+        };
+        
+        // Only the most recent deliveries count: an early fast message is outside the window
+        model.state.network_delivery_time.insert(message(1), 0);
+        for id in 2..=utils::DELAY_SAMPLE_WINDOW as u64 + 1 {
+            model.state.network_delivery_time.insert(message(id), 90);
+        }
+        assert_eq!(utils::observed_mean_delay(&model.state), 90);
+        fixed.state = model.state.clone();
+        for view in 1..=5 {
+            let adaptive = model.calculate_timeout(0, view);
+            let static_timeout = fixed.calculate_timeout(0, view);
+            assert!(adaptive > static_timeout, "view {}: {} <= {}", view, adaptive, static_timeout);
+        }
+        
+        // Delays beyond max_network_delay are capped at it
+        let max_delay = model.config.max_network_delay;
+        for delay in model.state.network_delivery_time.values_mut() {
+            *delay = max_delay;
+        }
+        let capped = model.calculate_timeout(0, 1);
+        for delay in model.state.network_delivery_time.values_mut() {
+            *delay = 10 * max_delay;
+        }
+        assert_eq!(model.calculate_timeout(0, 1), capped);
+        
+        // A fast network shrinks the timeout
+        for delay in model.state.network_delivery_time.values_mut() {
+            *delay = 1;
+        }
+        assert!(model.calculate_timeout(0, 1) < fixed.calculate_timeout(0, 1));
+    }
+    
+    #[test]
+    fn test_fast_path_aware_timeouts() {
+        let config = Config::new()
+            .with_stake_distribution((0..5).map(|v| (v, 200)).collect())
+            .with_fast_path_aware_timeouts(true);
+        let mut fast = AlpenglowModel::new(Config { validator_count: 5, ..config });
+        fast.state.failure_states.insert(4, ValidatorStatus::Offline);
+        let mut slow = fast.clone();
+        slow.state.failure_states.insert(3, ValidatorStatus::Offline);
+        assert_eq!(fast.online_honest_stake(), 800);
+        assert_eq!(slow.online_honest_stake(), 600);
+        
+        let fast_timeout = fast.calculate_timeout(0, 1);
+        let slow_timeout = slow.calculate_timeout(0, 1);
+        assert!(fast_timeout < slow_timeout, "{} >= {}", fast_timeout, slow_timeout);
+        
+        // Disabled, timeouts ignore quorum availability
+        fast.config.fast_path_aware_timeouts = false;
+        slow.config.fast_path_aware_timeouts = false;
+        assert_eq!(fast.calculate_timeout(0, 1), slow.calculate_timeout(0, 1));
+    }
+    
+    #[test]
+    fn test_certificate_references_known_block() {
+        let config = Config::new();
+        let model = AlpenglowModel::new(config.clone());
+        let result = model.simulate_to_slot(1, 1000, &mut HonestScheduler::new()).unwrap();
+        let mut state = result.final_state;
+        assert!(properties::certificate_references_known_block_detailed(&state, &config).passed);
+        
+        // A certificate fabricated for a block nobody proposed
+        let phantom = Certificate {
+            slot: 1,
+            view: 1,
+            block: 9999,
+            cert_type: CertificateType::Slow,
+            validators: (0..3).collect(),
+            stake: 750,
+            signatures: AggregatedSignature {
+                signers: (0..3).collect(),
+                message: 9999,
+                signatures: (0..3).map(|v| v as u64).collect(),
+                valid: true,
+            },
+        };
+        state.votor_generated_certs.entry(1).or_default().insert(phantom);
+        
+        let result = properties::certificate_references_known_block_detailed(&state, &config);
+        assert!(!result.passed);
+        assert_eq!(result.counterexample_length, Some(1));
+        assert!(result.error.unwrap().contains("Slow cert in view 1 for block 9999"));
+    }
+    
+    #[test]
+    fn test_wall_clock_source_drives_advance_clock() {
+        #[derive(Debug)]
+        struct MockWallClock(Arc<std::sync::atomic::AtomicU64>);
+        
+        impl ClockSource for MockWallClock {
+            fn now_tv(&self) -> TimeValue {
+                self.0.load(std::sync::atomic::Ordering::SeqCst)
+            }
+        }
+        
+        let now = Arc::new(std::sync::atomic::AtomicU64::new(0));
+        let mut model = AlpenglowModel::new(Config::new()).with_clock_source(MockWallClock(now.clone()));
+        
+        now.store(250, std::sync::atomic::Ordering::SeqCst);
+        model.state = model.execute_action(AlpenglowAction::AdvanceClock).unwrap();
+        assert_eq!(model.state.clock, 250);
+        
+        // No real time elapsed: the clock still ticks, so AdvanceClock is never a no-op
+        model.state = model.execute_action(AlpenglowAction::AdvanceClock).unwrap();
+        assert_eq!(model.state.clock, 251);
+        
+        now.store(1000, std::sync::atomic::Ordering::SeqCst);
+        model.state = model.execute_action(AlpenglowAction::AdvanceClock).unwrap();
+        assert_eq!(model.state.clock, 1000);
+        
+        // The default logical clock still ticks once per action
+        let logical = AlpenglowModel::new(Config::new());
+        assert_eq!(logical.execute_action(AlpenglowAction::AdvanceClock).unwrap().clock, 1);
+        
+        // Models are equal only when driven by the same clock source
+        assert_eq!(logical, AlpenglowModel::with_state(Config::new(), logical.state.clone()));
+        assert_eq!(model, model.clone());
+        let wall = logical.clone().with_clock_source(WallClock::new());
+        assert_ne!(logical, wall);
+        assert_ne!(wall, logical.clone().with_clock_source(WallClock::new()));
+        assert!(WallClock::new().now_tv() < 1000);
+    }
+    
+    #[test]
+    fn test_repair_requests_resolvable() {
+        let config = Config::new();
+        let mut state = AlpenglowState::init(&config);
+        let piece = |index: u32| ErasureCodedPiece {
+            block_id: 1,
+            index,
+            total_pieces: config.n,
+            data: vec![1, index as u64],
+            is_parity: false,
+            signature: 0,
+        };
+        state.rotor_block_shreds.entry(1).or_default().insert(2, [piece(1), piece(2)].into_iter().collect());
+        let request = |block_id: BlockHash| RepairRequest {
+            requester: 0,
+            block_id,
+            missing_indices: [1, 2].into_iter().collect(),
+            timestamp: 0,
+        };
+        
+        // Validator 2 holds the shreds validator 0 is missing
+        state.rotor_repair_requests.insert(request(1));
+        assert!(properties::repair_requests_resolvable_detailed(&state, &config).passed);
+        
+        // Nobody holds any shred of block 7
+        state.rotor_repair_requests.insert(request(7));
+        let result = properties::repair_requests_resolvable_detailed(&state, &config);
+        assert!(!result.passed);
+        assert_eq!(result.counterexample_length, Some(1));
+        assert!(result.error.unwrap().contains("validator 0 block 7 indices {1, 2} at 0: no validator holds the missing shreds"));
+        
+        // An answerable request left unanswered past its timeout is also stuck
+        state.rotor_repair_requests.remove(&request(7));
+        state.clock = config.timeout_delta + 1;
+        let result = properties::repair_requests_resolvable_detailed(&state, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("block 1 indices {1, 2} at 0: outstanding past its timeout"));
+    }
+    
+    #[test]
+    fn test_clock_skew_shifts_timeouts() {
+        let config = Config::new();
         let mut model = AlpenglowModel::new(config.clone());
+        let expiry = model.state.votor_timeout_expiry[&0];
+        assert_eq!(model.state.votor_timeout_expiry[&1], expiry);
         
-        // Mark specified validators as offline
-        for &validator in offline_validators {
-            if validator < config.validator_count as ValidatorId {
-                model.state.failure_states.insert(validator, ValidatorStatus::Offline);
-            }
-        }
+        // Validator 1 runs 40 ticks ahead, so its timeout fires 40 ticks earlier in global time
+        model.state = model.execute_action(AlpenglowAction::SkewClock { validator: 1, offset: 40 }).unwrap();
+        assert!(properties::clock_skew_bounded_detailed(&model.state, &config).passed);
+        model.state.clock = expiry - 40;
+        assert_eq!(model.state.local_clock(1), expiry);
+        assert!(model.action_enabled(&AlpenglowAction::Votor(VotorAction::Timeout { validator: 1 })));
+        assert!(!model.action_enabled(&AlpenglowAction::Votor(VotorAction::Timeout { validator: 0 })));
         
-        Ok(model)
+        model.state.clock = expiry;
+        assert!(model.action_enabled(&AlpenglowAction::Votor(VotorAction::Timeout { validator: 0 })));
+        
+        // Drift beyond max_clock_skew in either direction violates the bound
+        model.state = model.execute_action(AlpenglowAction::SkewClock { validator: 2, offset: -150 }).unwrap();
+        let result = properties::clock_skew_bounded_detailed(&model.state, &config);
+        assert!(!result.passed);
+        assert_eq!(result.counterexample_length, Some(1));
+        assert!(result.error.unwrap().contains("validator 2 (offset -150)"));
+        assert!(model.execute_action(AlpenglowAction::SkewClock { validator: 9, offset: 1 }).is_err());
     }
     
-    /// Create stress test scenario with high network activity
-    pub fn create_stress_test_scenario(config: &Config) -> AlpenglowResult<AlpenglowModel> {
+    #[test]
+    fn test_stake_integrity() {
+        let config = Config::new().with_validators(4);
         let mut model = AlpenglowModel::new(config.clone());
-        
-        // Add multiple concurrent proposals
-        for validator in 0..config.validator_count {
-            let validator_id = validator as ValidatorId;
-            let current_view = model.state.votor_view.get(&validator_id).copied().unwrap_or(1);
-            
-            // Create test blocks for stress testing
-            let test_block = Block {
-                slot: model.state.current_slot,
-                view: current_view,
-                hash: (validator_id as u64) * 1000 + current_view,
-                parent: 0,
-                proposer: validator_id,
-                transactions: BTreeSet::new(),
-                timestamp: model.state.clock,
-                signature: validator_id as u64,
-                data: vec![],
-            };
-            
-            model.state.votor_voted_blocks
-                .entry(validator_id)
-                .or_default()
-                .entry(current_view)
-                .or_default()
-                .insert(test_block);
+        model.set_byzantine(3, ByzantineStrategy::DoubleVote).unwrap();
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 1,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        for validator in 0..4 {
+            let vote = VotorAction::CastVote { validator, block: block.clone(), view: 1 };
+            model.state = model.execute_action(AlpenglowAction::Votor(vote)).unwrap();
         }
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CollectVotes { validator: 0, view: 1 })).unwrap();
         
-        Ok(model)
+        // Collected stake comes from the stake distribution, Byzantine voter included
+        let cert = model.state.votor_generated_certs[&1].iter().next().unwrap().clone();
+        let registered: StakeAmount = (0..4).map(|v| config.stake_distribution[&v]).sum();
+        assert_eq!(cert.stake, registered);
+        assert!(properties::stake_integrity_detailed(&model.state, &config).passed);
+        
+        // A certificate crediting the Byzantine validator with more stake than it holds is caught
+        let mut inflated = cert.clone();
+        inflated.stake += config.stake_distribution[&3];
+        model.state.votor_generated_certs.get_mut(&1).unwrap().insert(inflated);
+        let result = properties::stake_integrity_detailed(&model.state, &config);
+        assert!(!result.passed);
+        assert_eq!(result.counterexample_length, Some(1));
+        assert!(result.error.unwrap().contains(&format!("counts {} stake, signers registered {}", registered + config.stake_distribution[&3], registered)));
     }
     
-    /// Create adversarial scenario combining multiple attack vectors
-    pub fn create_adversarial_scenario(
-        config: &Config,
-        byzantine_validators: &[ValidatorId],
-        offline_validators: &[ValidatorId],
-        network_partitions: Vec<BTreeSet<ValidatorId>>,
-    ) -> AlpenglowResult<AlpenglowModel> {
+    #[test]
+    fn test_safety_valid_certificates() {
+        let config = Config::new().with_validators(4);
         let mut model = AlpenglowModel::new(config.clone());
-        
-        // Mark Byzantine validators
-        for &validator in byzantine_validators {
-            if validator < config.validator_count as ValidatorId {
-                model.state.failure_states.insert(validator, ValidatorStatus::Byzantine);
-            }
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 1,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        for validator in 0..4 {
+            let vote = VotorAction::CastVote { validator, block: block.clone(), view: 1 };
+            model.state = model.execute_action(AlpenglowAction::Votor(vote)).unwrap();
         }
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CollectVotes { validator: 0, view: 1 })).unwrap();
+        let cert = model.state.votor_generated_certs[&1].iter().next().unwrap().clone();
+        assert!(properties::safety_valid_certificates(&model.state, &config));
+        
+        // An inflated stake still clears the threshold but no longer matches the validators
+        let mut state = model.state.clone();
+        let mut inflated = cert.clone();
+        inflated.stake += 1;
+        state.votor_generated_certs.insert(1, BTreeSet::from([inflated]));
+        assert!(properties::certificate_validity(&state, &config));
+        let result = properties::safety_valid_certificates_detailed(&state, &config);
+        assert!(!result.passed);
+        assert_eq!(result.counterexample_length, Some(1));
+        assert!(result.error.unwrap().contains(&format!("claims {} stake, validators hold {}", cert.stake + 1, cert.stake)));
+        
+        // Signers must be the validators, and a slow certificate must not carry fast-path stake
+        let mut unsigned = cert.clone();
+        unsigned.signatures.signers.remove(&3);
+        let mut downgraded = cert.clone();
+        downgraded.cert_type = CertificateType::Slow;
+        state.votor_generated_certs.insert(1, BTreeSet::from([unsigned, downgraded]));
+        let result = properties::safety_valid_certificates_detailed(&state, &config);
+        assert_eq!(result.counterexample_length, Some(2));
+        let error = result.error.unwrap();
+        assert!(error.contains("signers {0, 1, 2} differ from validators {0, 1, 2, 3}"));
+        assert!(error.contains("does not match a Slow certificate"));
+    }
+    
+    #[test]
+    fn test_forged_shred_rejected() {
+        let config = Config::new().with_validators(4).with_erasure_coding(2, 4);
+        let mut model = AlpenglowModel::new(config.clone());
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 42,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        model.state = model.execute_action(AlpenglowAction::Rotor(RotorAction::ShredAndDistribute { leader: 0, block })).unwrap();
+        assert!(properties::shred_signature_valid_detailed(&model.state, &config).passed);
+        
+        // Validator 3 holds one genuine shred and a forged shred for a second index
+        let relay = 3;
+        let genuine = model.state.rotor_block_shreds[&42][&relay].iter().next().cloned().unwrap();
+        let mut forged = genuine.clone();
+        forged.index = if genuine.index == 1 { 2 } else { 1 };
+        forged.is_parity = false;
+        forged.data = vec![42, forged.index as u64];
+        forged.signature = utils::shred_signature(&config, relay, &forged);
+        let holding: BTreeSet<_> = [genuine, forged.clone()].into_iter().collect();
+        model.state.rotor_block_shreds.get_mut(&42).unwrap().insert(relay, holding);
+        
+        let result = properties::shred_signature_valid_detailed(&model.state, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains(&format!("validator 3 block 42 shred {}", forged.index)));
+        
+        // The forged shred neither counts towards reconstruction nor spreads through relaying
+        let reconstruct = AlpenglowAction::Rotor(RotorAction::AttemptReconstruction { validator: relay, block_id: 42 });
+        assert!(!model.action_enabled(&reconstruct));
+        let relayed = model.execute_action(AlpenglowAction::Rotor(RotorAction::RelayShreds { validator: relay, block_id: 42 })).unwrap();
+        assert!(relayed.rotor_block_shreds[&42].iter()
+            .filter(|(validator, _)| **validator != relay)
+            .all(|(_, pieces)| !pieces.contains(&forged)));
+    }
+    
+    #[test]
+    fn test_state_fingerprint() {
+        let config = Config::new().with_validators(4);
+        let state = AlpenglowState::init(&config);
+        assert_eq!(state.fingerprint(), state.clone().fingerprint());
+        assert_eq!(state.fingerprint(), AlpenglowState::init(&config).fingerprint());
         
-        // Mark offline validators
-        for &validator in offline_validators {
-            if validator < config.validator_count as ValidatorId {
-                model.state.failure_states.insert(validator, ValidatorStatus::Offline);
-            }
-        }
+        let mut other = state.clone();
+        other.votor_view.insert(2, 2);
+        assert_ne!(state.fingerprint(), other.fingerprint());
         
-        // Add network partitions
-        for partition in network_partitions {
-            model.state.network_partitions.insert(partition);
-        }
+        let mut swapped = state.clone();
+        swapped.votor_view.insert(3, 2);
+        assert_ne!(other.fingerprint(), swapped.fingerprint());
         
-        Ok(model)
+        // The hasher behind fingerprints gives the same output everywhere, so they can be persisted
+        let mut hasher = utils::StableHasher::new(0);
+        (7u64, BTreeMap::from([(2u32, "view".to_string())])).hash(&mut hasher);
+        assert_eq!(std::hash::Hasher::finish(&hasher), 12703106703783924391);
     }
     
-    /// Create scenario for testing economic incentives
-    pub fn create_economic_test_scenario(config: &Config) -> AlpenglowResult<AlpenglowModel> {
+    #[test]
+    fn test_tla_state_json_round_trip() {
+        // A simulated slot populates the Votor and Rotor variables; gossiped votes populate the network ones
+        let config = Config::new().with_vote_gossip_delay(10);
+        let simulated = AlpenglowModel::new(Config::new()).simulate_to_slot(1, 1000, &mut HonestScheduler::new()).unwrap();
         let mut model = AlpenglowModel::new(config.clone());
+        model.state = simulated.final_state;
+        let view = model.state.votor_view[&0];
+        model.state.clock = model.state.clock.max(model.state.votor_timeout_expiry[&0]);
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::SubmitSkipVote { validator: 0, view })).unwrap();
+        let block = model.state.votor_finalized_chain[0].clone();
+        for validator in [1, 2] {
+            let vote = VotorAction::CastVote { validator, block: block.clone(), view };
+            model.state = model.execute_action(AlpenglowAction::Votor(vote)).unwrap();
+        }
+        let queued: Vec<_> = model.state.network_message_queue.iter().cloned().collect();
+        model.state = model.execute_action(AlpenglowAction::Network(NetworkAction::DelayMessage { message: queued[0].clone(), delay: 50 })).unwrap();
+        model.state.clock += 10;
+        model.state = model.execute_action(AlpenglowAction::Network(NetworkAction::DeliverMessage { message: queued[1].clone() })).unwrap();
+        model.state.failure_states.insert(3, ValidatorStatus::Byzantine);
+        let state = model.state;
+        assert!(!state.votor_generated_certs.is_empty() && !state.rotor_block_shreds.is_empty());
+        assert!(!state.network_delivery_time.is_empty() && !state.network_earliest_delivery.is_empty());
+        
+        // Variables appear under their TLA+ names with validator ids as string keys
+        let exported = state.export_tla_state_json();
+        assert_eq!(exported["votorView"]["0"], serde_json::json!(state.votor_view[&0]));
+        assert_eq!(exported["failureStates"]["3"], serde_json::json!("Byzantine"));
+        assert!(exported["rotorBlockShreds"].is_object());
+        assert!(exported["networkMessageQueue"].is_array());
+        
+        let parsed: serde_json::Value = serde_json::from_str(&state.export_tla_state()).unwrap();
+        let mut imported = AlpenglowState::init(&config);
+        imported.import_tla_state_from_json(parsed.clone()).unwrap();
+        assert_eq!(imported, state);
+        assert_eq!(imported.fingerprint(), state.fingerprint());
+        
+        // A missing variable is reported and leaves the state untouched
+        let mut missing = parsed;
+        missing.as_object_mut().unwrap().remove("votorSkipVotes");
+        let mut untouched = AlpenglowState::init(&config);
+        let err = untouched.import_tla_state_from_json(missing).unwrap_err();
+        assert!(err.to_string().contains("Missing 'votorSkipVotes'"));
+        assert_eq!(untouched, AlpenglowState::init(&config));
+    }
+    
+    #[test]
+    fn test_import_tla_state_copies_every_field() {
+        let config = Config::new().with_validators(4).with_byzantine_threshold(0);
+        let mut state = AlpenglowState::init(&config);
+        state.clock = 42;
+        state.current_slot = 3;
+        state.votor_view.insert(1, 5);
+        state.votor_timeout_expiry.insert(2, 99);
+        state.failure_states.insert(3, ValidatorStatus::Offline);
+        state.network_dropped_messages = 7;
+        state.rotor_bandwidth_usage.insert(0, 128);
+        
+        let mut imported = AlpenglowState::init(&config);
+        imported.import_tla_state(&state).unwrap();
+        assert_eq!(imported.clock, 42);
+        assert_eq!(imported.current_slot, 3);
+        assert_eq!(imported.votor_view, state.votor_view);
+        assert_eq!(imported.votor_timeout_expiry, state.votor_timeout_expiry);
+        assert_eq!(imported.failure_states, state.failure_states);
+        assert_eq!(imported.network_dropped_messages, 7);
+        assert_eq!(imported.rotor_bandwidth_usage, state.rotor_bandwidth_usage);
+        assert_eq!(imported, state);
+    }
+    
+    #[test]
+    fn test_detailed_diff() {
+        let config = Config::new().with_validators(4);
+        let state = AlpenglowState::init(&config);
+        assert!(state.detailed_diff(&state.clone()).is_empty());
         
-        // Create certificates with different stake amounts for testing thresholds
-        let test_cert_fast = Certificate {
+        let mut other = state.clone();
+        other.votor_generated_certs.entry(1).or_default().insert(Certificate {
             slot: 1,
             view: 1,
-            block: 123,
-            cert_type: CertificateType::Fast,
-            validators: (0..config.validator_count as ValidatorId).collect(),
-            stake: config.fast_path_threshold,
-            signatures: AggregatedSignature {
-                signers: (0..config.validator_count as ValidatorId).collect(),
-                message: 123,
-                signatures: (0..config.validator_count as ValidatorId).map(|v| v as u64).collect(),
-                valid: true,
-            },
-        };
-        
-        let test_cert_slow = Certificate {
-            slot: 2,
-            view: 2,
-            block: 456,
+            block: 7,
             cert_type: CertificateType::Slow,
-            validators: (0..((config.validator_count * 2) / 3) as ValidatorId).collect(),
-            stake: config.slow_path_threshold,
+            validators: (0..3).collect(),
+            stake: 3,
             signatures: AggregatedSignature {
-                signers: (0..((config.validator_count * 2) / 3) as ValidatorId).collect(),
-                message: 456,
-                signatures: (0..((config.validator_count * 2) / 3) as ValidatorId).map(|v| v as u64).collect(),
+                signers: (0..3).collect(),
+                message: 7,
+                signatures: BTreeSet::new(),
                 valid: true,
             },
-        };
-        
-        model.state.votor_generated_certs.entry(1).or_default().insert(test_cert_fast);
-        model.state.votor_generated_certs.entry(2).or_default().insert(test_cert_slow);
+        });
+        other.network_partitions.insert([0, 1].into_iter().collect());
         
-        Ok(model)
+        let diffs = state.detailed_diff(&other);
+        assert_eq!(diffs.len(), 2);
+        let cert_diff = diffs.iter().find(|diff| diff.path == "votor_generated_certs.1").unwrap();
+        assert_eq!(cert_diff.old, None);
+        assert!(cert_diff.new.as_ref().unwrap().contains("\"block\":7"));
+        let partition_diff = diffs.iter().find(|diff| diff.path == "network_partitions").unwrap();
+        assert_eq!(partition_diff.old.as_deref(), Some("[]"));
+        assert_eq!(partition_diff.new.as_deref(), Some("[[0,1]]"));
+        assert_eq!(partition_diff.to_string(), "network_partitions: [] -> [[0,1]]");
+        assert!(cert_diff.to_string().starts_with("votor_generated_certs.1: <absent> -> "));
     }
     
-    /// Create scenario for testing VRF leader selection
-    pub fn create_vrf_test_scenario(config: &Config) -> AlpenglowResult<AlpenglowModel> {
-        let mut model = AlpenglowModel::new(config.clone());
+    #[test]
+    fn test_participating_stake() {
+        let config = Config::new().with_validators(4).with_stake_distribution(
+            [(0, 100), (1, 200), (2, 300), (3, 400)].into_iter().collect()
+        );
+        let mut state = AlpenglowState::init(&config);
+        let vote = |voter: ValidatorId, view: ViewNumber| Vote {
+            voter,
+            slot: 1,
+            view,
+            block: 1,
+            vote_type: VoteType::Commit,
+            signature: voter as Signature,
+            timestamp: 0,
+        };
         
-        // Test leader selection across multiple views
-        for view in 1..=10 {
-            let leader = model.compute_leader_for_view(view);
-            
-            // Create a test block from the selected leader
-            let test_block = Block {
-                slot: view,
-                view,
-                hash: view * 1000 + leader as u64,
-                parent: if view > 1 { (view - 1) * 1000 } else { 0 },
-                proposer: leader,
-                transactions: BTreeSet::new(),
-                timestamp: model.state.clock + view,
-                signature: leader as u64,
-                data: vec![],
-            };
-            
-            model.state.votor_voted_blocks
-                .entry(leader)
-                .or_default()
-                .entry(view)
-                .or_default()
-                .insert(test_block);
-        }
+        // Validator 1's vote reaches two receivers but its stake counts once
+        state.votor_received_votes.entry(0).or_default().entry(1).or_default().extend([vote(1, 1), vote(2, 1)]);
+        state.votor_received_votes.entry(3).or_default().entry(1).or_default().insert(vote(1, 1));
+        state.votor_received_votes.entry(3).or_default().entry(2).or_default().insert(vote(3, 2));
         
-        Ok(model)
+        assert_eq!(state.participating_stake(&config, 1), 500);
+        assert!((state.participation_fraction(&config, 1) - 0.5).abs() < f64::EPSILON);
+        assert_eq!(state.participating_stake(&config, 2), 400);
+        assert_eq!(state.participating_stake(&config, 3), 0);
+        assert_eq!(state.participation_fraction(&config, 3), 0.0);
     }
     
-    /// Create scenario for testing adaptive timeouts
-    pub fn create_adaptive_timeout_scenario(config: &Config) -> AlpenglowResult<AlpenglowModel> {
-        let mut model = AlpenglowModel::new(config.clone());
+    #[test]
+    fn test_finalized_total_order() {
+        let config = Config::new().with_validators(4);
+        let mut state = AlpenglowState::init(&config);
+        let block = |hash: BlockHash, view: ViewNumber, parent: BlockHash| Block {
+            slot: 1,
+            view,
+            hash,
+            parent,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
         
-        // Set up different timeout states for validators
-        for validator in 0..config.validator_count {
-            let validator_id = validator as ValidatorId;
-            let view = (validator + 1) as ViewNumber;
-            
-            // Set different views and timeout expiries
-            model.state.votor_view.insert(validator_id, view);
-            let timeout = model.calculate_timeout(model.state.clock, view);
-            model.state.votor_timeout_expiry.insert(validator_id, timeout);
-        }
+        state.votor_finalized_chain = vec![block(1, 1, 0), block(2, 2, 1), block(3, 3, 2)];
+        assert!(properties::finalized_total_order_detailed(&state, &config).passed);
         
-        Ok(model)
+        // Two blocks at height 2 that both descend from block 1 are concurrent
+        state.votor_finalized_chain = vec![block(1, 1, 0), block(2, 2, 1), block(3, 3, 1)];
+        let result = properties::finalized_total_order_detailed(&state, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("height 2 block 3 (view 3, parent 1) does not extend block 2 (view 2)"));
+        assert!(!properties::finalized_total_order(&state, &config));
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
     
     #[test]
-    fn test_config_creation() {
+    fn test_certificate_resolution() {
         let config = Config::new().with_validators(4);
-        assert_eq!(config.validator_count, 4);
-        assert_eq!(config.byzantine_threshold, 1);
-        assert!(config.validate().is_ok());
+        let mut state = AlpenglowState::init(&config);
+        let cert = |view: ViewNumber, block: BlockHash, cert_type: CertificateType| Certificate {
+            slot: view,
+            view,
+            block,
+            cert_type,
+            validators: (0..4).collect(),
+            stake: config.total_stake,
+            signatures: AggregatedSignature {
+                signers: (0..4).collect(),
+                message: block,
+                signatures: BTreeSet::new(),
+                valid: true,
+            },
+        };
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 1,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        
+        state.votor_generated_certs.entry(1).or_default().insert(cert(1, 1, CertificateType::Fast));
+        state.votor_finalized_chain.push(block);
+        state.votor_generated_certs.entry(2).or_default().insert(cert(2, 2, CertificateType::Slow));
+        state.votor_generated_certs.entry(2).or_default().insert(cert(2, 0, CertificateType::Skip));
+        assert!(properties::certificate_resolution_detailed(&state, &config).passed);
+        
+        // A commit certificate meeting the threshold that is not yet finalized is still in flight
+        let orphan = cert(3, 3, CertificateType::Slow);
+        assert!(orphan.stake >= config.slow_path_threshold);
+        state.votor_generated_certs.entry(3).or_default().insert(orphan.clone());
+        state.votor_view.values_mut().for_each(|view| *view = 3);
+        assert_eq!(properties::certificate_resolutions(&state).iter().find(|(cert, _)| **cert == orphan).unwrap().1, CertificateResolution::Pending);
+        assert!(properties::certificate_resolution_detailed(&state, &config).passed);
+        
+        // Once every validator has left its view it can no longer be finalized
+        state.votor_view.values_mut().for_each(|view| *view = 4);
+        let resolutions = properties::certificate_resolutions(&state);
+        assert_eq!(resolutions.len(), 4);
+        assert_eq!(resolutions.iter().filter(|(_, r)| *r == CertificateResolution::Skipped).count(), 2);
+        assert_eq!(resolutions.iter().find(|(cert, _)| cert.block == 1).unwrap().1, CertificateResolution::Finalized);
+        assert_eq!(resolutions.iter().find(|(cert, _)| **cert == orphan).unwrap().1, CertificateResolution::Orphaned);
+        
+        let result = properties::certificate_resolution_detailed(&state, &config);
+        assert!(!result.passed);
+        assert_eq!(result.counterexample_length, Some(1));
+        assert!(result.error.unwrap().contains("Slow certificate for block 3 in view 3"));
+        assert!(!properties::certificate_resolution(&state, &config));
     }
     
     #[test]
-    fn test_config_validation() {
-        let invalid_config = Config {
-            validator_count: 0,
-            ..Default::default()
-        };
-        assert!(invalid_config.validate().is_err());
+    fn test_liveness_under_clock_fairness() {
+        let config = Config::new().with_validators(3);
+        let clock_advances = |state: &AlpenglowState| state.clock >= 2;
+        
+        // Without fairness the initial state may stutter forever
+        let mut checker = RichModelChecker::new(config.clone());
+        checker.set_exploration_depth(5);
+        let result = checker.check_eventually("ClockAdvances", clock_advances);
+        assert_eq!(result.status, PropertyStatus::Violated);
+        assert_eq!(result.counterexample, Some(vec![]));
+        assert_eq!(result.first_violation_step, Some(0));
+        
+        for fairness in [FairnessSpec::new().weak(ActionClass::AdvanceClock), FairnessSpec::new().strong(ActionClass::AdvanceClock)] {
+            let mut checker = RichModelChecker::new(config.clone()).with_fairness(fairness);
+            checker.set_exploration_depth(5);
+            let result = checker.check_eventually("ClockAdvances", clock_advances);
+            assert_eq!(result.status, PropertyStatus::Satisfied);
+            assert!(result.counterexample.is_none());
+        }
+        
+        // Fairness on an unrelated action class does not rule out the stuttering lasso
+        let mut checker = RichModelChecker::new(config).with_fairness(FairnessSpec::new().weak(ActionClass::AdvanceView));
+        checker.set_exploration_depth(5);
+        assert_eq!(checker.check_eventually("ClockAdvances", clock_advances).status, PropertyStatus::Violated);
     }
     
     #[test]
-    fn test_stake_thresholds() {
+    fn test_leader_has_active_timeout() {
         let config = Config::new().with_validators(4);
-        assert!(config.fast_path_threshold > config.slow_path_threshold);
-        assert!(config.slow_path_threshold > config.total_stake / 2);
+        let mut state = AlpenglowState::init(&config);
+        let leader = utils::leader_for_view(&config, 1);
+        assert!(properties::leader_has_active_timeout_detailed(&state, &config).passed);
+        
+        // A leader with no timeout leaves nothing to skip the view with if it never proposes
+        state.votor_timeout_expiry.remove(&leader);
+        let result = properties::leader_has_active_timeout_detailed(&state, &config);
+        assert!(!result.passed);
+        assert_eq!(result.error.unwrap(), format!("Views may hang on their leader: view 1 leader {} has no timeout", leader));
+        assert!(!properties::leader_has_active_timeout(&state, &config));
+        
+        // An expired timeout can still fire, so the view is not stuck on its leader
+        state.votor_timeout_expiry.insert(leader, 5);
+        state.clock = 5;
+        assert!(properties::leader_has_active_timeout(&state, &config));
+        let timeout = AlpenglowAction::Votor(VotorAction::Timeout { validator: leader });
+        assert!(AlpenglowModel::with_state(config.clone(), state.clone()).action_enabled(&timeout));
+        
+        state.votor_timeout_expiry.remove(&leader);
+        
+        // Once the view is certified its leader no longer needs a timeout
+        state.votor_generated_certs.entry(1).or_default().insert(Certificate {
+            slot: 1,
+            view: 1,
+            block: 1,
+            cert_type: CertificateType::Skip,
+            validators: (0..4).collect(),
+            stake: config.total_stake,
+            signatures: AggregatedSignature {
+                signers: (0..4).collect(),
+                message: 1,
+                signatures: BTreeSet::new(),
+                valid: true,
+            },
+        });
+        assert!(properties::leader_has_active_timeout(&state, &config));
     }
     
     #[test]
-    fn test_model_creation() {
-        let config = Config::new().with_validators(3);
-        let model = create_model(config);
-        assert!(model.is_ok());
+    fn test_fast_path_liveness() {
+        // Five validators of 200 stake; with one offline the others hold exactly the 80% fast-path threshold
+        let config = Config::new().with_validators(5).with_byzantine_threshold(0);
+        let leader = utils::leader_for_view(&config, 1);
+        let offline = (leader + 1) % 5;
+        let mut model = AlpenglowModel::new(config.clone());
+        model.state.failure_states.insert(offline, ValidatorStatus::Offline);
+        
+        // Before GST the property does not apply
+        let result = properties::fast_path_liveness_detailed(&model.state, &config);
+        assert!(result.passed);
+        assert!(result.error.unwrap().contains("before GST"));
+        
+        model.state.clock = config.gst;
+        let result = properties::fast_path_liveness_detailed(&model.state, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("No fast certificate for view 1"));
+        
+        // Without enough responsive stake it does not apply either
+        let mut degraded = model.state.clone();
+        degraded.failure_states.insert((leader + 2) % 5, ValidatorStatus::Offline);
+        let result = properties::fast_path_liveness_detailed(&degraded, &config);
+        assert!(result.passed);
+        assert!(result.error.unwrap().contains("responsive honest stake 600 is below the fast-path threshold 800"));
+        
+        // One round of proposal, votes from the responsive validators and aggregation yields a fast certificate
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: leader, view: 1 })).unwrap();
+        let block = model.state.votor_voted_blocks[&leader][&1].iter().next().unwrap().clone();
+        for validator in (0..5).filter(|v| *v != offline) {
+            let vote = VotorAction::CastVote { validator, block: block.clone(), view: 1 };
+            model.state = model.execute_action(AlpenglowAction::Votor(vote)).unwrap();
+        }
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CollectVotes { validator: leader, view: 1 })).unwrap();
+        let result = properties::fast_path_liveness_detailed(&model.state, &config);
+        assert!(result.passed);
+        assert!(result.error.is_none());
+        assert!(properties::fast_path_liveness(&model.state, &config));
     }
     
     #[test]
-    fn test_alpenglow_state_init() {
-        let config = Config::new().with_validators(3);
-        let state = AlpenglowState::init(&config);
+    fn test_slow_path_liveness() {
+        // Twenty validators of 50 stake; with seven offline exactly 65% of stake is responsive
+        let config = Config::new().with_validators(20).with_byzantine_threshold(0);
+        let leader = utils::leader_for_view(&config, 1);
+        let offline: Vec<ValidatorId> = (0..20).filter(|v| *v != leader).take(7).collect();
+        let mut model = AlpenglowModel::new(config.clone());
+        for validator in &offline {
+            model.state.failure_states.insert(*validator, ValidatorStatus::Offline);
+        }
+        model.state.clock = config.gst;
+        
+        // The fast path no longer applies, and the view is not yet certified
+        assert!(properties::fast_path_liveness_detailed(&model.state, &config).error.unwrap().contains("not applicable"));
+        let result = properties::slow_path_liveness_detailed(&model.state, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("with 65.0% of stake responsive"));
+        
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: leader, view: 1 })).unwrap();
+        let block = model.state.votor_voted_blocks[&leader][&1].iter().next().unwrap().clone();
+        for validator in (0..20).filter(|v| !offline.contains(v)) {
+            let vote = VotorAction::CastVote { validator, block: block.clone(), view: 1 };
+            model.state = model.execute_action(AlpenglowAction::Votor(vote)).unwrap();
+        }
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CollectVotes { validator: leader, view: 1 })).unwrap();
         
-        assert_eq!(state.clock, 0);
-        assert_eq!(state.current_slot, 1);
-        assert_eq!(state.votor_view.len(), 3);
-        assert!(state.votor_finalized_chain.is_empty());
+        // The view degrades to a slow certificate instead of stalling
+        let certs = &model.state.votor_generated_certs[&1];
+        assert!(certs.iter().all(|cert| cert.cert_type == CertificateType::Slow && cert.stake == 650));
+        let result = properties::slow_path_liveness_detailed(&model.state, &config);
+        assert!(result.passed);
+        assert!(result.error.is_none());
+        
+        // With fast-path responsive stake the slow path property does not apply
+        let mut responsive = model.state.clone();
+        responsive.failure_states.clear();
+        assert!(properties::slow_path_liveness_detailed(&responsive, &config).error.unwrap().contains("meets the fast-path threshold"));
     }
     
     #[test]
-    fn test_leader_selection() {
-        let config = Config::new().with_validators(4);
-        let model = AlpenglowModel::new(config);
+    fn test_skip_votes_aggregate_into_certificate() {
+        let config = Config::new().with_validators(4).with_byzantine_threshold(0);
+        let mut model = AlpenglowModel::new(config.clone());
+        let add_skip_vote = |model: &mut AlpenglowModel, voter: ValidatorId| {
+            let skip_vote = Vote {
+                voter,
+                slot: 1,
+                view: 1,
+                block: 0,
+                vote_type: VoteType::Skip,
+                signature: voter as u64,
+                timestamp: model.state.clock,
+            };
+            model.state.votor_skip_votes.get_mut(&0).unwrap().entry(1).or_default().insert(skip_vote);
+        };
+        let collect = AlpenglowAction::Votor(VotorAction::CollectSkipVotes { validator: 0, view: 1 });
         
-        // Test deterministic leader selection
-        let leader1 = model.compute_leader_for_view(1);
-        let leader2 = model.compute_leader_for_view(1);
-        assert_eq!(leader1, leader2);
+        // Half the stake is below the slow-path threshold
+        add_skip_vote(&mut model, 1);
+        add_skip_vote(&mut model, 2);
+        let state = model.execute_action(collect.clone()).unwrap();
+        assert!(state.votor_generated_certs.get(&1).is_none_or(|certs| certs.is_empty()));
         
-        // Different views may have different leaders
-        let leader_view2 = model.compute_leader_for_view(2);
-        // Leaders can be the same or different, but selection should be deterministic
-        assert_eq!(model.compute_leader_for_view(2), leader_view2);
+        add_skip_vote(&mut model, 3);
+        model.state = model.execute_action(collect).unwrap();
+        let certs: Vec<_> = model.state.votor_generated_certs[&1].iter().collect();
+        assert_eq!(certs.len(), 1);
+        assert_eq!(certs[0].cert_type, CertificateType::Skip);
+        assert_eq!(certs[0].validators, BTreeSet::from([1, 2, 3]));
+        assert_eq!(certs[0].signatures.signers, certs[0].validators);
+        assert_eq!(certs[0].stake, 750);
+        assert!(properties::certificate_validity(&model.state, &config));
     }
     
     #[test]
-    fn test_erasure_encoding() {
-        let config = Config::new().with_validators(4).with_erasure_coding(2, 4);
-        let model = AlpenglowModel::new(config);
+    fn test_observer_validates_without_voting() {
+        let base = Config::new().with_validators_exact_stake(3);
+        let config = base.clone().with_observer();
+        assert_eq!(config.validator_count, 4);
+        assert_eq!(config.total_stake, base.total_stake);
+        assert_eq!(config.fast_path_threshold, base.fast_path_threshold);
+        assert_eq!(config.slow_path_threshold, base.slow_path_threshold);
         
+        let observer = 3;
+        let mut model = AlpenglowModel::new(config.clone());
+        assert_eq!(model.state.failure_states[&observer], ValidatorStatus::Observer);
         let block = Block {
             slot: 1,
             view: 1,
-            hash: 123,
+            hash: 1,
             parent: 0,
             proposer: 0,
             transactions: BTreeSet::new(),
             timestamp: 0,
-            signature: 456,
+            signature: 0,
             data: vec![],
         };
+        assert!(!model.action_enabled(&AlpenglowAction::Votor(VotorAction::CastVote { validator: observer, block: block.clone(), view: 1 })));
+        assert!(!model.action_enabled(&AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: observer, view: 1 })));
         
-        let shreds = model.erasure_encode(&block);
-        assert_eq!(shreds.len(), 4);
+        for validator in 0..3 {
+            let vote = VotorAction::CastVote { validator, block: block.clone(), view: 1 };
+            model.state = model.execute_action(AlpenglowAction::Votor(vote)).unwrap();
+        }
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CollectVotes { validator: 0, view: 1 })).unwrap();
         
-        // Check data shreds
-        let data_shreds: Vec<_> = shreds.iter().filter(|s| !s.is_parity).collect();
-        assert_eq!(data_shreds.len(), 2);
-        assert!(data_shreds.iter().all(|s| s.index <= 2));
+        // The observer hears every vote but is absent from the certificate
+        assert_eq!(model.state.votor_received_votes[&observer][&1].len(), 3);
+        let cert = model.state.votor_generated_certs[&1].iter().next().unwrap();
+        assert_eq!(cert.stake, base.total_stake);
+        assert!(!cert.validators.contains(&observer));
         
-        // Check parity shreds
-        let parity_shreds: Vec<_> = shreds.iter().filter(|s| s.is_parity).collect();
-        assert_eq!(parity_shreds.len(), 2);
-        assert!(parity_shreds.iter().all(|s| s.index > 2));
+        // The checker runs the observer's safety checks on every explored state
+        let mut checker = RichModelChecker::new(config);
+        checker.set_max_states(4);
+        let result = checker.verify_with_progress(std::io::sink()).unwrap();
+        let metrics = &result.observer_metrics[&observer];
+        assert_eq!(metrics.states_validated, result.total_states_explored as u64);
+        assert!(metrics.violations.is_empty(), "Unexpected violations: {:?}", metrics.violations);
     }
     
     #[test]
-    fn test_action_execution() {
+    fn test_view_monotonic_per_validator() {
         let config = Config::new().with_validators(3);
-        let model = AlpenglowModel::new(config);
+        let model = AlpenglowModel::new(config.clone());
+        let checker = RichModelChecker::new(config.clone());
         
-        // Test clock advancement
-        let new_state = model.execute_action(AlpenglowAction::AdvanceClock).unwrap();
-        assert_eq!(new_state.clock, 1);
+        let mut previous = model.state.clone();
+        previous.clock = config.timeout_delta;
+        let action = AlpenglowAction::AdvanceView { validator: 1 };
+        let advanced = model.next_state(&previous, action.clone()).unwrap();
+        assert!(properties::view_monotonic_per_validator_detailed(&previous, &advanced, &config).passed);
         
-        // Test view advancement
-        let validator = 0;
-        let new_state = model.execute_action(AlpenglowAction::AdvanceView { validator }).unwrap();
-        assert_eq!(new_state.votor_view.get(&validator).copied().unwrap_or(1), 2);
+        // A buggy transition that moves validator 1 back to view 1
+        let mut regressed = advanced.clone();
+        regressed.votor_view.insert(1, 1);
+        let result = properties::view_monotonic_per_validator_detailed(&advanced, &regressed, &config);
+        assert!(!result.passed);
+        assert_eq!(result.error.unwrap(), "View regressed: validator 1 from view 2 to 1");
+        
+        let (results, violations) = checker.check_transition_properties(&advanced, &action, &regressed);
+        assert_eq!(results["ViewMonotonicPerValidator"].status, PropertyStatus::Violated);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].state, regressed);
     }
     
     #[test]
-    fn test_safety_properties() {
-        let config = Config::new().with_validators(3);
-        let state = AlpenglowState::init(&config);
+    fn test_violation_reporters_receive_each_violation() {
+        use std::sync::Mutex;
+        
+        // Stake that does not add up to the configured total violates stake conservation in every state
+        let mut config = Config::new();
+        config.total_stake += 1;
+        let mut checker = RichModelChecker::new(config);
+        checker.set_exploration_depth(2);
+        
+        let captured: Arc<Mutex<Vec<PropertyViolation>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink = captured.clone();
+        checker.add_violation_reporter(CallbackReporter::new(move |violation, _config| {
+            sink.lock().unwrap().push(violation.clone());
+        }));
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("violations.jsonl");
+        checker.add_violation_reporter(JsonFileReporter::new(&log_path));
+        checker.add_violation_reporter(LogReporter);
+        
+        let result = checker.verify_with_progress(std::io::sink()).unwrap();
+        let captured = captured.lock().unwrap();
+        assert!(!captured.is_empty());
+        assert_eq!(*captured, result.violations_found);
+        assert!(captured.iter().any(|violation| violation.property_name == "TotalStakeConserved"));
+        
+        // The file reporter records each violation with its counterexample state
+        let logged: Vec<PropertyViolation> = fs::read_to_string(&log_path).unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(logged, *captured);
         
-        assert!(properties::safety_no_conflicting_finalization(&state));
-        assert!(properties::chain_consistency(&state));
-        assert!(properties::bandwidth_safety(&state, &config));
-        assert!(properties::erasure_coding_validity(&state, &config));
+        // A reporter that cannot write aborts the run instead of dropping violations
+        let mut failing = RichModelChecker::new(checker.config.clone());
+        failing.add_violation_reporter(JsonFileReporter::new(dir.path().join("missing").join("violations.jsonl")));
+        let error = failing.verify_with_progress(std::io::sink()).unwrap_err();
+        assert!(matches!(error, AlpenglowError::IoError(ref message) if message.contains("Failed to report violation")));
     }
     
     #[test]
-    fn test_model_checker() {
-        let config = Config::new().with_validators(3);
-        let model = AlpenglowModel::new(config.clone());
-        let mut checker = ModelChecker::new(config);
+    fn test_validator_phase() {
+        let config = Config::new().with_validators(4);
+        let mut model = AlpenglowModel::new(config.clone());
+        let leader = utils::leader_for_view(&config, 1);
+        let follower = (leader + 1) % 4;
         
-        let metrics = checker.verify_model(&model).unwrap();
-        assert!(metrics.properties_checked > 0);
-        assert_eq!(metrics.violations, 0);
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: leader, view: 1 })).unwrap();
+        assert_eq!(model.state.validator_phase(leader, &config), ValidatorPhase::Proposing);
+        assert_eq!(model.state.validator_phase(follower, &config), ValidatorPhase::Voting);
+        
+        let block = model.state.votor_voted_blocks[&leader][&1].iter().next().cloned().unwrap();
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CastVote { validator: follower, block, view: 1 })).unwrap();
+        assert_eq!(model.state.validator_phase(follower, &config), ValidatorPhase::AwaitingVotes);
+        
+        // Past the timeout with no certificate, a validator that never voted has timed out
+        let idle = (leader + 2) % 4;
+        model.state.clock = model.state.votor_timeout_expiry[&idle];
+        assert_eq!(model.state.validator_phase(idle, &config), ValidatorPhase::TimedOut);
     }
     
     #[test]
-    fn test_property_detailed_results() {
-        let config = Config::new().with_validators(3);
-        let state = AlpenglowState::init(&config);
+    fn test_certificate_signer_vote_consistency() {
+        let config = Config::new().with_validators(4).with_byzantine_threshold(0);
+        let mut model = AlpenglowModel::new(config.clone());
+        let leader = utils::leader_for_view(&config, 1);
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: leader, view: 1 })).unwrap();
+        let block = model.state.votor_voted_blocks[&leader][&1].iter().next().cloned().unwrap();
+        let other = Block { hash: block.hash + 1, ..block.clone() };
+        
+        // Three validators vote for the proposal and one for a different block in the same view
+        for voter in 0..3 {
+            model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CastVote { validator: voter, block: block.clone(), view: 1 })).unwrap();
+        }
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CastVote { validator: 3, block: other.clone(), view: 1 })).unwrap();
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CollectVotes { validator: 0, view: 1 })).unwrap();
+        
+        let certs = &model.state.votor_generated_certs[&1];
+        assert_eq!(certs.len(), 1);
+        let cert = certs.iter().next().unwrap().clone();
+        assert_eq!(cert.block, block.hash);
+        assert_eq!(cert.cert_type, CertificateType::Slow);
+        assert_eq!(cert.validators, (0..3).collect());
+        assert!(properties::certificate_signer_vote_consistency_detailed(&model.state, &config).passed);
+        
+        // A certificate lumping in the vote for the other block names that signer
+        let mut lumped = cert;
+        lumped.validators.insert(3);
+        model.state.votor_generated_certs.get_mut(&1).unwrap().insert(lumped);
+        let result = properties::certificate_signer_vote_consistency_detailed(&model.state, &config);
+        assert!(!result.passed);
+        assert_eq!(result.counterexample_length, Some(1));
+        assert!(result.error.unwrap().contains(&format!("signer 3 of the view 1 certificate for block {} voted for {{{}}}", block.hash, other.hash)));
+        assert!(!properties::certificate_signer_vote_consistency(&model.state, &config));
+    }
+    
+    #[test]
+    #[cfg(feature = "parallel-verification")]
+    fn test_verify_model_parallel() {
+        let mut serial = RichModelChecker::new(Config::new().with_validators(7));
+        serial.set_max_states(400);
+        serial.set_exploration_depth(10);
+        let mut parallel = serial.clone();
+        
+        let expected = serial.verify_with_progress(std::io::sink()).unwrap();
+        let result = parallel.verify_model_parallel(4).unwrap();
+        assert_eq!(result.total_states_explored, expected.total_states_explored);
+        assert_eq!(result.property_results, expected.property_results);
+        assert_eq!(result.violations_found, expected.violations_found);
+        assert_eq!(result.max_depth, expected.max_depth);
+        assert_eq!(result.avg_branching_factor, expected.avg_branching_factor);
+    }
+    
+    #[test]
+    fn test_depth_first_search() {
+        let config = Config::new().with_validators(4).with_byzantine_threshold(0);
+        let finalized = |result: &VerificationResult| {
+            result.collected_states.iter().any(|info| !info.state.votor_finalized_chain.is_empty())
+        };
+        let run = |search: SearchStrategy, strategy: ExplorationStrategy| {
+            let mut checker = RichModelChecker::new(config.clone());
+            checker.set_max_states(200);
+            checker.set_exploration_depth(40);
+            checker.set_search_strategy(search);
+            checker.set_strategy(strategy);
+            checker.enable_state_collection();
+            checker.verify_with_progress(std::io::sink()).unwrap()
+        };
+        let bfs = run(SearchStrategy::Bfs, ExplorationStrategy::Bfs);
+        let dfs = run(SearchStrategy::Dfs { max_depth: 40 }, ExplorationStrategy::Bfs);
+        let guided = run(SearchStrategy::Dfs { max_depth: 40 }, ExplorationStrategy::Guided(|state| {
+            let votes: usize = state.votor_voted_blocks.values().flat_map(|views| views.values()).map(|blocks| blocks.len()).sum();
+            let certs: usize = state.votor_generated_certs.values().map(|certs| certs.len()).sum();
+            (state.votor_finalized_chain.len() * 1000 + certs * 100 + votes) as i64
+        }));
+        
+        // Breadth-first, 200 states do not get past the first few levels
+        assert!(!finalized(&bfs));
+        assert!(bfs.max_depth < 10);
+        
+        // Depth-first reaches its own depth bound holding one frame per level of its path
+        assert_eq!(dfs.max_depth, 40);
+        assert!(dfs.performance_metrics.peak_queue_size <= 41);
+        
+        // Preferring votes, certificates and finalizations reaches a finalized block
+        assert!(finalized(&guided));
+        
+        // States first reached deep are explored again from shallower paths, so a bounded
+        // depth-first search covers the same states as breadth-first search to that depth
+        let explored = |search: SearchStrategy| {
+            let mut checker = RichModelChecker::new(config.clone());
+            checker.set_exploration_depth(5);
+            checker.set_search_strategy(search);
+            checker.verify_with_progress(std::io::sink()).unwrap().total_states_explored
+        };
+        assert_eq!(explored(SearchStrategy::Dfs { max_depth: 5 }), explored(SearchStrategy::Bfs));
+        
+        // A violation found deep carries the trace that reproduces it
+        let mut config = config;
+        config.timeout_delta = 0;
+        let mut checker = RichModelChecker::new(config.clone());
+        checker.set_max_states(50);
+        checker.set_search_strategy(SearchStrategy::Dfs { max_depth: 10 });
+        checker.set_strategy(ExplorationStrategy::Guided(|state| {
+            state.votor_view_advances.values().map(|advances| advances.len() as i64).sum()
+        }));
+        let result = checker.verify_with_progress(std::io::sink()).unwrap();
+        let premature = &result.property_results["NoPrematureViewAdvance"];
+        assert_eq!(premature.status, PropertyStatus::Violated);
+        let trace = premature.counterexample.clone().unwrap();
+        assert_eq!(premature.first_violation_step, Some(trace.len()));
+        let model = AlpenglowModel::new(config);
+        let end = trace.into_iter().fold(model.state.clone(), |state, action| model.next_state(&state, action).unwrap());
+        assert!(!properties::no_premature_view_advance(&end, &model.config));
         
-        let result = properties::safety_no_conflicting_finalization_detailed(&state, &config);
-        assert!(result.passed);
-        assert!(result.error.is_none());
+        // Violating states are only kept when violation collection is enabled
+        assert!(result.violations_found.is_empty());
+        checker.enable_violation_collection();
+        let result = checker.verify_with_progress(std::io::sink()).unwrap();
+        assert!(result.violations_found.iter().any(|violation| violation.property_name == "NoPrematureViewAdvance"));
+    }
+    
+    #[test]
+    fn test_exploration_strategy() {
+        // Zero timeouts let validator 0 leave two views at the same clock, a premature advance
+        let mut config = Config::new().with_validators(4).with_byzantine_threshold(0);
+        config.timeout_delta = 0;
+        
+        // Fewest explored states at which the planted violation is found
+        let states_to_violation = |search: SearchStrategy, strategy: ExplorationStrategy| {
+            (1..=50).find(|&max_states| {
+                let mut checker = RichModelChecker::new(config.clone());
+                checker.set_exploration_depth(10);
+                checker.set_max_states(max_states);
+                checker.set_search_strategy(search);
+                checker.set_strategy(strategy);
+                let result = checker.verify_with_progress(std::io::sink()).unwrap();
+                result.property_results["NoPrematureViewAdvance"].status == PropertyStatus::Violated
+            }).unwrap()
+        };
         
-        let result = properties::liveness_eventual_progress_detailed(&state, &config);
-        assert!(!result.passed); // No blocks finalized yet
-        assert!(result.error.is_some());
+        let bfs = states_to_violation(SearchStrategy::Bfs, ExplorationStrategy::Bfs);
+        let dfs = states_to_violation(SearchStrategy::Dfs { max_depth: 10 }, ExplorationStrategy::Bfs);
+        assert!(dfs < bfs, "DFS needed {} states, BFS {}", dfs, bfs);
+        
+        // A heuristic preferring states with more view advances goes straight to the violation
+        let guided = states_to_violation(SearchStrategy::Bfs, ExplorationStrategy::Guided(|state| {
+            state.votor_view_advances.values().map(|advances| advances.len() as i64).sum()
+        }));
+        assert!(guided < bfs);
+        
+        // Every order explores the same states when the whole space fits the bounds
+        let explored = |strategy: ExplorationStrategy| {
+            let mut checker = RichModelChecker::new(Config::new().with_validators(4).with_byzantine_threshold(0));
+            checker.set_exploration_depth(5);
+            checker.set_strategy(strategy);
+            checker.verify_with_progress(std::io::sink()).unwrap().total_states_explored
+        };
+        assert_eq!(explored(ExplorationStrategy::Guided(|state| state.clock as i64)), explored(ExplorationStrategy::Bfs));
     }
     
     #[test]
-    fn test_config_json_conversion() {
-        let config = Config::new().with_validators(4);
-        let json_value = serde_json::to_value(&config).unwrap();
-        let converted_config = Config::try_from(json_value).unwrap();
-        assert_eq!(config, converted_config);
+    fn test_aggregation_batching() {
+        let base = Config::new().with_validators(4).with_byzantine_threshold(0);
+        let collect = AlpenglowAction::Votor(VotorAction::CollectVotes { validator: 0, view: 1 });
+        let voted_model = |config: &Config, voters: u32| {
+            let mut model = AlpenglowModel::new(config.clone());
+            let leader = utils::leader_for_view(config, 1);
+            model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: leader, view: 1 })).unwrap();
+            let block = model.state.votor_voted_blocks[&leader][&1].iter().next().cloned().unwrap();
+            for voter in 0..voters {
+                model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CastVote { validator: voter, block: block.clone(), view: 1 })).unwrap();
+            }
+            model.state.clock = 3;
+            model
+        };
+        
+        // Unbatched, the first vote crossing the slow threshold forms a certificate
+        let model = voted_model(&base, 3);
+        let state = model.execute_action(collect.clone()).unwrap();
+        assert_eq!(state.votor_generated_certs[&1].iter().next().unwrap().cert_type, CertificateType::Slow);
+        
+        // Batched by vote count, aggregation waits for the fourth vote
+        let config = base.clone().with_aggregation_batching(AggregationBatching::VoteCount(4));
+        assert!(config.validate().is_ok());
+        let model = voted_model(&config, 3);
+        assert!(!model.action_enabled(&collect));
+        let model = voted_model(&config, 4);
+        let state = model.execute_action(collect.clone()).unwrap();
+        assert_eq!(state.votor_generated_certs[&1].len(), 1);
+        assert_eq!(state.votor_generated_certs[&1].iter().next().unwrap().cert_type, CertificateType::Fast);
+        
+        // A batch counts the votes since the last aggregation, even when several arrive at once
+        let config = Config::new().with_validators(6).with_aggregation_batching(AggregationBatching::VoteCount(2));
+        let mut model = voted_model(&config, 2);
+        model.state = model.execute_action(collect.clone()).unwrap();
+        assert_eq!(model.state.votor_aggregated_votes[&0][&1], 2);
+        let block = model.state.votor_voted_blocks[&utils::leader_for_view(&config, 1)][&1].iter().next().cloned().unwrap();
+        for voter in 2..5 {
+            if voter == 3 {
+                assert!(!model.action_enabled(&collect));
+            }
+            model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CastVote { validator: voter, block: block.clone(), view: 1 })).unwrap();
+        }
+        assert!(model.action_enabled(&collect));
+        
+        // Batched by interval, aggregation waits for the next clock boundary
+        let config = base.clone().with_aggregation_batching(AggregationBatching::Interval(5));
+        let mut model = voted_model(&config, 3);
+        assert!(!model.action_enabled(&collect));
+        model.state.clock = 5;
+        let state = model.execute_action(collect).unwrap();
+        assert_eq!(state.votor_generated_certs[&1].len(), 1);
+        
+        assert!(base.with_aggregation_batching(AggregationBatching::Interval(0)).validate().is_err());
     }
     
     #[test]
-    fn test_byzantine_scenario_creation() {
-        let config = Config::new().with_validators(4);
-        let byzantine_validators = vec![0, 1];
-        let model = utils::create_byzantine_scenario(&config, &byzantine_validators).unwrap();
+    fn test_ranked_violations() {
+        let state = AlpenglowState::init(&Config::new());
+        let violation = |property_name: &str, violation_step: usize| PropertyViolation {
+            property_name: property_name.to_string(),
+            violation_step,
+            state: state.clone(),
+            action: AlpenglowAction::AdvanceClock,
+            description: String::new(),
+        };
+        let result = VerificationResult {
+            property_results: HashMap::new(),
+            collected_states: Vec::new(),
+            verification_time_ms: 0,
+            total_states_explored: 0,
+            violations_found: vec![
+                violation("ThroughputOptimization", 1),
+                violation("BandwidthResetsPerRound", 5),
+                violation("VotorSafety", 7),
+                violation("chain_consistency", 3),
+                violation("SafetyNoDoubleVoting", 9),
+                violation("SafetyValidCertificates", 2),
+            ],
+            performance_metrics: PerformanceMetrics {
+                states_per_second: 0.0,
+                memory_usage_mb: 0.0,
+                peak_queue_size: 0,
+                property_check_time_ms: HashMap::new(),
+            },
+            max_depth: 0,
+            avg_branching_factor: 0.0,
+            warnings: Vec::new(),
+            observer_metrics: BTreeMap::new(),
+        };
         
+        // Critical safety first, shortest counterexample breaking ties; performance last
+        let ranked: Vec<_> = result.ranked_violations().into_iter()
+            .map(|violation| (violation.property_name, violation.violation_step))
+            .collect();
+        assert_eq!(ranked, vec![
+            ("SafetyValidCertificates".to_string(), 2),
+            ("chain_consistency".to_string(), 3),
+            ("VotorSafety".to_string(), 7),
+            ("SafetyNoDoubleVoting".to_string(), 9),
+            ("BandwidthResetsPerRound".to_string(), 5),
+            ("ThroughputOptimization".to_string(), 1),
+        ]);
+    }
+    
+    #[test]
+    fn test_vote_gossip_delays_certificates() {
+        let config = Config::new().with_validators(3).with_vote_gossip_delay(20);
         assert_eq!(
-            model.state.failure_states.get(&0),
-            Some(&ValidatorStatus::Byzantine)
+            config.clone().with_byzantine_threshold(0).with_vote_gossip_delay(config.delta + 1).validate(),
+            Err(AlpenglowError::InvalidConfig("Vote gossip delay exceeds Delta".to_string()))
         );
+        let mut model = AlpenglowModel::new(config.clone());
+        let block = Block {
+            slot: 1,
+            view: 1,
+            hash: 1,
+            parent: 0,
+            proposer: 0,
+            transactions: BTreeSet::new(),
+            timestamp: 0,
+            signature: 0,
+            data: vec![],
+        };
+        for validator in 0..3 {
+            let vote = VotorAction::CastVote { validator, block: block.clone(), view: 1 };
+            model.state = model.execute_action(AlpenglowAction::Votor(vote)).unwrap();
+        }
+        
+        // Votes are in flight, so nobody has seen them and no certificate can form yet
+        assert!(model.state.votor_received_votes.values().all(|views| views.get(&1).map_or(true, |votes| votes.is_empty())));
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CollectVotes { validator: 0, view: 1 })).unwrap();
+        assert!(model.state.votor_generated_certs.get(&1).map_or(true, |certs| certs.is_empty()));
+        
+        let in_flight: Vec<_> = model.state.network_message_queue.iter().cloned().collect();
+        assert_eq!(in_flight.len(), 3);
+        model.state.clock = 19;
+        assert!(!model.action_enabled(&AlpenglowAction::Network(NetworkAction::DeliverMessage { message: in_flight[0].clone() })));
+        
+        // Once the delay has passed the votes arrive and a certificate forms
+        model.state.clock = 20;
+        for message in in_flight {
+            model.state = model.execute_action(AlpenglowAction::Network(NetworkAction::DeliverMessage { message })).unwrap();
+        }
+        assert_eq!(model.state.votor_received_votes[&0][&1].len(), 3);
+        assert!(model.state.network_delivery_time.values().all(|delay| *delay == 20));
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CollectVotes { validator: 0, view: 1 })).unwrap();
+        assert_eq!(model.state.votor_generated_certs[&1].len(), 1);
+    }
+    
+    #[test]
+    fn test_delta_bounded_delivery() {
+        let config = Config::new().with_validators(4);
+        let mut model = AlpenglowModel::new(config.clone());
+        let message = |id: u64, timestamp: TimeValue| NetworkMessage {
+            id,
+            msg_type: MessageType::Block,
+            sender: 0,
+            recipient: MessageRecipient::Broadcast,
+            payload: vec![],
+            timestamp,
+            signature: 0,
+        };
+        let deliver = |message: NetworkMessage| AlpenglowAction::Network(NetworkAction::DeliverMessage { message });
+        
+        // Delivered in time
+        model.state.enqueue_message(message(1, 0));
+        model.state.clock = config.max_network_delay;
+        model.state = model.execute_action(deliver(message(1, 0))).unwrap();
+        assert_eq!(model.state.network_delivery_time[&message(1, 0)], config.max_network_delay);
+        assert!(properties::delta_bounded_delivery_detailed(&model.state, &config).passed);
+        
+        // Before GST a message may sit in the queue past the bound, since it is due Delta after GST
+        model.state.enqueue_message(message(2, 10));
+        model.state.clock = 10 + config.max_network_delay;
+        assert!(model.action_enabled(&AlpenglowAction::AdvanceClock));
+        model.state = model.execute_action(AlpenglowAction::AdvanceClock).unwrap();
+        model.state = model.execute_action(deliver(message(2, 10))).unwrap();
+        assert_eq!(model.state.network_delivery_time[&message(2, 10)], config.max_network_delay + 1);
+        assert!(properties::delta_bounded_delivery_detailed(&model.state, &config).passed);
+        
+        // After GST the clock waits for a message that has been in flight for Delta
+        let sent = config.gst + 5;
+        model.state.enqueue_message(message(3, sent));
+        assert_eq!(model.delivery_deadline(&message(3, sent)), sent + config.delta);
+        model.state.clock = sent + config.delta - 1;
+        assert!(model.action_enabled(&AlpenglowAction::AdvanceClock));
+        model.state.clock = sent + config.delta;
+        assert!(!model.action_enabled(&AlpenglowAction::AdvanceClock));
+        model.state = model.execute_action(deliver(message(3, sent))).unwrap();
+        assert!(model.action_enabled(&AlpenglowAction::AdvanceClock));
+        
+        // Messages sent before GST are due Delta after GST
+        assert_eq!(model.delivery_deadline(&message(4, 0)), config.gst + config.delta);
+        
+        // A message delivered after its deadline is flagged against the same deadline
+        model.state.enqueue_message(message(5, sent));
+        model.state.clock = sent + config.delta + 1;
+        model.state = model.execute_action(deliver(message(5, sent))).unwrap();
+        let result = properties::delta_bounded_delivery_detailed(&model.state, &config);
+        assert!(!result.passed);
+        assert!(!properties::delta_bounded_delivery(&model.state, &config));
+        assert_eq!(result.counterexample_length, Some(1));
         assert_eq!(
-            model.state.failure_states.get(&1),
-            Some(&ValidatorStatus::Byzantine)
+            result.error.unwrap(),
+            format!(
+                "Found 1 messages delivered after their Delta deadline: message 5 from validator 0 ({} ticks, due at {})",
+                config.delta + 1, model.delivery_deadline(&message(5, sent))
+            )
         );
     }
     
     #[test]
-    fn test_network_partition_scenario() {
-        let config = Config::new().with_validators(4);
-        let partition1: BTreeSet<ValidatorId> = [0, 1].iter().cloned().collect();
-        let partition2: BTreeSet<ValidatorId> = [2, 3].iter().cloned().collect();
-        let partitions = vec![partition1.clone(), partition2];
+    fn test_byzantine_leader_delays_messages() {
+        // The Byzantine leader holds half the stake, so no certificate forms without its vote
+        let stakes = |leader: ValidatorId| (0..4).map(|v| (v, if v == leader { 300 } else { 100 })).collect();
+        let (leader, config) = (0..4)
+            .map(|leader| (leader, Config::new().with_validators(4).with_stake_distribution(stakes(leader)).with_vote_gossip_delay(10)))
+            .find(|(leader, config)| utils::leader_for_view(config, 1) == *leader)
+            .unwrap();
+        let honest: Vec<ValidatorId> = (0..4).filter(|v| *v != leader).collect();
+        let mut model = AlpenglowModel::new(config.clone());
+        model.set_byzantine(leader, ByzantineStrategy::WithholdShreds).unwrap();
         
-        let model = utils::create_network_partition_scenario(&config, partitions).unwrap();
-        assert!(model.state.network_partitions.contains(&partition1));
+        model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: leader, view: 1 })).unwrap();
+        let block = model.state.votor_voted_blocks[&leader][&1].iter().next().unwrap().clone();
+        for validator in 0..4 {
+            let vote = VotorAction::CastVote { validator, block: block.clone(), view: 1 };
+            model.state = model.execute_action(AlpenglowAction::Votor(vote)).unwrap();
+        }
+        let collect = |model: &mut AlpenglowModel| {
+            model.state.clock = 10;
+            let queued: Vec<_> = model.state.network_message_queue.iter().cloned().collect();
+            for message in queued {
+                let deliver = AlpenglowAction::Network(NetworkAction::DeliverMessage { message });
+                if model.action_enabled(&deliver) {
+                    model.state = model.execute_action(deliver).unwrap();
+                }
+            }
+            model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::CollectVotes { validator: honest[0], view: 1 })).unwrap();
+            model.state.votor_generated_certs.get(&1).is_some_and(|certs| !certs.is_empty())
+        };
+        
+        // Delivered on time, the leader's vote completes a certificate long before any timeout
+        assert!(collect(&mut model.clone()));
+        
+        // Delayed, it is still in flight when the honest validators' timeouts expire
+        let delay = AlpenglowAction::Byzantine(ByzantineAction::DelayMessages { validator: leader, delay: 200 });
+        assert!(model.action_enabled(&delay));
+        model.state = model.execute_action(delay).unwrap();
+        assert!(!collect(&mut model));
+        let in_flight: Vec<_> = model.state.network_message_queue.iter().collect();
+        assert_eq!(in_flight.len(), 1);
+        assert_eq!(in_flight[0].sender, leader);
+        assert_eq!(model.earliest_delivery(&model.state, in_flight[0]), 210);
+        
+        model.state.clock = config.timeout_delta;
+        for &validator in &honest {
+            let skip = AlpenglowAction::Votor(VotorAction::SubmitSkipVote { validator, view: 1 });
+            assert!(model.action_enabled(&skip));
+            model.state = model.execute_action(skip).unwrap();
+            assert_eq!(model.state.votor_skip_votes[&validator][&1].len(), 1);
+            assert_eq!(model.state.votor_view[&validator], 2);
+        }
+        assert!(model.state.votor_generated_certs.get(&1).is_none_or(|certs| certs.is_empty()));
+        
+        // Only Byzantine validators can delay messages
+        assert!(!model.action_enabled(&AlpenglowAction::Byzantine(ByzantineAction::DelayMessages { validator: honest[0], delay: 200 })));
     }
     
     #[test]
-    fn test_config_builder_methods() {
-        let config = Config::new()
-            .with_validators(5)
-            .with_exploration_depth(2000)
-            .with_timeout(60000)
-            .with_test_mode(true)
-            .with_leader_window_size(8)
-            .with_adaptive_timeouts(false)
-            .with_vrf_enabled(false);
+    fn test_double_vote_records_conflicting_votes() {
+        let config = Config::new().with_validators(4).with_byzantine_threshold(1);
+        let mut model = AlpenglowModel::new(config.clone());
+        model.set_byzantine(3, ByzantineStrategy::DoubleVote).unwrap();
         
-        assert_eq!(config.validator_count, 5);
-        assert_eq!(config.exploration_depth, 2000);
-        assert_eq!(config.verification_timeout_ms, 60000);
-        assert!(config.test_mode);
-        assert_eq!(config.leader_window_size, 8);
-        assert!(!config.adaptive_timeouts);
-        assert!(!config.vrf_enabled);
+        model.state = model.execute_action(AlpenglowAction::Byzantine(ByzantineAction::DoubleVote { validator: 3, view: 1 })).unwrap();
+        
+        // Every recipient holds both of the offender's commit votes for the view
+        for recipient in 0..4 {
+            let blocks: BTreeSet<BlockHash> = model.state.votor_received_votes[&recipient][&1].iter()
+                .filter(|vote| vote.voter == 3 && vote.vote_type == VoteType::Commit)
+                .map(|vote| vote.block)
+                .collect();
+            assert_eq!(blocks, BTreeSet::from([1, 2]));
+        }
+        assert_eq!(model.state.votor_voted_blocks[&3][&1].len(), 2);
+        
+        // The property only holds honest validators to account, so it passes while 3 is flagged Byzantine
+        assert!(properties::safety_no_double_voting(&model.state, &config));
+        
+        // Judged as honest, the same votes are a double vote by the offender
+        let mut state = model.state.clone();
+        state.failure_states.insert(3, ValidatorStatus::Honest);
+        let result = properties::safety_no_double_voting_detailed(&state, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("validator 3 view 1 voted for {1, 2}"));
     }
     
     #[test]
-    fn test_byzantine_resilience() {
-        let config = Config::new().with_validators(4).with_byzantine_threshold(1);
-        let mut state = AlpenglowState::init(&config);
+    fn test_message_delays_respect_gst() {
+        let config = Config::new().with_validators(4);
+        let mut model = AlpenglowModel::new(config.clone());
+        let message = |id: u64, timestamp: TimeValue| NetworkMessage {
+            id,
+            msg_type: MessageType::Block,
+            sender: 0,
+            recipient: MessageRecipient::Broadcast,
+            payload: vec![],
+            timestamp,
+            signature: 0,
+        };
+        let deliver = |message: NetworkMessage| AlpenglowAction::Network(NetworkAction::DeliverMessage { message });
+        let delay = |message: NetworkMessage, delay: TimeValue| AlpenglowAction::Network(NetworkAction::DelayMessage { message, delay });
+        
+        // Injected before GST, a message can be held back well past Delta
+        let early = message(1, 0);
+        model.state.enqueue_message(early.clone());
+        assert!(model.action_enabled(&deliver(early.clone())));
+        model.state = model.execute_action(delay(early.clone(), 3 * config.delta)).unwrap();
+        assert_eq!(model.earliest_delivery(&model.state, &early), 3 * config.delta);
+        model.state.clock = 3 * config.delta - 1;
+        assert!(!model.action_enabled(&deliver(early.clone())));
+        model.state.clock = 3 * config.delta;
+        model.state = model.execute_action(deliver(early.clone())).unwrap();
+        assert_eq!(model.state.network_delivery_time[&early], 3 * config.delta);
+        assert!(model.state.network_earliest_delivery.is_empty());
+        
+        // Held back before GST, a message still arrives Delta after GST at the latest
+        let late_sender = message(2, config.gst - 10);
+        assert_eq!(
+            PartialSynchronyModel::schedule_delivery(&config, late_sender.timestamp, 5 * config.delta),
+            config.gst + config.delta
+        );
         
-        // Mark one validator as Byzantine
-        state.failure_states.insert(0, ValidatorStatus::Byzantine);
+        // Injected after GST, the delay is capped at Delta
+        let sent = config.gst + 5;
+        let synchronous = message(3, sent);
+        model.state.clock = sent;
+        model.state.enqueue_message(synchronous.clone());
+        model.state = model.execute_action(delay(synchronous.clone(), 3 * config.delta)).unwrap();
+        assert_eq!(model.earliest_delivery(&model.state, &synchronous), sent + config.delta);
+        assert!(!model.action_enabled(&delay(synchronous.clone(), 2 * config.delta)));
+        model.state.clock = sent + config.delta;
+        assert!(model.action_enabled(&deliver(synchronous.clone())));
+        model.state = model.execute_action(deliver(synchronous.clone())).unwrap();
+        assert!(model.state.network_delivery_time[&synchronous] <= config.delta);
+    }
+    
+    #[test]
+    fn test_rotor_leader_tracks_schedule() {
+        let config = Config::new().with_validators(4);
+        let mut model = AlpenglowModel::new(config.clone());
+        assert_eq!(model.state.current_rotor, utils::leader_for_view(&config, 1));
+        assert!(properties::rotor_leader_correct_detailed(&model.state, &config).passed);
+        
+        // Each slot finalizes a block, times out every validator into the next view, then advances
+        for slot in 1..=3 {
+            let block = Block {
+                slot,
+                view: slot,
+                hash: slot,
+                parent: slot - 1,
+                proposer: model.state.current_rotor,
+                transactions: BTreeSet::new(),
+                timestamp: 0,
+                signature: 0,
+                data: vec![],
+            };
+            model.state.finalized_blocks.entry(slot).or_default().insert(block);
+            model.state.clock = model.state.votor_timeout_expiry.values().copied().max().unwrap();
+            for validator in 0..4 {
+                model.state = model.execute_action(AlpenglowAction::Votor(VotorAction::Timeout { validator })).unwrap();
+            }
+            model.state = model.execute_action(AlpenglowAction::AdvanceSlot).unwrap();
+            
+            assert_eq!(model.state.current_slot, slot + 1);
+            assert_eq!(model.state.current_view(), slot + 1);
+            assert_eq!(model.state.current_rotor, utils::leader_for_view(&config, slot + 1));
+            assert!(properties::rotor_leader_correct_detailed(&model.state, &config).passed);
+        }
         
-        assert!(properties::byzantine_resilience(&state, &config));
+        let mut stale = model.state.clone();
+        stale.current_rotor = (stale.current_rotor + 1) % 4;
+        let result = properties::rotor_leader_correct_detailed(&stale, &config);
+        assert!(!result.passed);
+        assert!(result.error.unwrap().contains("Relay leader in slot 4 view 4"));
+    }
+    
+    #[cfg(feature = "json-schema-validation")]
+    #[test]
+    fn test_json_schema_validates_serialized_output() {
+        let config = Config::new();
+        let mut model = AlpenglowModel::new(config.clone());
+        model.state = model.execute_action(AlpenglowAction::AdvanceClock).unwrap();
         
-        // Mark too many validators as Byzantine
-        state.failure_states.insert(1, ValidatorStatus::Byzantine);
-        assert!(!properties::byzantine_resilience(&state, &config));
+        let state_schema = jsonschema::JSONSchema::compile(&AlpenglowState::json_schema()).unwrap();
+        let config_schema = jsonschema::JSONSchema::compile(&Config::json_schema()).unwrap();
+        let state = serde_json::to_value(&model.state).unwrap();
+        assert!(state_schema.is_valid(&state));
+        assert!(config_schema.is_valid(&serde_json::to_value(&config).unwrap()));
+        
+        // Wrong field types and missing required fields are rejected
+        let mut malformed = state.clone();
+        malformed["clock"] = serde_json::json!("not a number");
+        assert!(!state_schema.is_valid(&malformed));
+        let mut missing = serde_json::to_value(&config).unwrap();
+        missing.as_object_mut().unwrap().remove("validator_count");
+        assert!(!config_schema.is_valid(&missing));
     }
     
     #[test]
@@ -2978,6 +10498,44 @@ mod tests {
         assert!(next_state.is_some());
         assert_eq!(next_state.unwrap().clock, 1);
     }
+    
+    #[test]
+    fn test_actions_enumerates_enabled_transitions() {
+        let config = Config::new().with_validators(4);
+        let model = AlpenglowModel::new(config.clone());
+        let init = model.init_states().remove(0);
+        let mut actions = Vec::new();
+        model.actions(&init, &mut actions);
+        
+        // The leader of view 1 can propose, and every returned action is enabled
+        let leader = model.compute_leader_for_view(1);
+        assert!(actions.contains(&AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: leader, view: 1 })));
+        assert!(!actions.iter().any(|action| matches!(action, AlpenglowAction::Votor(VotorAction::ProposeBlock { validator, .. }) if *validator != leader)));
+        assert!(actions.iter().all(|action| model.action_enabled(action)));
+        assert!(!actions.contains(&AlpenglowAction::AdvanceSlot));
+        assert!(!actions.iter().any(|action| matches!(action, AlpenglowAction::Byzantine(_))));
+        
+        // Once a block is proposed, validators can vote for it and the leader can shred it
+        let proposed = model.next_state(&init, AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: leader, view: 1 })).unwrap();
+        let mut actions = Vec::new();
+        model.actions(&proposed, &mut actions);
+        assert!(actions.iter().any(|action| matches!(action, AlpenglowAction::Votor(VotorAction::CastVote { view: 1, .. }))));
+        assert!(actions.iter().any(|action| matches!(action, AlpenglowAction::Rotor(RotorAction::ShredAndDistribute { leader: l, .. }) if *l == leader)));
+        
+        // Byzantine actions are only generated for validators flagged Byzantine
+        let byzantine = utils::create_byzantine_scenario(&config, &[3]).unwrap();
+        let mut actions = Vec::new();
+        byzantine.actions(&byzantine.state, &mut actions);
+        assert!(actions.contains(&AlpenglowAction::Byzantine(ByzantineAction::Equivocate { validator: 3 })));
+        assert!(actions.iter().all(|action| match action {
+            AlpenglowAction::Byzantine(
+                ByzantineAction::DoubleVote { validator, .. } | ByzantineAction::InvalidBlock { validator }
+                | ByzantineAction::WithholdShreds { validator } | ByzantineAction::Equivocate { validator }
+                | ByzantineAction::DelayMessages { validator, .. }
+            ) => *validator == 3,
+            _ => true,
+        }));
+    }
 }
 
 // Implement minimal model-oriented helper methods to support tests:
@@ -2988,12 +10546,86 @@ impl AlpenglowModel {
         vec![AlpenglowState::init(&self.config)]
     }
     
-    /// Populate possible actions from a state into the provided vector
-    pub fn actions(&self, _state: &AlpenglowState, out: &mut Vec<AlpenglowAction>) {
-        // Minimal action set for tests
-        out.push(AlpenglowAction::AdvanceClock);
-        out.push(AlpenglowAction::AdvanceSlot);
-        out.push(AlpenglowAction::AdvanceView { validator: 0 });
+    /// Populate the actions enabled in `state` into the provided vector.
+    ///
+    /// Actions are grouped by kind and validators visited in ascending id order, so the result is
+    /// deterministic. Actions whose parameters range over unbounded values (`SkewClock` offsets,
+    /// `DelayMessage` delays, `PartitionNetwork` subsets) are not enumerated, except that a
+    /// Byzantine validator may delay its messages by `delta` at a time.
+    pub fn actions(&self, state: &AlpenglowState, out: &mut Vec<AlpenglowAction>) {
+        let mut tmp = self.clone();
+        tmp.state = state.clone();
+        let validators: Vec<ValidatorId> = (0..self.config.validator_count as ValidatorId)
+            .filter(|validator| state.failure_states.get(validator) != Some(&ValidatorStatus::Offline))
+            .collect();
+        let mut candidates = vec![AlpenglowAction::AdvanceClock, AlpenglowAction::AdvanceSlot];
+        
+        for &validator in &validators {
+            candidates.push(AlpenglowAction::AdvanceView { validator });
+        }
+        
+        // Votor actions in each validator's current view
+        for &validator in &validators {
+            let view = state.votor_view.get(&validator).copied().unwrap_or(1);
+            let voted = state.votor_voted_blocks.get(&validator).and_then(|views| views.get(&view));
+            candidates.push(AlpenglowAction::Votor(VotorAction::ProposeBlock { validator, view }));
+            let proposals: BTreeSet<&Block> = state.votor_voted_blocks.values()
+                .filter_map(|views| views.get(&view))
+                .flatten()
+                .filter(|block| voted.is_none_or(|voted| !voted.contains(*block)))
+                .collect();
+            for block in proposals {
+                candidates.push(AlpenglowAction::Votor(VotorAction::CastVote { validator, block: block.clone(), view }));
+            }
+            candidates.push(AlpenglowAction::Votor(VotorAction::CollectVotes { validator, view }));
+            candidates.push(AlpenglowAction::Votor(VotorAction::SubmitSkipVote { validator, view }));
+            candidates.push(AlpenglowAction::Votor(VotorAction::CollectSkipVotes { validator, view }));
+            candidates.push(AlpenglowAction::Votor(VotorAction::Timeout { validator }));
+            for certificate in state.votor_generated_certs.get(&view).into_iter().flatten() {
+                candidates.push(AlpenglowAction::Votor(VotorAction::FinalizeBlock { validator, certificate: certificate.clone() }));
+            }
+        }
+        
+        // Rotor actions for proposed blocks and blocks in flight
+        let proposed: BTreeSet<&Block> = state.votor_voted_blocks.values().flat_map(|views| views.values()).flatten().collect();
+        for block in proposed {
+            candidates.push(AlpenglowAction::Rotor(RotorAction::ShredAndDistribute { leader: block.proposer, block: block.clone() }));
+        }
+        for (&block_id, holders) in &state.rotor_block_shreds {
+            for &validator in &validators {
+                candidates.push(AlpenglowAction::Rotor(RotorAction::RelayShreds { validator, block_id }));
+                candidates.push(AlpenglowAction::Rotor(RotorAction::AttemptReconstruction { validator, block_id }));
+                if holders.contains_key(&validator) {
+                    candidates.push(AlpenglowAction::Rotor(RotorAction::RequestRepair { validator, block_id }));
+                }
+            }
+        }
+        for request in &state.rotor_repair_requests {
+            for &validator in &validators {
+                candidates.push(AlpenglowAction::Rotor(RotorAction::RespondToRepair { validator, request: request.clone() }));
+            }
+        }
+        
+        // Network actions for every queued message
+        for message in &state.network_message_queue {
+            candidates.push(AlpenglowAction::Network(NetworkAction::DeliverMessage { message: message.clone() }));
+            candidates.push(AlpenglowAction::Network(NetworkAction::DropMessage { message: message.clone() }));
+        }
+        candidates.push(AlpenglowAction::Network(NetworkAction::HealPartition));
+        
+        // Byzantine actions for validators flagged Byzantine
+        for (&validator, status) in &state.failure_states {
+            if *status == ValidatorStatus::Byzantine {
+                let view = state.votor_view.get(&validator).copied().unwrap_or(1);
+                candidates.push(AlpenglowAction::Byzantine(ByzantineAction::DoubleVote { validator, view }));
+                candidates.push(AlpenglowAction::Byzantine(ByzantineAction::InvalidBlock { validator }));
+                candidates.push(AlpenglowAction::Byzantine(ByzantineAction::WithholdShreds { validator }));
+                candidates.push(AlpenglowAction::Byzantine(ByzantineAction::Equivocate { validator }));
+                candidates.push(AlpenglowAction::Byzantine(ByzantineAction::DelayMessages { validator, delay: self.config.delta }));
+            }
+        }
+        
+        out.extend(candidates.into_iter().filter(|action| tmp.action_enabled(action)));
     }
     
     /// Compute the next_state for a state-action pair if enabled
@@ -3010,4 +10642,19 @@ impl AlpenglowModel {
             None
         }
     }
+    
+    /// Apply `trace` step by step from the model's state, returning that state followed by the
+    /// state after each step. Fails with the step index at the first action that is not enabled.
+    pub fn replay_trace(&self, trace: &[AlpenglowAction]) -> AlpenglowResult<Vec<AlpenglowState>> {
+        let mut model = self.clone();
+        let mut states = vec![model.state.clone()];
+        for (step, action) in trace.iter().enumerate() {
+            let next = model.execute_action(action.clone()).map_err(|err| {
+                AlpenglowError::ProtocolViolation(format!("Step {} of the trace: {}", step, err))
+            })?;
+            states.push(next.clone());
+            model.state = next;
+        }
+        Ok(states)
+    }
 }
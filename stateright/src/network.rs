@@ -673,6 +673,27 @@ impl PartialSynchronyModel {
             && timestamp >= self.config.gst
             && !self.byzantine_validators.contains(&sender)
     }
+
+    /// Latest time a message sent at `sent_at` may be delivered: Delta after it was sent, or
+    /// Delta after GST for a message sent before the network stabilized
+    pub fn delivery_deadline(config: &Config, sent_at: u64) -> u64 {
+        sent_at.max(config.gst).saturating_add(config.delta)
+    }
+
+    /// Earliest delivery time of a message sent at `sent_at` that the network holds back for
+    /// `delay`. Sent before GST, the delay may reach the pre-GST bound of ten times
+    /// `max_network_delay`; sent after GST, it is at most Delta. Either way the message
+    /// arrives by its `delivery_deadline`.
+    pub fn schedule_delivery(config: &Config, sent_at: u64, delay: u64) -> u64 {
+        let bound = if sent_at >= config.gst {
+            config.delta
+        } else {
+            config.max_network_delay.saturating_mul(10)
+        };
+        sent_at
+            .saturating_add(delay.min(bound))
+            .min(Self::delivery_deadline(config, sent_at))
+    }
 }
 
 impl Verifiable for NetworkState {
@@ -773,12 +773,8 @@ impl RotorState {
     
     /// Helper method to parse block ID from string representation
     fn parse_block_id_from_string(&self, block_id_str: &str) -> AlpenglowResult<BlockHash> {
-        // For simplicity, create a deterministic block ID from the string hash
-        let mut hasher = std::collections::hash_map::DefaultHasher::new();
-        use std::hash::{Hash, Hasher};
-        block_id_str.hash(&mut hasher);
-        let hash = hasher.finish();
-        Ok(hash)
+        // For simplicity, create a deterministic block ID from a stable string hash
+        Ok(crate::utils::stable_hash(0, block_id_str.as_bytes()))
     }
     
     /// Helper method to parse shred ID from string representation
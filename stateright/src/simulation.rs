@@ -0,0 +1,444 @@
+// Author: Ayush Srivastava
+//! Scheduler-driven simulation of the Alpenglow model
+//!
+//! Drives `AlpenglowModel` step by step with a `Scheduler` until a target slot
+//! finalizes, recording when each slot was proposed and finalized and whether the
+//! fast (≥80% stake) or slow (≥60% stake) certificate path was used.
+
+use crate::{
+    AlpenglowAction, AlpenglowModel, AlpenglowResult, AlpenglowState, Block, BlockHash,
+    CertificateType, RotorAction, SlotNumber, TimeValue, ValidatorId, ValidatorStatus,
+    ViewNumber, VotorAction, utils,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Chooses the next action to drive a simulation
+pub trait Scheduler {
+    /// Return the next action to execute, or `None` to stop the simulation
+    fn next_action(&mut self, model: &AlpenglowModel) -> Option<AlpenglowAction>;
+}
+
+/// Finalization record for a single slot
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SlotFinalization {
+    pub slot: SlotNumber,
+    pub block: BlockHash,
+    pub proposed_at: TimeValue,
+    pub finalized_at: TimeValue,
+    /// Clock ticks between proposal and finalization
+    pub latency: TimeValue,
+    pub path: CertificateType,
+}
+
+/// Outcome of `AlpenglowModel::simulate_to_slot`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SimulationResult {
+    pub target_slot: SlotNumber,
+    pub reached_target: bool,
+    pub steps_taken: usize,
+    /// Every action executed, in order
+    pub trace: Vec<AlpenglowAction>,
+    pub slot_finalizations: BTreeMap<SlotNumber, SlotFinalization>,
+    pub final_state: AlpenglowState,
+}
+
+impl SimulationResult {
+    /// Latency of each finalized slot in clock ticks
+    pub fn latencies(&self) -> BTreeMap<SlotNumber, TimeValue> {
+        self.slot_finalizations.iter()
+            .map(|(slot, finalization)| (*slot, finalization.latency))
+            .collect()
+    }
+}
+
+impl AlpenglowModel {
+    /// Drive the model with `scheduler` until `target_slot` finalizes or `max_steps` run out
+    pub fn simulate_to_slot<S: Scheduler>(
+        &self,
+        target_slot: SlotNumber,
+        max_steps: usize,
+        scheduler: &mut S,
+    ) -> AlpenglowResult<SimulationResult> {
+        let mut model = self.clone();
+        let mut slot_finalizations = BTreeMap::new();
+        let mut trace = Vec::new();
+        let mut steps_taken = 0;
+
+        while steps_taken < max_steps && !slot_finalized(&model.state, target_slot) {
+            let action = match scheduler.next_action(&model) {
+                Some(action) => action,
+                None => break,
+            };
+
+            let finalizing = match &action {
+                AlpenglowAction::Votor(VotorAction::FinalizeBlock { certificate, .. }) => {
+                    Some(certificate.clone())
+                }
+                _ => None,
+            };
+
+            model.state = model.execute_action(action.clone())?;
+            trace.push(action);
+            steps_taken += 1;
+
+            if let Some(certificate) = finalizing {
+                let newly_finalized = !slot_finalizations.contains_key(&certificate.slot)
+                    && slot_finalized(&model.state, certificate.slot);
+                if newly_finalized {
+                    let proposed_at = model.state.finalized_blocks[&certificate.slot].iter()
+                        .map(|block| block.timestamp)
+                        .min()
+                        .unwrap_or(model.state.clock);
+                    slot_finalizations.insert(certificate.slot, SlotFinalization {
+                        slot: certificate.slot,
+                        block: certificate.block,
+                        proposed_at,
+                        finalized_at: model.state.clock,
+                        latency: model.state.clock.saturating_sub(proposed_at),
+                        path: certificate.cert_type,
+                    });
+                }
+            }
+        }
+
+        Ok(SimulationResult {
+            target_slot,
+            reached_target: slot_finalized(&model.state, target_slot),
+            steps_taken,
+            trace,
+            slot_finalizations,
+            final_state: model.state,
+        })
+    }
+}
+
+fn slot_finalized(state: &AlpenglowState, slot: SlotNumber) -> bool {
+    state.finalized_blocks.get(&slot).is_some_and(|blocks| !blocks.is_empty())
+}
+
+/// Deterministic scheduler where every honest validator follows the protocol.
+///
+/// Each slot runs as: the leader proposes and disseminates the block through Rotor,
+/// honest validators vote once they reconstruct it, one clock tick passes for the
+/// votes to propagate, then a certificate is collected and the block finalized.
+/// Between slots validators move to the next view once their timeout expires.
+/// The order in which honest validators act rotates each slot by `Config::seed`.
+///
+/// Validators marked Byzantine with `AlpenglowModel::set_byzantine` take the action
+/// of their strategy once per view before the honest protocol proceeds.
+#[derive(Debug, Clone, Default)]
+pub struct HonestScheduler {
+    byzantine_acted: BTreeSet<(ValidatorId, ViewNumber)>,
+}
+
+impl HonestScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn byzantine_step(&mut self, model: &AlpenglowModel) -> Option<AlpenglowAction> {
+        for (&validator, strategy) in &model.state.byzantine_strategies {
+            let view = model.state.votor_view.get(&validator).copied().unwrap_or(1);
+            if self.byzantine_acted.contains(&(validator, view)) {
+                continue;
+            }
+            let action = AlpenglowAction::Byzantine(strategy.action(validator, view));
+            if model.action_enabled(&action) {
+                self.byzantine_acted.insert((validator, view));
+                return Some(action);
+            }
+        }
+        
+        None
+    }
+
+    fn honest_validators(model: &AlpenglowModel) -> Vec<ValidatorId> {
+        let mut honest: Vec<ValidatorId> = (0..model.config.validator_count as ValidatorId)
+            .filter(|v| matches!(model.state.failure_states.get(v), Some(ValidatorStatus::Honest) | None))
+            .collect();
+        if !honest.is_empty() {
+            let offset = utils::seeded_hash(model.config.seed, model.state.current_slot) % honest.len() as u64;
+            honest.rotate_left(offset as usize);
+        }
+        honest
+    }
+
+    fn current_proposal(model: &AlpenglowModel, leader: ValidatorId, view: ViewNumber) -> Option<Block> {
+        model.state.votor_voted_blocks.get(&leader)
+            .and_then(|views| views.get(&view))
+            .and_then(|blocks| {
+                blocks.iter()
+                    .find(|b| b.proposer == leader && b.slot == model.state.current_slot)
+                    .cloned()
+            })
+    }
+
+    fn has_voted(model: &AlpenglowModel, validator: ValidatorId, view: ViewNumber) -> bool {
+        model.state.votor_received_votes.get(&validator)
+            .and_then(|views| views.get(&view))
+            .is_some_and(|votes| votes.iter().any(|vote| vote.voter == validator))
+    }
+
+    fn rotor_step(model: &AlpenglowModel, honest: &[ValidatorId], block: &Block) -> Option<AlpenglowAction> {
+        let state = &model.state;
+        let block_shreds = match state.rotor_block_shreds.get(&block.hash) {
+            Some(block_shreds) => block_shreds,
+            None => {
+                return Some(AlpenglowAction::Rotor(RotorAction::ShredAndDistribute {
+                    leader: block.proposer,
+                    block: block.clone(),
+                }));
+            }
+        };
+
+        for &validator in honest {
+            let delivered = state.rotor_delivered_blocks.get(&validator)
+                .is_some_and(|blocks| blocks.contains(&block.hash));
+            let action = AlpenglowAction::Rotor(RotorAction::AttemptReconstruction {
+                validator,
+                block_id: block.hash,
+            });
+            if !delivered && model.action_enabled(&action) {
+                return Some(action);
+            }
+        }
+
+        // Relay from any honest validator holding shreds another validator lacks
+        for &validator in honest {
+            let mine = match block_shreds.get(&validator) {
+                Some(mine) => mine,
+                None => continue,
+            };
+            let incomplete = (0..model.config.validator_count as ValidatorId)
+                .filter(|other| *other != validator)
+                .any(|other| block_shreds.get(&other).is_none_or(|theirs| !mine.is_subset(theirs)));
+            if !mine.is_empty() && incomplete {
+                return Some(AlpenglowAction::Rotor(RotorAction::RelayShreds {
+                    validator,
+                    block_id: block.hash,
+                }));
+            }
+        }
+
+        None
+    }
+}
+
+impl Scheduler for HonestScheduler {
+    fn next_action(&mut self, model: &AlpenglowModel) -> Option<AlpenglowAction> {
+        let state = &model.state;
+        let honest = Self::honest_validators(model);
+
+        if let Some(action) = self.byzantine_step(model) {
+            return Some(action);
+        }
+
+        // Move to the next slot once the current one is finalized
+        if slot_finalized(state, state.current_slot) {
+            if model.action_enabled(&AlpenglowAction::AdvanceSlot) {
+                return Some(AlpenglowAction::AdvanceSlot);
+            }
+            return None;
+        }
+
+        // Validators still in an already finalized view move on once their timeout expires
+        let finalized_view = state.latest_finalized_view();
+        for &validator in &honest {
+            let view = state.votor_view.get(&validator).copied().unwrap_or(1);
+            if view <= finalized_view {
+                let advance = AlpenglowAction::AdvanceView { validator };
+                if model.action_enabled(&advance) {
+                    return Some(advance);
+                }
+                return Some(AlpenglowAction::AdvanceClock);
+            }
+        }
+
+        let view = state.votor_view.get(&0).copied().unwrap_or(1);
+        let leader = model.compute_leader_for_view(view);
+
+        let block = match Self::current_proposal(model, leader, view) {
+            Some(block) => block,
+            None => {
+                let propose = AlpenglowAction::Votor(VotorAction::ProposeBlock { validator: leader, view });
+                if honest.contains(&leader) && model.action_enabled(&propose) {
+                    return Some(propose);
+                }
+                return Some(AlpenglowAction::AdvanceClock);
+            }
+        };
+
+        if let Some(action) = Self::rotor_step(model, &honest, &block) {
+            return Some(action);
+        }
+
+        // Honest validators vote for the block once Rotor delivered it
+        for &validator in &honest {
+            let delivered = state.rotor_delivered_blocks.get(&validator)
+                .is_some_and(|blocks| blocks.contains(&block.hash));
+            if delivered && !Self::has_voted(model, validator, view) {
+                return Some(AlpenglowAction::Votor(VotorAction::CastVote {
+                    validator,
+                    block: block.clone(),
+                    view,
+                }));
+            }
+        }
+
+        // Votes need one network round before they can be aggregated
+        let votes = state.votor_received_votes.get(&leader).and_then(|views| views.get(&view));
+        let last_vote = votes.and_then(|votes| votes.iter().map(|vote| vote.timestamp).max());
+        match last_vote {
+            Some(timestamp) if state.clock > timestamp => {}
+            _ => return Some(AlpenglowAction::AdvanceClock),
+        }
+
+        let certificate = state.votor_generated_certs.get(&view)
+            .and_then(|certs| certs.iter().find(|cert| cert.block == block.hash))
+            .cloned();
+        match certificate {
+            None => {
+                let voted_stake: u64 = votes.into_iter()
+                    .flat_map(|votes| votes.iter())
+                    .map(|vote| model.config.stake_distribution.get(&vote.voter).copied().unwrap_or(0))
+                    .sum();
+                if voted_stake >= model.config.slow_path_threshold {
+                    Some(AlpenglowAction::Votor(VotorAction::CollectVotes { validator: leader, view }))
+                } else {
+                    // Not enough stake voted; wait for the view to time out
+                    Some(AlpenglowAction::AdvanceClock)
+                }
+            }
+            Some(certificate) => Some(AlpenglowAction::Votor(VotorAction::FinalizeBlock {
+                validator: leader,
+                certificate,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ByzantineAction, ByzantineStrategy, Config, ModelChecker};
+
+    #[test]
+    fn test_simulate_honest_config_to_slot_three() {
+        let model = AlpenglowModel::new(Config::new());
+        let mut scheduler = HonestScheduler::new();
+
+        let result = model.simulate_to_slot(3, 5000, &mut scheduler).unwrap();
+
+        assert!(result.reached_target, "slot 3 should finalize within the step budget");
+        assert_eq!(result.slot_finalizations.len(), 3);
+        for slot in 1..=3 {
+            let finalization = &result.slot_finalizations[&slot];
+            assert_eq!(finalization.path, CertificateType::Fast);
+            assert!(finalization.finalized_at > finalization.proposed_at);
+            assert_eq!(finalization.latency, finalization.finalized_at - finalization.proposed_at);
+        }
+        assert_eq!(result.latencies().len(), 3);
+        assert!(result.steps_taken <= 5000);
+        assert_eq!(result.final_state.votor_finalized_chain.len(), 3);
+    }
+
+    #[test]
+    fn test_fast_path_finalizes_in_one_round() {
+        let config = Config::new();
+        let model = AlpenglowModel::new(config.clone());
+        let result = model.simulate_to_slot(1, 1000, &mut HonestScheduler::new()).unwrap();
+        assert!(result.reached_target);
+
+        // With every validator honest the block finalizes through a fast certificate
+        let finalization = &result.slot_finalizations[&1];
+        assert_eq!(finalization.path, CertificateType::Fast);
+        assert!(finalization.latency <= config.delta, "latency {} exceeds delta {}", finalization.latency, config.delta);
+        let certificate = result.final_state.votor_generated_certs.values()
+            .flat_map(|certs| certs.iter())
+            .find(|cert| cert.block == finalization.block)
+            .unwrap();
+        assert_eq!(certificate.cert_type, CertificateType::Fast);
+        assert!(certificate.stake >= config.fast_path_threshold);
+
+        // One proposal, one vote from each validator, one aggregation: a single round
+        let count = |matches: fn(&AlpenglowAction) -> bool| result.trace.iter().filter(|action| matches(action)).count();
+        assert_eq!(count(|action| matches!(action, AlpenglowAction::Votor(VotorAction::ProposeBlock { .. }))), 1);
+        assert_eq!(count(|action| matches!(action, AlpenglowAction::Votor(VotorAction::CastVote { .. }))), config.validator_count);
+        assert_eq!(count(|action| matches!(action, AlpenglowAction::Votor(VotorAction::CollectVotes { .. }))), 1);
+        assert_eq!(count(|action| matches!(action, AlpenglowAction::Votor(VotorAction::SubmitSkipVote { .. }))), 0);
+    }
+
+    #[test]
+    fn test_set_byzantine_mid_run_equivocates_afterwards() {
+        let mut model = AlpenglowModel::new(Config::new());
+        let mut scheduler = HonestScheduler::new();
+        let byzantine = 3;
+        let equivocations = |state: &AlpenglowState| {
+            state.network_message_queue.iter()
+                .filter(|msg| msg.sender == byzantine && msg.msg_type == crate::MessageType::Vote)
+                .count()
+        };
+
+        for step in 0..40 {
+            if step == 5 {
+                assert_eq!(equivocations(&model.state), 0);
+                model.set_byzantine(byzantine, ByzantineStrategy::Equivocate).unwrap();
+            }
+
+            let action = scheduler.next_action(&model).unwrap();
+            if step < 5 {
+                assert!(!matches!(action, AlpenglowAction::Byzantine(_)));
+            }
+            if step == 5 {
+                assert_eq!(action, AlpenglowAction::Byzantine(ByzantineAction::Equivocate { validator: byzantine }));
+            }
+            model.state = model.execute_action(action).unwrap();
+
+            if step < 5 {
+                assert_eq!(equivocations(&model.state), 0, "no equivocation before step 5");
+            }
+        }
+
+        assert_eq!(equivocations(&model.state), 2);
+        assert_eq!(model.state.failure_states[&byzantine], ValidatorStatus::Byzantine);
+        assert!(model.set_byzantine(4, ByzantineStrategy::DoubleVote).is_err());
+    }
+
+    #[test]
+    fn test_same_seed_reproduces_trace_and_verification() {
+        let run = |seed: u64| {
+            let model = AlpenglowModel::new(Config::new().with_seed(seed));
+            let result = model.simulate_to_slot(3, 5000, &mut HonestScheduler::new()).unwrap();
+            let final_model = AlpenglowModel { state: result.final_state.clone(), ..model.clone() };
+            let metrics = ModelChecker::new(model.config.clone()).verify_model(&final_model).unwrap();
+            let encoded = bincode::serialize(&result).unwrap();
+            (utils::stable_hash(0, &encoded), metrics.fingerprint(), result.trace)
+        };
+
+        let (first_hash, first_fingerprint, first_trace) = run(42);
+        let (second_hash, second_fingerprint, second_trace) = run(42);
+        assert_eq!(first_trace, second_trace);
+        assert_eq!(first_hash, second_hash);
+        assert_eq!(first_fingerprint, second_fingerprint);
+
+        // The seed drives leader selection, so another seed yields a different schedule
+        let leaders = |seed: u64| {
+            let model = AlpenglowModel::new(Config::new().with_seed(seed));
+            (1..=20).map(|view| model.compute_leader_for_view(view)).collect::<Vec<_>>()
+        };
+        assert_eq!(leaders(42), leaders(42));
+        assert_ne!(leaders(42), leaders(43));
+    }
+
+    #[test]
+    fn test_simulation_stops_at_step_budget() {
+        let model = AlpenglowModel::new(Config::new());
+        let mut scheduler = HonestScheduler::new();
+
+        let result = model.simulate_to_slot(3, 10, &mut scheduler).unwrap();
+
+        assert!(!result.reached_target);
+        assert_eq!(result.steps_taken, 10);
+    }
+}
@@ -129,6 +129,19 @@ pub struct ActorModel<A: Actor, I = (), O = ()> {
     pub step_count: usize,
 }
 
+impl<A: Actor + Clone, I: Clone, O: Clone> Clone for ActorModel<A, I, O> {
+    fn clone(&self) -> Self {
+        Self {
+            actors: self.actors.clone(),
+            init_network: self.init_network.clone(),
+            record_msg_out: self.record_msg_out.clone(),
+            actor_states: self.actor_states.clone(),
+            message_queue: self.message_queue.clone(),
+            step_count: self.step_count,
+        }
+    }
+}
+
 impl<A: Actor> ActorModel<A> {
     pub fn new() -> Self {
         Self {
@@ -244,11 +257,13 @@ impl<A: Actor> ActorModel<A> {
 }
 
 /// Network state for message passing
+#[derive(Clone)]
 pub struct Network {
     pub messages: Vec<(Id, Id, Vec<u8>)>,
 }
 
 /// System state for actor models
+#[derive(Clone)]
 pub struct SystemState<S> {
     pub actor_states: Vec<Option<S>>,
     pub network: Network,
@@ -0,0 +1,459 @@
+// Author: Ayush Srivastava
+//! Backends that model check a scenario with TLC
+//!
+//! A `TlaBackend` takes the TLA+ constants for one scenario (as produced by
+//! `TlaCompatible::export_tla_state` / `Config::to_tla_constants`) and returns the
+//! TLC results. `RemoteTlcBackend` submits the constants to an HTTP service running
+//! TLC and polls until the job finishes, so clients need no local JVM or TLA+ tools.
+//!
+//! The service protocol is:
+//! - `POST {endpoint}/jobs` with the constants as JSON, answered with `{"job_id": "..."}`
+//! - `GET {endpoint}/jobs/{job_id}`, answered with `{"status": "running"}`,
+//!   `{"status": "done", "result": <TlaResult>}` or `{"status": "failed", "error": "..."}`
+
+use crate::{AlpenglowError, AlpenglowResult};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+/// TLA+ execution results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlaResult {
+    pub model_check_output: String,
+    pub states_explored: usize,
+    pub properties_checked: Vec<TlaProperty>,
+    pub violations_found: Vec<TlaViolation>,
+    pub execution_time_ms: u64,
+    pub memory_usage_mb: f64,
+    pub tlc_statistics: TlcStatistics,
+}
+
+/// TLA+ property result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlaProperty {
+    pub name: String,
+    pub status: String,
+    pub violation_count: usize,
+    pub counterexample: Option<Vec<String>>,
+    pub proof_obligations: Vec<String>,
+}
+
+/// TLA+ violation details
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlaViolation {
+    pub property_name: String,
+    pub violation_type: String,
+    pub trace_length: usize,
+    pub error_state: Value,
+    pub counterexample_trace: Vec<Value>,
+}
+
+/// TLC statistics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlcStatistics {
+    pub states_generated: usize,
+    pub states_distinct: usize,
+    pub states_left_on_queue: usize,
+    pub diameter: usize,
+    pub fingerprint_collisions: usize,
+}
+
+/// Runs TLC for a scenario
+#[async_trait]
+pub trait TlaBackend: Send + Sync {
+    /// Model check the scenario described by the TLA+ `constants`
+    async fn run(&self, constants: &Value) -> AlpenglowResult<TlaResult>;
+}
+
+/// TLC running behind an HTTP service
+#[derive(Debug, Clone)]
+pub struct RemoteTlcBackend {
+    /// Base URL of the service, e.g. `http://tlc.internal:8080/api`
+    pub endpoint: String,
+    /// Give up on a job that has not finished after this long
+    pub timeout: Duration,
+    /// Connect, read and write timeout of each HTTP request
+    pub io_timeout: Duration,
+    /// Extra attempts for each HTTP request that fails to connect. GETs are also retried when
+    /// the exchange fails later or returns a 5xx; a job submission that may have reached the
+    /// service is not, so it cannot start the same job twice.
+    pub retries: u32,
+    /// Delay between polls of a running job
+    pub poll_interval: Duration,
+}
+
+impl RemoteTlcBackend {
+    /// Create a backend for the service at `endpoint`
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            timeout: Duration::from_secs(600),
+            io_timeout: Duration::from_secs(30),
+            retries: 3,
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+
+    /// Set the job timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the timeout of each connect, read and write
+    pub fn with_io_timeout(mut self, io_timeout: Duration) -> Self {
+        self.io_timeout = io_timeout;
+        self
+    }
+
+    /// Set the number of retries per request
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Set the polling interval
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Send one request, retrying connection failures and, for idempotent GETs, server errors
+    async fn request(&self, method: &'static str, path: String, body: Option<Vec<u8>>) -> AlpenglowResult<Value> {
+        let url = format!("{}{}", self.endpoint.trim_end_matches('/'), path);
+        let idempotent = method == "GET";
+        let mut last_error = AlpenglowError::NetworkError(format!("No attempt made for {} {}", method, url));
+
+        for attempt in 0..=self.retries {
+            if attempt > 0 {
+                tokio::time::sleep(self.poll_interval).await;
+            }
+
+            let (request_url, body, timeout) = (url.clone(), body.clone(), self.io_timeout);
+            let response = tokio::task::spawn_blocking(move || http_request(method, &request_url, body.as_deref(), timeout))
+                .await
+                .map_err(|e| AlpenglowError::NetworkError(format!("Request task failed: {}", e)))?;
+
+            match response {
+                Ok((status, body)) if status < 300 => {
+                    return serde_json::from_slice(&body).map_err(|e| {
+                        AlpenglowError::SerializationError(format!("Invalid response from {}: {}", url, e))
+                    });
+                },
+                Ok((status, body)) => {
+                    let error = AlpenglowError::NetworkError(format!(
+                        "{} {} returned {}: {}", method, url, status, String::from_utf8_lossy(&body)
+                    ));
+                    if status < 500 || !idempotent {
+                        return Err(error);
+                    }
+                    last_error = error;
+                },
+                Err(RequestFailure { error, sent }) => {
+                    if sent && !idempotent {
+                        return Err(error);
+                    }
+                    last_error = error;
+                },
+            }
+        }
+
+        Err(last_error)
+    }
+}
+
+#[async_trait]
+impl TlaBackend for RemoteTlcBackend {
+    async fn run(&self, constants: &Value) -> AlpenglowResult<TlaResult> {
+        let deadline = Instant::now() + self.timeout;
+        let body = serde_json::to_vec(constants)
+            .map_err(|e| AlpenglowError::SerializationError(format!("Failed to serialize constants: {}", e)))?;
+
+        let submitted = self.request("POST", "/jobs".to_string(), Some(body)).await?;
+        let job_id = match &submitted["job_id"] {
+            Value::String(id) => id.clone(),
+            Value::Number(id) => id.to_string(),
+            _ => return Err(AlpenglowError::SerializationError(format!("Missing job_id in {}", submitted))),
+        };
+
+        loop {
+            let job = self.request("GET", format!("/jobs/{}", job_id), None).await?;
+            match job["status"].as_str() {
+                Some("done") => {
+                    return serde_json::from_value(job["result"].clone()).map_err(|e| {
+                        AlpenglowError::SerializationError(format!("Invalid TLC result for job {}: {}", job_id, e))
+                    });
+                },
+                Some("failed") => {
+                    return Err(AlpenglowError::NetworkError(format!(
+                        "TLC job {} failed: {}", job_id, job["error"].as_str().unwrap_or("unknown error")
+                    )));
+                },
+                _ => {},
+            }
+
+            if Instant::now() >= deadline {
+                return Err(AlpenglowError::VerificationTimeout(format!(
+                    "TLC job {} did not finish within {:?}", job_id, self.timeout
+                )));
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+/// A failed HTTP request, and whether it may have reached the server
+struct RequestFailure {
+    error: AlpenglowError,
+    sent: bool,
+}
+
+/// Minimal blocking HTTP/1.1 request, returning the status code and body
+fn http_request(method: &str, url: &str, body: Option<&[u8]>, timeout: Duration) -> Result<(u16, Vec<u8>), RequestFailure> {
+    let (stream, authority, path) = http_connect(method, url, timeout)
+        .map_err(|error| RequestFailure { error, sent: false })?;
+    http_exchange(stream, method, url, &authority, &path, body, timeout)
+        .map_err(|error| RequestFailure { error, sent: true })
+}
+
+/// Connect to the server of `url`, returning the stream, authority and request path
+fn http_connect(method: &str, url: &str, timeout: Duration) -> AlpenglowResult<(TcpStream, String, String)> {
+    let rest = url.strip_prefix("http://")
+        .ok_or_else(|| AlpenglowError::InvalidConfig(format!("Only http:// endpoints are supported: {}", url)))?;
+    let (authority, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, "/"),
+    };
+    let address = if authority.contains(':') { authority.to_string() } else { format!("{}:80", authority) };
+    let network_error = |e: std::io::Error| AlpenglowError::NetworkError(format!("{} {}: {}", method, url, e));
+
+    let socket = address.to_socket_addrs()
+        .map_err(network_error)?
+        .next()
+        .ok_or_else(|| AlpenglowError::NetworkError(format!("Cannot resolve {}", authority)))?;
+    let stream = TcpStream::connect_timeout(&socket, timeout).map_err(network_error)?;
+    Ok((stream, authority.to_string(), path.to_string()))
+}
+
+/// Send the request over `stream` and read the response
+fn http_exchange(
+    mut stream: TcpStream,
+    method: &str,
+    url: &str,
+    authority: &str,
+    path: &str,
+    body: Option<&[u8]>,
+    timeout: Duration,
+) -> AlpenglowResult<(u16, Vec<u8>)> {
+    let network_error = |e: std::io::Error| AlpenglowError::NetworkError(format!("{} {}: {}", method, url, e));
+    stream.set_read_timeout(Some(timeout)).map_err(network_error)?;
+    stream.set_write_timeout(Some(timeout)).map_err(network_error)?;
+
+    let body = body.unwrap_or_default();
+    let head = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        method, path, authority, body.len()
+    );
+    stream.write_all(head.as_bytes()).map_err(network_error)?;
+    stream.write_all(body).map_err(network_error)?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).map_err(network_error)?;
+
+    let malformed = || AlpenglowError::NetworkError(format!("Malformed HTTP response from {}", url));
+    let split = response.windows(4).position(|w| w == b"\r\n\r\n").ok_or_else(malformed)?;
+    let head = String::from_utf8_lossy(&response[..split]).to_lowercase();
+    let status = head.split_whitespace().nth(1).and_then(|code| code.parse().ok()).ok_or_else(malformed)?;
+    let payload = &response[split + 4..];
+
+    let payload = if head.contains("transfer-encoding: chunked") {
+        decode_chunked(payload).ok_or_else(malformed)?
+    } else {
+        payload.to_vec()
+    };
+
+    Ok((status, payload))
+}
+
+/// Decode a `Transfer-Encoding: chunked` body, or `None` if it is malformed or truncated
+fn decode_chunked(mut data: &[u8]) -> Option<Vec<u8>> {
+    let mut body = Vec::new();
+    loop {
+        let line_end = data.windows(2).position(|w| w == b"\r\n")?;
+        let size_field = std::str::from_utf8(&data[..line_end]).ok()?;
+        let size = usize::from_str_radix(size_field.split(';').next()?.trim(), 16).ok()?;
+        data = &data[line_end + 2..];
+        if size == 0 {
+            return Some(body);
+        }
+        body.extend_from_slice(data.get(..size)?);
+        if data.get(size..size.checked_add(2)?)? != b"\r\n" {
+            return None;
+        }
+        data = &data[size + 2..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Serve canned responses in order, recording each request line and body
+    fn mock_server(responses: Vec<(u16, String)>) -> (String, Arc<Mutex<Vec<(String, String)>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/api", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 4096];
+                loop {
+                    let read = stream.read(&mut buffer).unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    if let Some(split) = text.find("\r\n\r\n") {
+                        let length = text[..split].lines()
+                            .find_map(|line| line.to_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap()))
+                            .unwrap_or(0);
+                        if request.len() >= split + 4 + length {
+                            let request_line = text.lines().next().unwrap().to_string();
+                            recorded.lock().unwrap().push((request_line, text[split + 4..].to_string()));
+                            break;
+                        }
+                    }
+                }
+                let reply = format!("HTTP/1.1 {} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body);
+                stream.write_all(reply.as_bytes()).unwrap();
+            }
+        });
+
+        (endpoint, requests)
+    }
+
+    #[tokio::test]
+    async fn test_remote_tlc_backend_submits_and_polls() {
+        let result = serde_json::json!({
+            "model_check_output": "Model checking completed. No error has been found.",
+            "states_explored": 1234,
+            "properties_checked": [{
+                "name": "Safety",
+                "status": "satisfied",
+                "violation_count": 0,
+                "counterexample": null,
+                "proof_obligations": [],
+            }],
+            "violations_found": [],
+            "execution_time_ms": 900,
+            "memory_usage_mb": 256.0,
+            "tlc_statistics": {
+                "states_generated": 5000,
+                "states_distinct": 1234,
+                "states_left_on_queue": 0,
+                "diameter": 17,
+                "fingerprint_collisions": 0,
+            },
+        });
+        let (endpoint, requests) = mock_server(vec![
+            (200, r#"{"job_id": "job-7"}"#.to_string()),
+            (503, "busy".to_string()),
+            (200, r#"{"status": "running"}"#.to_string()),
+            (200, serde_json::json!({"status": "done", "result": result}).to_string()),
+        ]);
+        let backend = RemoteTlcBackend::new(endpoint)
+            .with_timeout(Duration::from_secs(5))
+            .with_retries(1)
+            .with_poll_interval(Duration::from_millis(10));
+        let constants = crate::Config::new().to_tla_constants().unwrap();
+
+        let tla_result = backend.run(&constants).await.unwrap();
+
+        assert_eq!(tla_result.states_explored, 1234);
+        assert_eq!(tla_result.properties_checked[0].name, "Safety");
+        assert_eq!(tla_result.tlc_statistics.diameter, 17);
+
+        let requests = requests.lock().unwrap();
+        let lines: Vec<&str> = requests.iter().map(|(line, _)| line.as_str()).collect();
+        assert_eq!(lines, vec![
+            "POST /api/jobs HTTP/1.1",
+            "GET /api/jobs/job-7 HTTP/1.1",
+            "GET /api/jobs/job-7 HTTP/1.1",
+            "GET /api/jobs/job-7 HTTP/1.1",
+        ]);
+        let submitted: Value = serde_json::from_str(&requests[0].1).unwrap();
+        assert_eq!(submitted, constants);
+    }
+
+    #[tokio::test]
+    async fn test_remote_tlc_backend_does_not_resubmit_jobs() {
+        // The service may have started the job before failing, so submitting again could run it twice
+        let (endpoint, requests) = mock_server(vec![
+            (503, "busy".to_string()),
+            (200, r#"{"job_id": "job-8"}"#.to_string()),
+        ]);
+        let backend = RemoteTlcBackend::new(endpoint)
+            .with_retries(3)
+            .with_poll_interval(Duration::from_millis(10));
+
+        let error = backend.run(&serde_json::json!({})).await.unwrap_err();
+
+        assert!(matches!(error, AlpenglowError::NetworkError(_)), "{:?}", error);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_decode_chunked_rejects_malformed_bodies() {
+        assert_eq!(decode_chunked(b"4\r\nWiki\r\n5;ext=1\r\npedia\r\n0\r\n\r\n").unwrap(), b"Wikipedia");
+
+        assert_eq!(decode_chunked(b"zz\r\nWiki\r\n0\r\n\r\n"), None, "size is not hex");
+        assert_eq!(decode_chunked(b"a\r\nWiki\r\n0\r\n\r\n"), None, "chunk shorter than its size");
+        assert_eq!(decode_chunked(b"2\r\nWiki\r\n0\r\n\r\n"), None, "chunk longer than its size");
+        assert_eq!(decode_chunked(b"4\r\nWiki\r\n"), None, "missing last chunk");
+        assert_eq!(decode_chunked(b"4\r\nWiki"), None, "truncated after data");
+        assert_eq!(decode_chunked(b"ffffffffffffffff\r\nWiki\r\n"), None, "size overflows");
+    }
+
+    #[tokio::test]
+    async fn test_remote_tlc_backend_io_timeout() {
+        // The server accepts the connection but never answers
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || listener.accept().map(|(stream, _)| {
+            thread::sleep(Duration::from_secs(2));
+            drop(stream);
+        }));
+        let backend = RemoteTlcBackend::new(endpoint)
+            .with_timeout(Duration::from_secs(600))
+            .with_io_timeout(Duration::from_millis(100))
+            .with_retries(0);
+
+        let started = Instant::now();
+        let error = backend.run(&serde_json::json!({})).await.unwrap_err();
+
+        assert!(matches!(error, AlpenglowError::NetworkError(_)), "{:?}", error);
+        assert!(started.elapsed() < Duration::from_secs(2), "waited for the job timeout");
+        server.join().unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_remote_tlc_backend_times_out() {
+        let mut responses = vec![(200, r#"{"job_id": 1}"#.to_string())];
+        responses.extend((0..100).map(|_| (200, r#"{"status": "running"}"#.to_string())));
+        let (endpoint, _) = mock_server(responses);
+        let backend = RemoteTlcBackend::new(endpoint)
+            .with_timeout(Duration::from_millis(200))
+            .with_poll_interval(Duration::from_millis(20));
+
+        let error = backend.run(&serde_json::json!({})).await.unwrap_err();
+
+        assert!(matches!(error, AlpenglowError::VerificationTimeout(_)), "{:?}", error);
+    }
+}
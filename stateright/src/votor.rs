@@ -27,6 +27,7 @@ use crate::{
     AlpenglowError, AlpenglowResult, BlockHash, Config, Signature, SlotNumber, 
     StakeAmount, TlaCompatible, ValidatorId, Verifiable
 };
+use crate::utils::seeded_hash;
 use serde::{Deserialize, Serialize};
 use crate::stateright::{Actor, Id};
 use std::collections::{HashMap, HashSet};
@@ -65,6 +66,62 @@ pub struct VRFProof {
     pub valid: bool,
 }
 
+/// Domain separator keeping VRF keys independent of the other seeded hashes
+const VRF_KEY_DOMAIN: u64 = 0x5652_465F_4B45_5953;
+
+/// Simplified VRF key pair of `validator` derived from `seed`. The public key is the private
+/// key XORed with a hash of itself, which has no inverse, so it does not reveal the private key.
+pub fn vrf_key_pair(validator: ValidatorId, seed: u64) -> VRFKeyPair {
+    let private_key = seeded_hash(seed ^ VRF_KEY_DOMAIN, validator as u64);
+    VRFKeyPair {
+        validator,
+        public_key: seeded_hash(VRF_KEY_DOMAIN, private_key) ^ private_key,
+        private_key,
+        valid: true,
+    }
+}
+
+/// Simplified VRF output of `private_key` on `input`
+pub fn vrf_evaluate(private_key: u64, input: u64) -> u64 {
+    seeded_hash(private_key, input)
+}
+
+/// Evaluate the simplified VRF of `key_pair` on `input`
+pub fn vrf_prove(key_pair: &VRFKeyPair, input: u64) -> VRFProof {
+    let output = vrf_evaluate(key_pair.private_key, input);
+    VRFProof {
+        validator: key_pair.validator,
+        input,
+        output,
+        proof: seeded_hash(key_pair.public_key ^ output, input),
+        public_key: key_pair.public_key,
+        valid: key_pair.valid,
+    }
+}
+
+/// Whether `proof` is an evaluation of the VRF by `key_pair`, the registered key pair of its
+/// validator. A real VRF checks this against the public key alone; the simplified one needs
+/// the registry since the output cannot be recomputed from the public key.
+pub fn vrf_verify(key_pair: &VRFKeyPair, proof: &VRFProof) -> bool {
+    proof.valid
+        && key_pair.valid
+        && proof.validator == key_pair.validator
+        && proof.public_key == key_pair.public_key
+        && proof.output == vrf_evaluate(key_pair.private_key, proof.input)
+        && proof.proof == seeded_hash(proof.public_key ^ proof.output, proof.input)
+}
+
+/// Stake-weighted rank of a VRF output: an exponential draw `-ln(u) / stake` with `u` the
+/// output mapped to (0, 1]. The lowest rank wins the election, which makes each validator's
+/// chance of winning proportional to its stake; validators without stake never win.
+pub fn vrf_weighted_value(output: u64, stake: StakeAmount) -> f64 {
+    if stake == 0 {
+        return f64::INFINITY;
+    }
+    let uniform = ((output >> 11) + 1) as f64 / (1u64 << 53) as f64;
+    -uniform.ln() / stake as f64
+}
+
 /// Block structure for the blockchain
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct Block {
@@ -861,50 +918,31 @@ impl VotorState {
     
     /// Generate VRF key pair for a validator - mirrors TLA+ VRFGenerateKeyPair
     pub fn generate_vrf_key_pair(&self, validator: ValidatorId, seed: u64) -> VRFKeyPair {
-        let private_key = (validator as u64 * seed * 7919) % 999999;
-        let public_key = (private_key * 2) % 999999;
-        
-        VRFKeyPair {
-            validator,
-            public_key,
-            private_key,
-            valid: true,
-        }
+        vrf_key_pair(validator, seed)
     }
     
     /// VRF evaluation function - mirrors TLA+ VRFEvaluate
     pub fn vrf_evaluate(&self, private_key: u64, input: u64) -> u64 {
-        let max_vrf_output = 999999;
-        let hash1 = (private_key * input * 991) % max_vrf_output;
-        let hash2 = (hash1 * 997 + input * 983) % max_vrf_output;
-        (hash1 + hash2) % max_vrf_output
+        vrf_evaluate(private_key, input)
     }
     
     /// Generate VRF proof - mirrors TLA+ VRFProve
     pub fn vrf_prove(&self, validator: ValidatorId, input: u64) -> Option<VRFProof> {
-        if let Some(key_pair) = self.vrf_key_pairs.get(&validator) {
-            let output = self.vrf_evaluate(key_pair.private_key, input);
-            let proof = (key_pair.private_key * input * output * 7919) % 999999;
-            
-            Some(VRFProof {
-                validator,
+        self.vrf_key_pairs.get(&validator).map(|key_pair| vrf_prove(key_pair, input))
+    }
+    
+    /// Verify VRF proof against the registered key pair with `public_key` - mirrors TLA+ VRFVerify
+    pub fn vrf_verify(&self, public_key: u64, input: u64, proof: u64, output: u64) -> bool {
+        self.vrf_key_pairs.values()
+            .filter(|key_pair| key_pair.public_key == public_key)
+            .any(|key_pair| vrf_verify(key_pair, &VRFProof {
+                validator: key_pair.validator,
                 input,
                 output,
                 proof,
-                public_key: key_pair.public_key,
+                public_key,
                 valid: true,
-            })
-        } else {
-            None
-        }
-    }
-    
-    /// Verify VRF proof - mirrors TLA+ VRFVerify
-    pub fn vrf_verify(&self, public_key: u64, input: u64, proof: u64, output: u64) -> bool {
-        let expected_proof_hash = (public_key * input * output * 7919) % 999999;
-        let deterministic_output = self.vrf_evaluate(public_key / 2, input); // Derive private key
-        
-        proof == expected_proof_hash && output == deterministic_output
+            }))
     }
     
     /// Adaptive timeout using leader window based exponential backoff - mirrors TLA+ AdaptiveTimeout
@@ -1188,17 +1226,13 @@ impl VotorState {
         }
         
         let mut best_validator = 0;
-        let mut best_weighted_value = u64::MAX;
+        let mut best_weighted_value = f64::INFINITY;
         
         for validator in 0..self.config.validator_count {
             let validator_id = validator as ValidatorId;
             if let Some(vrf_proof) = self.vrf_prove(validator_id, input) {
                 let stake = self.config.stake_distribution.get(&validator_id).copied().unwrap_or(0);
-                let weighted_value = if stake == 0 {
-                    u64::MAX
-                } else {
-                    (vrf_proof.output * self.config.total_stake) / stake
-                };
+                let weighted_value = vrf_weighted_value(vrf_proof.output, stake);
                 
                 if weighted_value < best_weighted_value {
                     best_weighted_value = weighted_value;
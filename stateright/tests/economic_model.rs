@@ -390,10 +390,7 @@ fn test_byzantine_penalties_detailed(config: &Config, _test_config: &TestConfig)
         // Mark validator as Byzantine
         state.failure_states.insert(validator_id, ValidatorStatus::Byzantine);
         
-        let temp_model = AlpenglowModel {
-            config: config.clone(),
-            state: state.clone(),
-        };
+        let temp_model = AlpenglowModel::with_state(config.clone(), state.clone());
         
         // Test that Byzantine actions are enabled for Byzantine validators
         let double_vote_action = AlpenglowAction::Byzantine(ByzantineAction::DoubleVote {
@@ -1099,10 +1096,7 @@ fn test_byzantine_economic_penalties() {
         view: 1,
     });
     
-    let temp_model = AlpenglowModel {
-        config: model.config().clone(),
-        state: state.clone(),
-    };
+    let temp_model = AlpenglowModel::with_state(model.config().clone(), state.clone());
     
     assert!(temp_model.action_enabled(&double_vote_action));
     
@@ -1249,10 +1243,7 @@ fn test_timely_participation_incentives() {
     // Advance time past timeout
     state.clock = initial_timeout + 1;
     
-    let temp_model = AlpenglowModel {
-        config: model.config().clone(),
-        state: state.clone(),
-    };
+    let temp_model = AlpenglowModel::with_state(model.config().clone(), state.clone());
     
     // Validator should be able to advance view after timeout
     let advance_view_action = AlpenglowAction::AdvanceView { validator };